@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+/// @title IFiatTokenV2_2
+/// @notice The external interface of Circle's FiatTokenV2_2, the implementation
+/// behind native USDC. Bridged deployments built on Circle's Bridged USDC
+/// Standard expose the same interface.
+/// @dev Feed this file to alloy's `sol!` macro to generate bindings, e.g.
+/// `sol!(#[sol(rpc)] IFiatTokenV2_2, "abi/IFiatTokenV2_2.sol");`.
+interface IFiatTokenV2_2 {
+    // ERC-20
+
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+
+    function name() external view returns (string memory);
+    function symbol() external view returns (string memory);
+    function decimals() external view returns (uint8);
+    function currency() external view returns (string memory);
+    function version() external pure returns (string memory);
+    function totalSupply() external view returns (uint256);
+    function balanceOf(address account) external view returns (uint256);
+    function allowance(address owner, address spender) external view returns (uint256);
+    function approve(address spender, uint256 value) external returns (bool);
+    function transfer(address to, uint256 value) external returns (bool);
+    function transferFrom(address from, address to, uint256 value) external returns (bool);
+    function increaseAllowance(address spender, uint256 increment) external returns (bool);
+    function decreaseAllowance(address spender, uint256 decrement) external returns (bool);
+
+    // EIP-2612
+
+    function PERMIT_TYPEHASH() external view returns (bytes32);
+    function DOMAIN_SEPARATOR() external view returns (bytes32);
+    function nonces(address owner) external view returns (uint256);
+    function permit(
+        address owner,
+        address spender,
+        uint256 value,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+    function permit(
+        address owner,
+        address spender,
+        uint256 value,
+        uint256 deadline,
+        bytes memory signature
+    ) external;
+
+    // EIP-3009
+
+    event AuthorizationUsed(address indexed authorizer, bytes32 indexed nonce);
+    event AuthorizationCanceled(address indexed authorizer, bytes32 indexed nonce);
+
+    function TRANSFER_WITH_AUTHORIZATION_TYPEHASH() external view returns (bytes32);
+    function RECEIVE_WITH_AUTHORIZATION_TYPEHASH() external view returns (bytes32);
+    function CANCEL_AUTHORIZATION_TYPEHASH() external view returns (bytes32);
+    function authorizationState(address authorizer, bytes32 nonce) external view returns (bool);
+    function transferWithAuthorization(
+        address from,
+        address to,
+        uint256 value,
+        uint256 validAfter,
+        uint256 validBefore,
+        bytes32 nonce,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+    function transferWithAuthorization(
+        address from,
+        address to,
+        uint256 value,
+        uint256 validAfter,
+        uint256 validBefore,
+        bytes32 nonce,
+        bytes memory signature
+    ) external;
+    function receiveWithAuthorization(
+        address from,
+        address to,
+        uint256 value,
+        uint256 validAfter,
+        uint256 validBefore,
+        bytes32 nonce,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+    function receiveWithAuthorization(
+        address from,
+        address to,
+        uint256 value,
+        uint256 validAfter,
+        uint256 validBefore,
+        bytes32 nonce,
+        bytes memory signature
+    ) external;
+    function cancelAuthorization(address authorizer, bytes32 nonce, uint8 v, bytes32 r, bytes32 s)
+        external;
+    function cancelAuthorization(address authorizer, bytes32 nonce, bytes memory signature)
+        external;
+
+    // Minting
+
+    event Mint(address indexed minter, address indexed to, uint256 amount);
+    event Burn(address indexed burner, uint256 amount);
+    event MinterConfigured(address indexed minter, uint256 minterAllowedAmount);
+    event MinterRemoved(address indexed oldMinter);
+    event MasterMinterChanged(address indexed newMasterMinter);
+
+    function masterMinter() external view returns (address);
+    function isMinter(address account) external view returns (bool);
+    function minterAllowance(address minter) external view returns (uint256);
+    function configureMinter(address minter, uint256 minterAllowedAmount) external returns (bool);
+    function removeMinter(address minter) external returns (bool);
+    function mint(address to, uint256 amount) external returns (bool);
+    function burn(uint256 amount) external;
+    function updateMasterMinter(address newMasterMinter) external;
+
+    // Pausing
+
+    event Pause();
+    event Unpause();
+    event PauserChanged(address indexed newAddress);
+
+    function paused() external view returns (bool);
+    function pauser() external view returns (address);
+    function pause() external;
+    function unpause() external;
+    function updatePauser(address newPauser) external;
+
+    // Blacklisting
+
+    event Blacklisted(address indexed account);
+    event UnBlacklisted(address indexed account);
+    event BlacklisterChanged(address indexed newBlacklister);
+
+    function blacklister() external view returns (address);
+    function isBlacklisted(address account) external view returns (bool);
+    function blacklist(address account) external;
+    function unBlacklist(address account) external;
+    function updateBlacklister(address newBlacklister) external;
+
+    // Ownership and rescue
+
+    event OwnershipTransferred(address previousOwner, address newOwner);
+    event RescuerChanged(address indexed newRescuer);
+
+    function owner() external view returns (address);
+    function transferOwnership(address newOwner) external;
+    function rescuer() external view returns (address);
+    function rescueERC20(address tokenContract, address to, uint256 amount) external;
+    function updateRescuer(address newRescuer) external;
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+/// @title MockFiatToken
+/// @notice A 6-decimal stand-in for USDC on local test nodes such as Anvil.
+/// It exposes the FiatToken getters `usdshe` reads, and lets anyone mint,
+/// blacklist and pause so tests can set up any state.
+/// @dev Not a faithful FiatToken: there are no roles, minter allowances or
+/// signature-based transfers. Compile it with `forge build` and pass the
+/// creation bytecode to `deploy_mock_usdc`.
+contract MockFiatToken {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+
+    string public constant name = "USD Coin";
+    string public constant symbol = "USDC";
+    uint8 public constant decimals = 6;
+
+    uint256 public totalSupply;
+    bool public paused;
+    mapping(address => uint256) public balanceOf;
+    mapping(address => mapping(address => uint256)) public allowance;
+    mapping(address => bool) public isBlacklisted;
+
+    function mint(address to, uint256 amount) external {
+        totalSupply += amount;
+        balanceOf[to] += amount;
+        emit Transfer(address(0), to, amount);
+    }
+
+    function blacklist(address account, bool blacklisted) external {
+        isBlacklisted[account] = blacklisted;
+    }
+
+    function setPaused(bool paused_) external {
+        paused = paused_;
+    }
+
+    function transfer(address to, uint256 value) external returns (bool) {
+        _transfer(msg.sender, to, value);
+        return true;
+    }
+
+    function approve(address spender, uint256 value) external returns (bool) {
+        require(!paused, "Pausable: paused");
+        require(!isBlacklisted[msg.sender] && !isBlacklisted[spender], "Blacklistable: account is blacklisted");
+        allowance[msg.sender][spender] = value;
+        emit Approval(msg.sender, spender, value);
+        return true;
+    }
+
+    function transferFrom(address from, address to, uint256 value) external returns (bool) {
+        require(value <= allowance[from][msg.sender], "ERC20: transfer amount exceeds allowance");
+        allowance[from][msg.sender] -= value;
+        _transfer(from, to, value);
+        return true;
+    }
+
+    function _transfer(address from, address to, uint256 value) internal {
+        require(!paused, "Pausable: paused");
+        require(!isBlacklisted[from] && !isBlacklisted[to], "Blacklistable: account is blacklisted");
+        require(value <= balanceOf[from], "ERC20: transfer amount exceeds balance");
+        balanceOf[from] -= value;
+        balanceOf[to] += value;
+        emit Transfer(from, to, value);
+    }
+}
@@ -0,0 +1,116 @@
+"""Python bindings for the usdshe registry over its C ABI.
+
+Build the shared library with
+
+    cargo rustc --release --features ffi --crate-type cdylib
+
+and point ``USDSHE_LIB`` at it, or pass its path to ``load``:
+
+    >>> import usdshe
+    >>> usdshe.usdc_address(8453)
+    '0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913'
+    >>> usdshe.usdc_token_info(56).decimals
+    18
+"""
+
+import ctypes
+import os
+from dataclasses import dataclass
+
+OK = 0
+ERR_UNSUPPORTED_CHAIN = 1
+ERR_NULL_POINTER = 2
+ERR_BUFFER_TOO_SMALL = 3
+
+ADDRESS_STR_LEN = 43
+ADDRESS_LEN = 20
+
+
+class UsdsheError(Exception):
+    """A non-zero ``USDSHE_*`` status code returned by the library."""
+
+    def __init__(self, status):
+        self.status = status
+        super().__init__(
+            {
+                ERR_UNSUPPORTED_CHAIN: "no USDC address is known for this chain ID",
+                ERR_NULL_POINTER: "a required pointer argument was null",
+                ERR_BUFFER_TOO_SMALL: "the output buffer is too small",
+            }.get(status, "usdshe status %d" % status)
+        )
+
+
+class UnsupportedChain(UsdsheError, KeyError):
+    """No USDC address is known for the chain ID."""
+
+
+@dataclass(frozen=True)
+class UsdcTokenInfo:
+    """The USDC deployment on one chain."""
+
+    chain_id: int
+    address: str
+    decimals: int
+
+
+_lib = None
+
+
+def load(path=None):
+    """Loads the usdshe shared library from ``path``, or from ``USDSHE_LIB``."""
+    global _lib
+    path = path or os.environ.get("USDSHE_LIB")
+    if path is None:
+        raise OSError("set USDSHE_LIB to the usdshe shared library built with --features ffi")
+    lib = ctypes.CDLL(path)
+    lib.usdshe_usdc_address.argtypes = [ctypes.c_uint64, ctypes.c_char_p, ctypes.c_size_t]
+    lib.usdshe_usdc_decimals.argtypes = [ctypes.c_uint64, ctypes.POINTER(ctypes.c_uint8)]
+    lib.usdshe_is_usdc.argtypes = [
+        ctypes.c_uint64,
+        ctypes.c_char_p,
+        ctypes.POINTER(ctypes.c_uint8),
+    ]
+    for function in (lib.usdshe_usdc_address, lib.usdshe_usdc_decimals, lib.usdshe_is_usdc):
+        function.restype = ctypes.c_int32
+    _lib = lib
+    return lib
+
+
+def _library():
+    return _lib or load()
+
+
+def _check(status):
+    if status == ERR_UNSUPPORTED_CHAIN:
+        raise UnsupportedChain(status)
+    if status != OK:
+        raise UsdsheError(status)
+
+
+def usdc_address(chain_id):
+    """Returns the checksummed USDC address on ``chain_id``."""
+    buf = ctypes.create_string_buffer(ADDRESS_STR_LEN)
+    _check(_library().usdshe_usdc_address(chain_id, buf, len(buf)))
+    return buf.value.decode("ascii")
+
+
+def usdc_decimals(chain_id):
+    """Returns the decimals of the USDC token on ``chain_id``."""
+    out = ctypes.c_uint8()
+    _check(_library().usdshe_usdc_decimals(chain_id, ctypes.byref(out)))
+    return out.value
+
+
+def usdc_token_info(chain_id):
+    """Returns the address and decimals of the USDC token on ``chain_id``."""
+    return UsdcTokenInfo(chain_id, usdc_address(chain_id), usdc_decimals(chain_id))
+
+
+def is_usdc(chain_id, address):
+    """Returns whether ``address``, a ``0x``-prefixed hex string, is USDC on ``chain_id``."""
+    raw = bytes.fromhex(address.removeprefix("0x"))
+    if len(raw) != ADDRESS_LEN:
+        raise ValueError("expected a 20-byte address")
+    out = ctypes.c_uint8()
+    _check(_library().usdshe_is_usdc(chain_id, raw, ctypes.byref(out)))
+    return bool(out.value)
@@ -0,0 +1,203 @@
+//! The USDC contract ABIs, and minimal Solidity ABI encoding and decoding for
+//! the calls this crate makes.
+//!
+//! Only static argument types are encoded, and return data is decoded one value
+//! at a time, which covers the ERC-20 and FiatToken getters without a full ABI
+//! implementation.
+//!
+//! Calldata builders are always available; the getter selectors and decoders
+//! are only used by the `onchain` helpers.
+
+#![cfg_attr(not(feature = "onchain"), allow(dead_code))]
+
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{Address, Bytes, B256, U256};
+
+/// Returns the JSON ABI of FiatTokenV2_2, the implementation contract behind
+/// USDC, for tools that consume raw ABIs such as ethers, web3.py or subgraph
+/// scaffolding.
+///
+/// Call it at the token address: the proxy forwards every call. The same
+/// interface is available as Solidity in `abi/IFiatTokenV2_2.sol`.
+///
+/// ## Examples
+///
+/// ```rust
+/// let abi = usdshe::usdc_abi();
+/// assert!(abi.starts_with('['));
+/// assert!(abi.contains(r#""name": "transferWithAuthorization""#));
+/// ```
+pub fn usdc_abi() -> &'static str {
+    include_str!("../abi/FiatTokenV2_2.json")
+}
+
+/// Returns the JSON ABI of `FiatTokenProxy`, the upgradeable proxy deployed at
+/// the USDC address, with its `admin()`, `implementation()` and upgrade
+/// functions.
+///
+/// ## Examples
+///
+/// ```rust
+/// assert!(usdshe::usdc_proxy_abi().contains(r#""name": "upgradeTo""#));
+/// ```
+pub fn usdc_proxy_abi() -> &'static str {
+    include_str!("../abi/FiatTokenProxy.json")
+}
+
+/// A 4-byte function selector.
+pub(crate) type Selector = [u8; 4];
+
+/// `name()`
+pub(crate) const NAME: Selector = [0x06, 0xfd, 0xde, 0x03];
+/// `symbol()`
+pub(crate) const SYMBOL: Selector = [0x95, 0xd8, 0x9b, 0x41];
+/// `decimals()`
+pub(crate) const DECIMALS: Selector = [0x31, 0x3c, 0xe5, 0x67];
+/// `DOMAIN_SEPARATOR()`
+pub(crate) const DOMAIN_SEPARATOR: Selector = [0x36, 0x44, 0xe5, 0x15];
+/// `totalSupply()`
+pub(crate) const TOTAL_SUPPLY: Selector = [0x18, 0x16, 0x0d, 0xdd];
+/// `balanceOf(address)`
+pub(crate) const BALANCE_OF: Selector = [0x70, 0xa0, 0x82, 0x31];
+/// `allowance(address,address)`
+pub(crate) const ALLOWANCE: Selector = [0xdd, 0x62, 0xed, 0x3e];
+/// `isBlacklisted(address)`
+pub(crate) const IS_BLACKLISTED: Selector = [0xfe, 0x57, 0x5a, 0x87];
+/// `paused()`
+pub(crate) const PAUSED: Selector = [0x5c, 0x97, 0x5a, 0xbb];
+/// `owner()`
+pub(crate) const OWNER: Selector = [0x8d, 0xa5, 0xcb, 0x5b];
+/// `masterMinter()`
+pub(crate) const MASTER_MINTER: Selector = [0x35, 0xd9, 0x9f, 0x35];
+/// `pauser()`
+pub(crate) const PAUSER: Selector = [0x9f, 0xd0, 0x50, 0x6d];
+/// `blacklister()`
+pub(crate) const BLACKLISTER: Selector = [0xbd, 0x10, 0x24, 0x30];
+/// `rescuer()`
+pub(crate) const RESCUER: Selector = [0x38, 0xa6, 0x31, 0x83];
+/// `transfer(address,uint256)`
+pub(crate) const TRANSFER: Selector = [0xa9, 0x05, 0x9c, 0xbb];
+/// `approve(address,uint256)`
+pub(crate) const APPROVE: Selector = [0x09, 0x5e, 0xa7, 0xb3];
+/// `depositForBurn(uint256,uint32,bytes32,address)`, on the CCTP V1 `TokenMessenger`
+pub(crate) const DEPOSIT_FOR_BURN: Selector = [0x6f, 0xd3, 0x50, 0x4e];
+/// `depositForBurnWithCaller(uint256,uint32,bytes32,address,bytes32)`, on the
+/// CCTP V1 `TokenMessenger`
+pub(crate) const DEPOSIT_FOR_BURN_WITH_CALLER: Selector = [0xf8, 0x56, 0xdd, 0xb6];
+/// `depositForBurn(uint256,uint32,bytes32,address,bytes32,uint256,uint32)`, on
+/// the CCTP V2 `TokenMessengerV2`
+pub(crate) const DEPOSIT_FOR_BURN_V2: Selector = [0x8e, 0x02, 0x50, 0xee];
+/// `burnLimitsPerMessage(address)`, on the CCTP `TokenMinter`
+pub(crate) const BURN_LIMITS_PER_MESSAGE: Selector = [0xa5, 0x6e, 0xc6, 0x32];
+/// `receiveMessage(bytes,bytes)`, on the CCTP `MessageTransmitter`
+pub(crate) const RECEIVE_MESSAGE: Selector = [0x57, 0xec, 0xfd, 0x28];
+/// `latestRoundData()`, on a Chainlink aggregator
+pub(crate) const LATEST_ROUND_DATA: Selector = [0xfe, 0xaf, 0x96, 0x8c];
+/// `getPriceUnsafe(bytes32)`, on the Pyth contract
+pub(crate) const GET_PRICE_UNSAFE: Selector = [0x96, 0x83, 0x4a, 0xd3];
+/// `supply(address,uint256)`, on a Compound III `Comet` market
+pub(crate) const COMET_SUPPLY: Selector = [0xf2, 0xb9, 0xfd, 0xb8];
+/// `withdraw(address,uint256)`, on a Compound III `Comet` market
+pub(crate) const COMET_WITHDRAW: Selector = [0xf3, 0xfe, 0xf3, 0xa3];
+
+/// Encodes a call to `selector` with static arguments, each already a 32-byte
+/// word.
+pub(crate) fn encode_call(selector: Selector, args: &[B256]) -> Bytes {
+    let mut data = Vec::with_capacity(4 + 32 * args.len());
+    data.extend_from_slice(&selector);
+    for arg in args {
+        data.extend_from_slice(arg.as_slice());
+    }
+    data.into()
+}
+
+/// Encodes a call to `selector` whose arguments are all `bytes`.
+pub(crate) fn encode_bytes_call(selector: Selector, args: &[&[u8]]) -> Bytes {
+    let mut head = Vec::with_capacity(32 * args.len());
+    let mut tail = Vec::new();
+    for arg in args {
+        let offset = 32 * args.len() + tail.len();
+        head.extend_from_slice(uint_word(U256::from(offset)).as_slice());
+        tail.extend_from_slice(uint_word(U256::from(arg.len())).as_slice());
+        tail.extend_from_slice(arg);
+        tail.resize(tail.len().next_multiple_of(32), 0);
+    }
+    let mut data = Vec::with_capacity(4 + head.len() + tail.len());
+    data.extend_from_slice(&selector);
+    data.extend(head);
+    data.extend(tail);
+    data.into()
+}
+
+/// Encodes an `address` argument.
+pub(crate) fn address_word(address: Address) -> B256 {
+    address.into_word()
+}
+
+/// Encodes a `uint256` argument.
+pub(crate) fn uint_word(value: U256) -> B256 {
+    B256::from(value.to_be_bytes::<32>())
+}
+
+/// Decodes the first return word as a `uint256`.
+pub(crate) fn decode_uint(data: &[u8]) -> Option<U256> {
+    data.get(..32).map(U256::from_be_slice)
+}
+
+/// Decodes the first return word as a `bool`, rejecting dirty words.
+pub(crate) fn decode_bool(data: &[u8]) -> Option<bool> {
+    match u8::try_from(decode_uint(data)?).ok()? {
+        0 => Some(false),
+        1 => Some(true),
+        _ => None,
+    }
+}
+
+/// Decodes the first return word as an `address`.
+pub(crate) fn decode_address(data: &[u8]) -> Option<Address> {
+    word_to_address(decode_word(data)?)
+}
+
+/// Decodes the first return word as a `bytes32`.
+pub(crate) fn decode_word(data: &[u8]) -> Option<B256> {
+    data.get(..32).map(B256::from_slice)
+}
+
+/// Decodes the first return word as a `uint8`.
+pub(crate) fn decode_u8(data: &[u8]) -> Option<u8> {
+    decode_uint(data)?.try_into().ok()
+}
+
+/// Interprets a word as a left-padded address, as stored in proxy slots and
+/// indexed event topics.
+pub(crate) fn word_to_address(word: B256) -> Option<Address> {
+    word[..12]
+        .iter()
+        .all(|b| *b == 0)
+        .then(|| Address::from_word(word))
+}
+
+/// Decodes a `string` return value, also accepting the NUL-padded `bytes32`
+/// used by some older tokens.
+pub(crate) fn decode_string(data: &[u8]) -> Option<String> {
+    let bytes = if data.len() == 32 {
+        let end = data.iter().position(|b| *b == 0).unwrap_or(32);
+        &data[..end]
+    } else {
+        decode_bytes(data)?
+    };
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+/// Decodes the first value as `bytes`, e.g. the non-indexed argument of an
+/// event with a single `bytes` field.
+pub(crate) fn decode_bytes(data: &[u8]) -> Option<&[u8]> {
+    let offset = word_to_usize(data.get(..32)?)?;
+    let length = word_to_usize(data.get(offset..offset.checked_add(32)?)?)?;
+    let start = offset + 32;
+    data.get(start..start.checked_add(length)?)
+}
+
+fn word_to_usize(word: &[u8]) -> Option<usize> {
+    U256::from_be_slice(word).try_into().ok()
+}
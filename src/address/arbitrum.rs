@@ -1,2 +1,9 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
 /// <https://arbiscan.io/address/0xaf88d065e77c8cc2239327c5edb3a432268e5831>
-pub const USDC: &str = "0xaf88d065e77c8cC2239327C5EDb3A432268e5831";
+pub const USDC: Address = address!("0xaf88d065e77c8cC2239327C5EDb3A432268e5831");
+
+/// Bridged USDC.e: <https://arbiscan.io/address/0xff970a61a04b1ca14834a43f5de4533ebddb5cc8>
+pub const USDC_E: Address = address!("0xFF970A61A04b1cA14834A43f5dE4533eBDDB5CC8");
@@ -1,2 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
 /// <https://sepolia.arbiscan.io/address/0x75faf114eafb1BDbe2F0316DF893fd58CE46AA4d>
-pub const USDC: &str = "0x75faf114eafb1BDbe2F0316DF893fd58CE46AA4d";
+pub const USDC: Address = address!("0x75faf114eafb1BDbe2F0316DF893fd58CE46AA4d");
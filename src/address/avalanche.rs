@@ -0,0 +1,9 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+/// <https://debank.com/token/avax/0xb97ef9ef8734c71904d8002f8b6bc66dd9c48a6e/overview>
+pub const USDC: Address = address!("0xB97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E");
+
+/// Bridged USDC.e: <https://snowtrace.io/token/0xa7d7079b0fead91f3e65f86e8915cb59c1a4c664>
+pub const USDC_E: Address = address!("0xA7D7079b0FEaD91F3e65f86E8915Cb59c1a4C664");
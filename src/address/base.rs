@@ -1,2 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
 /// <https://basescan.org/address/0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913>
-pub const USDC: &str = "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913";
+pub const USDC: Address = address!("0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913");
@@ -0,0 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+/// <https://base-sepolia.blockscout.com/address/0x036CbD53842c5426634e7929541eC2318f3dCF7e>
+pub const USDC: Address = address!("0x036CbD53842c5426634e7929541eC2318f3dCF7e");
@@ -1,2 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
 /// <https://berascan.com/address/0x549943e04f40284185054145c6E4e9568C1D3241>
-pub const USDC: &str = "0x549943e04f40284185054145c6E4e9568C1D3241";
+pub const USDC: Address = address!("0x549943e04f40284185054145c6E4e9568C1D3241");
@@ -1,2 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
 /// <https://bscscan.com/address/0x8ac76a51cc950d9822d68b83fe1ad97b32cd580d>
-pub const USDC: &str = "0x8ac76a51cc950d9822d68b83fe1ad97b32cd580d";
+pub const USDC: Address = address!("0x8AC76a51cc950d9822D68b83fE1Ad97B32Cd580d");
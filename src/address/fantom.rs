@@ -0,0 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+/// <https://www.oklink.com/fantom/token/0x04068da6c83afcfa0e13ba15a6696662335d5b75>
+pub const USDC: Address = address!("0x04068DA6C83AFCFA0e13ba15A6696662335D5B75");
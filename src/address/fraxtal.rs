@@ -0,0 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+/// <https://fraxscan.com/token/0xDcc0F2D8F90FDe85b10aC1c8Ab57dc0AE946A543>
+pub const USDC: Address = address!("0xDcc0F2D8F90FDe85b10aC1c8Ab57dc0AE946A543");
@@ -0,0 +1,5 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+pub const USDC: Address = address!("0x176211869cA2b568f2A7D4EE941E073a821EE1ff");
@@ -0,0 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+/// <http://mantlescan.xyz/token/0x09bc4e0d864854c6afb6eb9a9cdf58ac190d0df9>
+pub const USDC: Address = address!("0x09Bc4E0D864854c6aFB6eB9A9cdF58aC190D0dF9");
@@ -1,2 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
 /// <https://explorer.mode.network/token/0xd988097fb8612cc24eeC14542bC03424c656005f>
-pub const USDC: &str = "0xd988097fb8612cc24eeC14542bC03424c656005f";
+pub const USDC: Address = address!("0xd988097fb8612cc24eeC14542bC03424c656005f");
@@ -0,0 +1,8 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+pub const USDC: Address = address!("0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85");
+
+/// Bridged USDC.e: <https://optimistic.etherscan.io/address/0x7f5c764cbc14f9669b88837ca1490cca17c31607>
+pub const USDC_E: Address = address!("0x7F5c764cBc14f9669B88837ca1490cCa17c31607");
@@ -1 +1,8 @@
-pub const USDC: &str = "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359";
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+pub const USDC: Address = address!("0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359");
+
+/// Bridged USDC.e: <https://polygonscan.com/address/0x2791bca1f2de4661ed88a30c99a7a9449aa84174>
+pub const USDC_E: Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
@@ -1,2 +1,6 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
 /// <https://scrollscan.com/address/0x06efdbff2a14a7c8e15944d1f4a48f9f95f663a4>
-pub const USDC: &str = "0x06eFdBFf2a14a7c8E15944D1F4A48F9F95F663A4";
+pub const USDC: Address = address!("0x06eFdBFf2a14a7c8E15944D1F4A48F9F95F663A4");
@@ -1 +1,5 @@
-pub const USDC: &str = "0x29219dd400f2Bf60E5a23d13Be72B486D4038894";
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+pub const USDC: Address = address!("0x29219dd400f2Bf60E5a23d13Be72B486D4038894");
@@ -1,2 +1,6 @@
-/// USDC on Unichain: <https://uniscan.xyz/address/0x078d782b760474a361dda0af3839290b0ef57ad6>
-pub const USDC: &str = "0x078D782b760474a361dDA0AF3839290b0EF57AD6";
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+/// <https://uniscan.xyz/address/0x078d782b760474a361dda0af3839290b0ef57ad6>
+pub const USDC: Address = address!("0x078D782b760474a361dDA0AF3839290b0EF57AD6");
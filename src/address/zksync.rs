@@ -0,0 +1,5 @@
+// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.
+
+use alloy_primitives::{address, Address};
+
+pub const USDC: Address = address!("0x1d17CBcF0D6D143135aE902365D2E5e2A16538D4");
@@ -0,0 +1,313 @@
+//! A fixed-point USDC amount.
+
+use crate::{format_usdc, parse_usdc};
+use alloc::string::{String, ToString};
+use alloy_primitives::U256;
+use core::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// An amount of USDC, stored as raw units with 6 decimals, i.e. one unit is one
+/// micro-USDC.
+///
+/// Keeping amounts in this type rather than a bare `U256` makes the scale
+/// explicit at API boundaries, where mixing up whole dollars and raw units is
+/// the most common USDC bug. Arithmetic is checked and never wraps.
+///
+/// The type is fixed at 6 decimals. The transaction builders rescale it for
+/// deployments with a different number of decimals, such as the 18-decimal
+/// Binance-Peg USDC on BSC.
+///
+/// Decimal types such as `rust_decimal::Decimal` and `bigdecimal::BigDecimal`
+/// convert losslessly through their decimal string form, without a hop through
+/// `f64`. Their `Display` keeps trailing zeros, so strip them first; a value
+/// with more than 6 significant decimal places is still rejected:
+///
+/// ```rust,ignore
+/// let amount: UsdcAmount = balance.normalize().to_string().parse()?;
+/// let balance: Decimal = amount.to_string().parse()?;
+/// ```
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::UsdcAmount;
+/// use alloy_primitives::U256;
+///
+/// let price: UsdcAmount = "1.50".parse().unwrap();
+/// assert_eq!(price.to_raw(), U256::from(1_500_000));
+///
+/// let total = price.checked_mul(U256::from(3)).unwrap();
+/// assert_eq!(total.to_string(), "4.50");
+///
+/// let change = "5".parse::<UsdcAmount>().unwrap().checked_sub(total).unwrap();
+/// assert_eq!(change, UsdcAmount::from_raw(U256::from(500_000)));
+/// assert_eq!(change.to_string(), "0.50");
+///
+/// assert!(UsdcAmount::ZERO.checked_sub(price).is_none());
+/// assert!("0.0000001".parse::<UsdcAmount>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UsdcAmount(U256);
+
+impl UsdcAmount {
+    /// The number of decimals of a USDC amount.
+    pub const DECIMALS: u8 = 6;
+
+    /// Zero USDC.
+    pub const ZERO: Self = Self(U256::ZERO);
+
+    /// One USDC, i.e. `1_000_000` raw units.
+    pub const ONE: Self = Self(U256::from_limbs([1_000_000, 0, 0, 0]));
+
+    /// The largest representable amount, `type(uint256).max` raw units.
+    pub const MAX: Self = Self(U256::MAX);
+
+    /// Creates an amount from raw units, i.e. micro-USDC.
+    pub const fn from_raw(raw: U256) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the amount in raw units, as passed to the token contract.
+    pub const fn to_raw(self) -> U256 {
+        self.0
+    }
+
+    /// Returns `true` if the amount is zero.
+    pub fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Subtracts `rhs`, returning `None` if the result would be negative.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Multiplies the amount by a whole-number factor, e.g. a quantity,
+    /// returning `None` on overflow.
+    pub fn checked_mul(self, rhs: U256) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    /// Formats the amount for display to users, e.g. as `"$1,234.50"`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use usdshe::{AmountFormat, UsdcAmount};
+    ///
+    /// let amount: UsdcAmount = "1234567.891".parse().unwrap();
+    /// assert_eq!(amount.format(&AmountFormat::usd()), "$1,234,567.89");
+    /// assert_eq!(amount.format(&AmountFormat::usdc()), "1,234,567.89 USDC");
+    ///
+    /// let german = AmountFormat::new()
+    ///     .thousands_separator('.')
+    ///     .decimal_separator(',')
+    ///     .decimal_places(0)
+    ///     .suffix(" USDC");
+    /// assert_eq!(amount.format(&german), "1.234.568 USDC");
+    /// ```
+    pub fn format(&self, format: &AmountFormat) -> String {
+        let scale = U256::from(10).pow(U256::from(Self::DECIMALS));
+        let (mut whole, mut fraction) = match format.decimal_places {
+            Some(places) if places < Self::DECIMALS => {
+                let step = U256::from(10).pow(U256::from(Self::DECIMALS - places));
+                let (mut units, remainder) = self.0.div_rem(step);
+                // Round half up. `units` is at most `MAX / 10`, so this cannot overflow.
+                if remainder >= step - remainder {
+                    units += U256::from(1);
+                }
+                let (whole, fraction) = units.div_rem(U256::from(10).pow(U256::from(places)));
+                (whole.to_string(), pad(fraction, places))
+            }
+            _ => {
+                let (whole, fraction) = self.0.div_rem(scale);
+                (whole.to_string(), pad(fraction, Self::DECIMALS))
+            }
+        };
+        match format.decimal_places {
+            Some(places) => {
+                let places = usize::from(places);
+                if fraction.len() < places {
+                    fraction.extend(core::iter::repeat_n('0', places - fraction.len()));
+                }
+            }
+            None => {
+                let trimmed = fraction.trim_end_matches('0').len().max(2);
+                fraction.truncate(trimmed);
+            }
+        }
+        if let Some(separator) = format.thousands_separator {
+            whole = group(&whole, separator);
+        }
+        let mut out = String::with_capacity(
+            format.prefix.len() + whole.len() + fraction.len() + format.suffix.len() + 1,
+        );
+        out.push_str(&format.prefix);
+        out.push_str(&whole);
+        if !fraction.is_empty() {
+            out.push(format.decimal_separator);
+            out.push_str(&fraction);
+        }
+        out.push_str(&format.suffix);
+        out
+    }
+}
+
+impl From<UsdcAmount> for U256 {
+    fn from(amount: UsdcAmount) -> Self {
+        amount.0
+    }
+}
+
+/// Formats the amount in whole USDC with at least two decimals, e.g. `"1.50"`
+/// or `"0.000001"`.
+impl fmt::Display for UsdcAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let formatted = format_usdc(self.0, Self::DECIMALS);
+        let decimals = formatted.split_once('.').map_or(0, |(_, d)| d.len());
+        match decimals {
+            0 => write!(f, "{formatted}.00"),
+            1 => write!(f, "{formatted}0"),
+            _ => f.write_str(&formatted),
+        }
+    }
+}
+
+/// Parses an amount in whole USDC, e.g. `"1.50"`, with at most 6 decimals.
+impl FromStr for UsdcAmount {
+    type Err = UsdcAmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_usdc(s, Self::DECIMALS).map(Self)
+    }
+}
+
+/// How [`UsdcAmount::format`] renders an amount for users: separators, the
+/// number of decimal places, and a currency prefix or suffix.
+///
+/// [`AmountFormat::new`] matches [`UsdcAmount`]'s `Display`: no grouping, at
+/// least two decimal places and no currency marker. [`AmountFormat::usd`] and
+/// [`AmountFormat::usdc`] are presets for English-language reports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AmountFormat {
+    thousands_separator: Option<char>,
+    decimal_separator: char,
+    decimal_places: Option<u8>,
+    prefix: String,
+    suffix: String,
+}
+
+impl AmountFormat {
+    /// Creates a format equivalent to `Display`, e.g. `1234.50`.
+    pub fn new() -> Self {
+        Self {
+            thousands_separator: None,
+            decimal_separator: '.',
+            decimal_places: None,
+            prefix: String::new(),
+            suffix: String::new(),
+        }
+    }
+
+    /// Dollar notation with grouped thousands and cents, e.g. `$1,234.50`.
+    pub fn usd() -> Self {
+        Self::new()
+            .thousands_separator(',')
+            .decimal_places(2)
+            .prefix("$")
+    }
+
+    /// Grouped thousands and cents with a ticker suffix, e.g. `1,234.50 USDC`.
+    pub fn usdc() -> Self {
+        Self::new()
+            .thousands_separator(',')
+            .decimal_places(2)
+            .suffix(" USDC")
+    }
+
+    /// Groups the whole part in threes with `separator`, e.g. `','` or `'.'`.
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Separates the fractional part with `separator` instead of `'.'`, e.g.
+    /// `','` for most European locales.
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Shows exactly `places` decimal places, rounding half up when fewer than
+    /// USDC's 6 and padding with zeros when more. `0` omits the fractional
+    /// part.
+    pub fn decimal_places(mut self, places: u8) -> Self {
+        self.decimal_places = Some(places);
+        self
+    }
+
+    /// Writes `prefix` before the number, e.g. `"$"`.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Writes `suffix` after the number, e.g. `" USDC"`.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+}
+
+impl Default for AmountFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Left-pads `value` with zeros to `width` digits; empty if `width` is zero.
+fn pad(value: U256, width: u8) -> String {
+    if width == 0 {
+        return String::new();
+    }
+    alloc::format!("{value:0>width$}", width = usize::from(width))
+}
+
+/// Inserts `separator` between groups of three digits, counted from the right.
+fn group(digits: &str, separator: char) -> String {
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 * separator.len_utf8());
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(separator);
+        }
+        out.push(digit);
+    }
+    out
+}
+
+/// Represents errors that can occur when parsing or converting an amount.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum UsdcAmountError {
+    /// The input is not a plain decimal number such as `"1.50"`.
+    #[error("Invalid amount: expected a decimal number such as '1.50'")]
+    InvalidFormat,
+
+    /// The input has more fractional digits than the token's decimals.
+    #[error("Amount has more than {decimals} decimal places")]
+    ExcessPrecision {
+        /// The decimals of the token.
+        decimals: u8,
+    },
+
+    /// The amount does not fit in a `uint256` of raw units.
+    #[error("Amount does not fit in a uint256 with {decimals} decimals")]
+    Overflow {
+        /// The decimals of the token.
+        decimals: u8,
+    },
+}
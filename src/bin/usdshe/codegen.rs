@@ -0,0 +1,75 @@
+//! Emitting the registry as TypeScript and Solidity source.
+
+use std::fmt::Write;
+use usdshe::{SupportedUsdcChain, UsdcRegistry};
+
+/// A target language for `usdshe codegen`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Lang {
+    TypeScript,
+    Solidity,
+}
+
+impl Lang {
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name {
+            "ts" | "typescript" => Some(Self::TypeScript),
+            "sol" | "solidity" => Some(Self::Solidity),
+            _ => None,
+        }
+    }
+}
+
+/// Renders every supported chain as a source file in `lang`.
+pub(crate) fn generate(lang: Lang) -> String {
+    match lang {
+        Lang::TypeScript => typescript(),
+        Lang::Solidity => UsdcRegistry::new().to_solidity_library(),
+    }
+}
+
+fn header(lang: &str) -> String {
+    format!(
+        "Generated by `usdshe codegen --lang {lang}` from usdshe {}. Do not edit by hand.",
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// The constant name for a chain, e.g. `ARBITRUM_SEPOLIA`.
+fn constant_name(chain: &SupportedUsdcChain) -> String {
+    chain
+        .named_chain()
+        .to_string()
+        .to_uppercase()
+        .replace('-', "_")
+}
+
+/// `usdc.ts`: named constants, a map keyed by chain ID and a lookup function.
+fn typescript() -> String {
+    let mut out = format!("// {}\n\n", header("ts"));
+    for chain in SupportedUsdcChain::ALL {
+        let _ = writeln!(
+            out,
+            "export const {}_USDC = \"{}\" as const;",
+            constant_name(chain),
+            chain.usdc_address()
+        );
+    }
+    out.push_str("\n/** USDC contract addresses keyed by EIP-155 chain ID. */\n");
+    out.push_str("export const USDC_ADDRESSES: Readonly<Record<number, `0x${string}`>> = {\n");
+    for chain in SupportedUsdcChain::ALL {
+        let _ = writeln!(
+            out,
+            "  {}: {}_USDC,",
+            chain.chain_id(),
+            constant_name(chain)
+        );
+    }
+    out.push_str("};\n\n");
+    out.push_str(
+        "/** Returns the USDC address on `chainId`, or `undefined` if it is not supported. */\n\
+         export function usdcAddress(chainId: number): `0x${string}` | undefined {\n  \
+         return USDC_ADDRESSES[chainId];\n}\n",
+    );
+    out
+}
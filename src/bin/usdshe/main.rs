@@ -0,0 +1,293 @@
+//! Command-line access to the USDC registry.
+//!
+//! ```text
+//! usdshe lookup base
+//! usdshe list --mainnets
+//! usdshe reverse 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48
+//! usdshe list --json | jq -r '.[].address'
+//! usdshe verify --chain arbitrum --rpc http://localhost:8545
+//! usdshe codegen --lang ts > usdc.ts
+//! ```
+//!
+//! `lookup`, `list` and `reverse` print records with the same fields, `chain`,
+//! `chain_id` and `address`, so the `--json` and `--csv` output can be relied on
+//! in scripts. `verify` adds `check`, `expected`, `actual` and `ok`.
+
+mod codegen;
+mod output;
+mod rpc;
+
+use alloy_chains::NamedChain;
+use alloy_primitives::Address;
+use codegen::Lang;
+use output::{Cell, Format, Table};
+use rpc::{RpcClient, RpcError};
+use std::{
+    env,
+    future::Future,
+    pin::pin,
+    process::ExitCode,
+    str::FromStr,
+    task::{Context, Poll, Waker},
+};
+use thiserror::Error;
+use usdshe::{FieldCheck, OnchainError, SupportedUsdcChain, UsdcError};
+
+const USAGE: &str = "\
+Query USDC contract addresses.
+
+Usage:
+  usdshe lookup <CHAIN>             Print the USDC address on a chain, by name or ID
+  usdshe list [--mainnets|--testnets]
+                                    Print every supported chain and its address
+  usdshe reverse <ADDRESS>          Print the chains on which an address is USDC
+  usdshe verify --chain <CHAIN> --rpc <URL>
+                                    Check the registered token against a node
+  usdshe codegen --lang <ts|sol>    Print the registry as TypeScript constants or a
+                                    Solidity library
+  usdshe serve [--addr <ADDR>]      Serve the registry as a JSON HTTP API (requires the
+                                    `server` feature; default address 127.0.0.1:8080)
+  usdshe --help | --version
+
+Options:
+  --json                            Print records as JSON
+  --csv                             Print records as CSV with a header row
+";
+
+/// The fields printed by `lookup`, `list` and `reverse`.
+const ADDRESS_FIELDS: &[&str] = &["chain", "chain_id", "address"];
+
+/// The fields printed by `verify`, one row per check.
+const VERIFY_FIELDS: &[&str] = &[
+    "chain", "chain_id", "address", "check", "expected", "actual", "ok",
+];
+
+#[derive(Error, Debug)]
+enum CliError {
+    #[error("{0}\n\n{USAGE}")]
+    Usage(String),
+
+    #[error("no supported chain has USDC at {0}")]
+    NotUsdc(Address),
+
+    #[error("{0} check(s) failed")]
+    Mismatch(usize),
+
+    #[error(transparent)]
+    Usdc(#[from] UsdcError),
+
+    #[error(transparent)]
+    Rpc(#[from] RpcError),
+
+    #[error(transparent)]
+    Onchain(#[from] OnchainError),
+
+    #[error("server failed: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err @ CliError::Usage(_)) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    let mut format = Format::Text;
+    let mut rest = Vec::new();
+    for arg in args {
+        let requested = match arg.as_str() {
+            "--json" => Format::Json,
+            "--csv" => Format::Csv,
+            other => {
+                rest.push(other);
+                continue;
+            }
+        };
+        if format != Format::Text && format != requested {
+            return Err(usage("--json and --csv cannot be combined"));
+        }
+        format = requested;
+    }
+
+    match rest[..] {
+        [] | ["help"] | ["-h" | "--help"] => print!("{USAGE}"),
+        ["-V" | "--version"] => println!("usdshe {}", env!("CARGO_PKG_VERSION")),
+        ["lookup", chain] => {
+            let chain = parse_chain(chain)?;
+            let mut table = Table::new(ADDRESS_FIELDS);
+            table.push(address_row(chain.named_chain(), chain.usdc_address()));
+            match format {
+                // A single address, so `$(usdshe lookup base)` works in scripts.
+                Format::Text => println!("{}", chain.usdc_address()),
+                Format::Json => println!("{}", table.object(0)),
+                Format::Csv => print!("{}", table.render(format)),
+            }
+        }
+        ["list", ref flags @ ..] => {
+            let filter = match flags {
+                [] => None,
+                ["--mainnets"] => Some(false),
+                ["--testnets"] => Some(true),
+                _ => return Err(usage("`list` accepts one of --mainnets or --testnets")),
+            };
+            let mut table = Table::new(ADDRESS_FIELDS);
+            for chain in SupportedUsdcChain::ALL {
+                let named = chain.named_chain();
+                if filter.is_none_or(|testnet| named.is_testnet() == testnet) {
+                    table.push(address_row(named, chain.usdc_address()));
+                }
+            }
+            print!("{}", table.render(format));
+        }
+        ["reverse", address] => {
+            let address =
+                Address::from_str(address).map_err(|source| UsdcError::AddressParseError {
+                    address_str: address.to_string(),
+                    source,
+                })?;
+            let mut table = Table::new(ADDRESS_FIELDS);
+            for chain in usdshe::chains_for_address(address) {
+                table.push(address_row(chain, address));
+            }
+            // Structured output reports no match as an empty result instead.
+            if table.is_empty() && format == Format::Text {
+                return Err(CliError::NotUsdc(address));
+            }
+            print!("{}", table.render(format));
+        }
+        ["verify", ref flags @ ..] => {
+            let (chain, url) = match flags {
+                ["--chain", chain, "--rpc", url] | ["--rpc", url, "--chain", chain] => (chain, url),
+                _ => return Err(usage("`verify` requires --chain <CHAIN> and --rpc <URL>")),
+            };
+            verify(parse_chain(chain)?, url, format)?;
+        }
+        ["codegen", "--lang", lang] => {
+            let lang = Lang::parse(lang).ok_or_else(|| {
+                usage(&format!(
+                    "unsupported language `{lang}`; expected ts or sol"
+                ))
+            })?;
+            if format != Format::Text {
+                return Err(usage("`codegen` does not support --json or --csv"));
+            }
+            print!("{}", codegen::generate(lang));
+        }
+        ["serve", ref flags @ ..] => {
+            let addr = match flags {
+                [] => "127.0.0.1:8080",
+                ["--addr", addr] => addr,
+                _ => return Err(usage("`serve` accepts only --addr <ADDR>")),
+            };
+            serve(addr)?;
+        }
+        [command, ..] => return Err(usage(&format!("unrecognized command `{command}`"))),
+    }
+    Ok(())
+}
+
+/// Compares the node's view of the registered token with the built-in data and
+/// fails if anything differs.
+fn verify(chain: SupportedUsdcChain, url: &str, format: Format) -> Result<(), CliError> {
+    let client = RpcClient::new(url)?;
+    let report = block_on(usdshe::verify_onchain(&client, chain.named_chain()))?;
+
+    let checks = [
+        ("chain_id", check_cells(&report.chain_id)),
+        ("symbol", check_cells(&report.symbol)),
+        ("decimals", check_cells(&report.decimals)),
+        ("name", check_cells(&report.name)),
+        (
+            "implementation",
+            implementation_cells(&report.implementation),
+        ),
+    ];
+    let mut table = Table::new(VERIFY_FIELDS);
+    for (check, (expected, actual, ok)) in checks {
+        let mut row = address_row(report.chain, report.address);
+        row.extend([check.into(), expected.into(), actual.into(), ok.into()]);
+        table.push(row);
+    }
+    print!("{}", table.render(format));
+
+    match report.mismatches().len() {
+        0 => Ok(()),
+        n => Err(CliError::Mismatch(n)),
+    }
+}
+
+fn check_cells<T: PartialEq + ToString>(check: &FieldCheck<T>) -> (String, String, bool) {
+    (
+        check.expected.to_string(),
+        check.actual.to_string(),
+        check.is_match(),
+    )
+}
+
+/// Renders the implementation check, with `-` for an unrecorded or unset
+/// implementation.
+fn implementation_cells(check: &FieldCheck<Option<Address>>) -> (String, String, bool) {
+    let cell =
+        |address: Option<Address>| address.map_or_else(|| "-".into(), |a| a.to_checksum(None));
+    (
+        cell(check.expected),
+        cell(check.actual),
+        check.is_match_or_unrecorded(),
+    )
+}
+
+/// Runs a future that never waits: the RPC client blocks inside each call, so
+/// the future completes on its first poll and there is nothing for a runtime to
+/// schedule.
+fn block_on<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("the RPC client completes every call before returning"),
+    }
+}
+
+#[cfg(feature = "server")]
+fn serve(addr: &str) -> Result<(), CliError> {
+    let listener = std::net::TcpListener::bind(addr)?;
+    eprintln!("listening on http://{}", listener.local_addr()?);
+    usdshe::UsdcService::new().serve(listener)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "server"))]
+fn serve(_addr: &str) -> Result<(), CliError> {
+    Err(usage("`serve` requires building with the `server` feature"))
+}
+
+fn address_row(chain: NamedChain, address: Address) -> Vec<Cell> {
+    vec![
+        chain.to_string().into(),
+        (chain as u64).into(),
+        address.to_string().into(),
+    ]
+}
+
+/// Parses a chain given by name, alias or numeric chain ID.
+fn parse_chain(input: &str) -> Result<SupportedUsdcChain, UsdcError> {
+    match input.parse::<u64>() {
+        Ok(id) => {
+            let chain = NamedChain::try_from(id).map_err(|_| UsdcError::UnsupportedChainId(id))?;
+            SupportedUsdcChain::try_from(chain)
+        }
+        Err(_) => input.parse(),
+    }
+}
+
+fn usage(message: &str) -> CliError {
+    CliError::Usage(message.to_string())
+}
@@ -0,0 +1,152 @@
+//! Rendering subcommand results as text, JSON or CSV.
+
+use std::fmt::Write;
+
+/// How results are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// A single value in a row.
+pub(crate) enum Cell {
+    Str(String),
+    Int(u64),
+    Bool(bool),
+}
+
+impl Cell {
+    fn text(&self) -> String {
+        match self {
+            Self::Str(s) => s.clone(),
+            Self::Int(n) => n.to_string(),
+            Self::Bool(b) => b.to_string(),
+        }
+    }
+
+    fn json(&self) -> String {
+        match self {
+            Self::Str(s) => json_string(s),
+            Self::Int(n) => n.to_string(),
+            Self::Bool(b) => b.to_string(),
+        }
+    }
+
+    fn csv(&self) -> String {
+        let text = self.text();
+        if text.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text
+        }
+    }
+}
+
+impl From<String> for Cell {
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(value: &str) -> Self {
+        Self::Str(value.to_string())
+    }
+}
+
+impl From<u64> for Cell {
+    fn from(value: u64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<bool> for Cell {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+/// Rows of values under a fixed set of field names.
+///
+/// Field names are part of the command-line interface: scripts select on them in
+/// the JSON and CSV output, so they must not be renamed.
+pub(crate) struct Table {
+    fields: &'static [&'static str],
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    pub(crate) fn new(fields: &'static [&'static str]) -> Self {
+        Self {
+            fields,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends a row. `row` must have one cell per field.
+    pub(crate) fn push(&mut self, row: Vec<Cell>) {
+        debug_assert_eq!(row.len(), self.fields.len());
+        self.rows.push(row);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Renders every row: tab-separated lines, a JSON array of objects, or CSV
+    /// with a header row.
+    pub(crate) fn render(&self, format: Format) -> String {
+        let mut out = String::new();
+        match format {
+            Format::Text => {
+                for row in &self.rows {
+                    let cells: Vec<String> = row.iter().map(Cell::text).collect();
+                    let _ = writeln!(out, "{}", cells.join("\t"));
+                }
+            }
+            Format::Json => {
+                let objects: Vec<String> = (0..self.rows.len()).map(|i| self.object(i)).collect();
+                let _ = writeln!(out, "[{}]", objects.join(","));
+            }
+            Format::Csv => {
+                let _ = writeln!(out, "{}", self.fields.join(","));
+                for row in &self.rows {
+                    let cells: Vec<String> = row.iter().map(Cell::csv).collect();
+                    let _ = writeln!(out, "{}", cells.join(","));
+                }
+            }
+        }
+        out
+    }
+
+    /// Renders row `index` as a JSON object.
+    pub(crate) fn object(&self, index: usize) -> String {
+        let members: Vec<String> = self
+            .fields
+            .iter()
+            .zip(&self.rows[index])
+            .map(|(field, cell)| format!("{}:{}", json_string(field), cell.json()))
+            .collect();
+        format!("{{{}}}", members.join(","))
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
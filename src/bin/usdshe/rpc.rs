@@ -0,0 +1,221 @@
+//! A minimal JSON-RPC client for reading token metadata from a node.
+//!
+//! Only plain `http://` endpoints are supported, e.g. a local node, Anvil, or a
+//! TLS-terminating proxy in front of a hosted provider.
+
+use alloy_primitives::{hex, Address, Bytes, B256};
+use std::{
+    io::{Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    str::FromStr,
+    time::Duration,
+};
+use thiserror::Error;
+use usdshe::EthProvider;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Error, Debug)]
+pub(crate) enum RpcError {
+    #[error("unsupported RPC URL '{0}': only http:// endpoints are supported")]
+    UnsupportedUrl(String),
+
+    #[error("RPC request to {url} failed: {source}")]
+    Io {
+        url: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("RPC request to {url} failed with HTTP status {status}")]
+    Status { url: String, status: u16 },
+
+    #[error("RPC error from {url}: {message}")]
+    Node { url: String, message: String },
+
+    #[error("invalid RPC response from {url}: {reason}")]
+    InvalidResponse { url: String, reason: &'static str },
+}
+
+/// A JSON-RPC endpoint.
+pub(crate) struct RpcClient {
+    url: String,
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl RpcClient {
+    pub(crate) fn new(url: &str) -> Result<Self, RpcError> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| RpcError::UnsupportedUrl(url.to_string()))?;
+        let (authority, path) = match rest.find('/') {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/"),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse()
+                    .map_err(|_| RpcError::UnsupportedUrl(url.to_string()))?,
+            ),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(RpcError::UnsupportedUrl(url.to_string()));
+        }
+        Ok(Self {
+            url: url.to_string(),
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        })
+    }
+
+    /// Returns the node's chain ID.
+    pub(crate) fn chain_id(&self) -> Result<u64, RpcError> {
+        let result = self.request(r#""method":"eth_chainId","params":[]"#)?;
+        let digits = result.strip_prefix("0x").unwrap_or(&result);
+        u64::from_str_radix(digits, 16).map_err(|_| self.invalid("chain ID is not a hex quantity"))
+    }
+
+    /// Calls a view function on `to` at the latest block and returns the raw
+    /// return data.
+    pub(crate) fn call(&self, to: Address, data: &[u8]) -> Result<Bytes, RpcError> {
+        let params = format!(
+            r#""method":"eth_call","params":[{{"to":"{to}","data":"{}"}},"latest"]"#,
+            hex::encode_prefixed(data)
+        );
+        let result = self.request(&params)?;
+        hex::decode(&result)
+            .map(Bytes::from)
+            .map_err(|_| self.invalid("call result is not hex"))
+    }
+
+    /// Reads a storage slot at the latest block.
+    pub(crate) fn storage_at(&self, address: Address, slot: B256) -> Result<B256, RpcError> {
+        let params =
+            format!(r#""method":"eth_getStorageAt","params":["{address}","{slot}","latest"]"#);
+        let result = self.request(&params)?;
+        let digits = result.strip_prefix("0x").unwrap_or(&result);
+        // Nodes may strip leading zeros from the word.
+        let padded = format!("{digits:0>64}");
+        B256::from_str(&padded).map_err(|_| self.invalid("storage value is not a 32-byte word"))
+    }
+
+    /// Sends a request with the given `"method"` and `"params"` members and
+    /// returns the `result` string.
+    fn request(&self, members: &str) -> Result<String, RpcError> {
+        let body = format!(r#"{{"jsonrpc":"2.0","id":1,{members}}}"#);
+        let response = self.post(&body).map_err(|source| RpcError::Io {
+            url: self.url.clone(),
+            source,
+        })?;
+        let (status, body) = parse_http_response(&response)
+            .ok_or_else(|| self.invalid("malformed HTTP response"))?;
+        if !(200..300).contains(&status) {
+            return Err(RpcError::Status {
+                url: self.url.clone(),
+                status,
+            });
+        }
+        if let Some(error) = find_member(&body, "error") {
+            let message = find_string(error, "message").unwrap_or(error);
+            return Err(RpcError::Node {
+                url: self.url.clone(),
+                message: message.to_string(),
+            });
+        }
+        find_string(&body, "result")
+            .map(str::to_string)
+            .ok_or_else(|| self.invalid("missing result"))
+    }
+
+    fn post(&self, body: &str) -> std::io::Result<Vec<u8>> {
+        let address = (self.host.as_str(), self.port)
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| std::io::Error::other("host did not resolve"))?;
+        let mut stream = TcpStream::connect_timeout(&address, TIMEOUT)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        write!(
+            stream,
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.path,
+            self.host,
+            body.len()
+        )?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+        Ok(response)
+    }
+
+    fn invalid(&self, reason: &'static str) -> RpcError {
+        RpcError::InvalidResponse {
+            url: self.url.clone(),
+            reason,
+        }
+    }
+}
+
+/// Splits an HTTP/1.1 response into its status code and body, undoing chunked
+/// transfer encoding.
+fn parse_http_response(response: &[u8]) -> Option<(u16, String)> {
+    let response = std::str::from_utf8(response).ok()?;
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    let status = head.split(' ').nth(1)?.parse().ok()?;
+    let chunked = head.lines().any(|line| {
+        line.to_ascii_lowercase()
+            .strip_prefix("transfer-encoding:")
+            .is_some_and(|value| value.contains("chunked"))
+    });
+    if !chunked {
+        return Some((status, body.to_string()));
+    }
+    let mut decoded = String::new();
+    let mut rest = body;
+    loop {
+        let (size, tail) = rest.split_once("\r\n")?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some((status, decoded));
+        }
+        decoded.push_str(tail.get(..size)?);
+        rest = tail.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+/// Returns the raw text following `"key":` in a JSON object. Enough for the
+/// flat, well-formed responses nodes send; not a general JSON parser.
+fn find_member<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let needle = format!("\"{key}\"");
+    let index = json.find(&needle)?;
+    let rest = json[index + needle.len()..].trim_start();
+    Some(rest.strip_prefix(':')?.trim_start())
+}
+
+/// Returns the string value of `"key"`. Hex results and node error messages do
+/// not contain escapes, so the value ends at the next quote.
+fn find_string<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let value = find_member(json, key)?.strip_prefix('"')?;
+    Some(&value[..value.find('"')?])
+}
+
+/// Requests are blocking, so each future is already complete when returned.
+impl EthProvider for RpcClient {
+    type Error = RpcError;
+
+    async fn chain_id(&self) -> Result<u64, RpcError> {
+        RpcClient::chain_id(self)
+    }
+
+    async fn call(&self, to: Address, data: Bytes) -> Result<Bytes, RpcError> {
+        RpcClient::call(self, to, &data)
+    }
+
+    async fn storage_at(&self, address: Address, slot: B256) -> Result<B256, RpcError> {
+        RpcClient::storage_at(self, address, slot)
+    }
+}
@@ -0,0 +1,179 @@
+//! Bitcoin-backed ERC-20 addresses: WBTC and Threshold's tBTC.
+
+use crate::{TokenKind, Tokens, UsdcError};
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::{address, Address};
+
+/// Wrapped BTC on Ethereum mainnet, custodied by BitGo.
+pub const ETHEREUM_WBTC: Address = address!("0x2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599");
+/// Bridged WBTC on Arbitrum One.
+pub const ARBITRUM_WBTC: Address = address!("0x2f2a2543B76A4166549F7aaB2e75Bef0aefC5B0f");
+/// Bridged WBTC.e on Avalanche C-Chain.
+pub const AVALANCHE_WBTC: Address = address!("0x50b7545627a5162F82A992c33b87aDc75187B52B");
+/// Bridged WBTC on Linea.
+pub const LINEA_WBTC: Address = address!("0x3aAB2285ddcDdaD8edf438C1bAB47e1a9D05a9b4");
+/// Bridged WBTC on OP Mainnet.
+pub const OPTIMISM_WBTC: Address = address!("0x68f180fcCe6836688e9084f035309E29Bf0A2095");
+/// Bridged WBTC on Polygon PoS.
+pub const POLYGON_WBTC: Address = address!("0x1BFD67037B42Cf73acF2047067bd4F2C47D9BfD6");
+/// Bridged WBTC on Scroll.
+pub const SCROLL_WBTC: Address = address!("0x3C1BCa5a656e69edCD0D4E36BEbb3FcDAcA60Cf1");
+/// Bridged WBTC on zkSync Era.
+pub const ZKSYNC_WBTC: Address = address!("0xBBeB516fb02a01611cBBE0453Fe3c580D7281011");
+
+/// Threshold's tBTC on Ethereum mainnet.
+pub const ETHEREUM_TBTC: Address = address!("0x18084fbA666a33d37592fA2633fD49a74DD93a88");
+/// tBTC on Arbitrum One. Shares its address with [`OPTIMISM_TBTC`].
+pub const ARBITRUM_TBTC: Address = address!("0x6c84a8f1c29108F47a79964b5Fe888D4f4D0dE40");
+/// tBTC on Base. Shares its address with [`POLYGON_TBTC`].
+pub const BASE_TBTC: Address = address!("0x236aa50979D5f3De3Bd1Eeb40E81137F22ab794b");
+/// tBTC on OP Mainnet. Shares its address with [`ARBITRUM_TBTC`].
+pub const OPTIMISM_TBTC: Address = address!("0x6c84a8f1c29108F47a79964b5Fe888D4f4D0dE40");
+/// tBTC on Polygon PoS. Shares its address with [`BASE_TBTC`].
+pub const POLYGON_TBTC: Address = address!("0x236aa50979D5f3De3Bd1Eeb40E81137F22ab794b");
+
+/// A trait for types that can provide a WBTC contract address, the counterpart
+/// to [`Usdc`](crate::Usdc).
+///
+/// WBTC uses 8 decimals, matching bitcoin's satoshi precision. Outside Ethereum
+/// the address is the bridged token minted by the chain's canonical bridge.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{Wbtc, UsdcError, ETHEREUM_WBTC};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(NamedChain::Mainnet.wbtc_address().unwrap(), ETHEREUM_WBTC);
+/// assert!(matches!(
+///     NamedChain::Base.wbtc_address(),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Base))
+/// ));
+/// ```
+pub trait Wbtc {
+    /// Returns the WBTC contract address for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but has no known
+    ///   WBTC address.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn wbtc_address(&self) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical WBTC contract for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_wbtc(&self, address: Address) -> bool {
+        self.wbtc_address().is_ok_and(|wbtc| wbtc == address)
+    }
+}
+
+pub(crate) const fn wbtc_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_WBTC),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_WBTC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Linea => Some(LINEA_WBTC),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_WBTC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_WBTC),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_WBTC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Scroll => Some(SCROLL_WBTC),
+        #[cfg(feature = "l2s")]
+        NamedChain::ZkSync => Some(ZKSYNC_WBTC),
+        _ => None,
+    }
+}
+
+impl Wbtc for NamedChain {
+    fn wbtc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Wbtc)
+    }
+}
+
+impl Wbtc for Chain {
+    fn wbtc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Wbtc)
+    }
+}
+
+impl Wbtc for u64 {
+    fn wbtc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Wbtc)
+    }
+}
+
+/// A trait for types that can provide a tBTC contract address, the counterpart
+/// to [`Usdc`](crate::Usdc).
+///
+/// tBTC uses 18 decimals and is minted natively on its L2s through Wormhole
+/// gateways.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{Tbtc, Wbtc, BASE_TBTC};
+/// use alloy_chains::NamedChain;
+///
+/// // Base has tBTC but no canonical WBTC.
+/// assert_eq!(NamedChain::Base.tbtc_address().unwrap(), BASE_TBTC);
+/// assert!(NamedChain::Base.wbtc_address().is_err());
+/// ```
+pub trait Tbtc {
+    /// Returns the tBTC contract address for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but has no known
+    ///   tBTC address.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn tbtc_address(&self) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical tBTC contract for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_tbtc(&self, address: Address) -> bool {
+        self.tbtc_address().is_ok_and(|tbtc| tbtc == address)
+    }
+}
+
+pub(crate) const fn tbtc_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_TBTC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_TBTC),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_TBTC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_TBTC),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_TBTC),
+        _ => None,
+    }
+}
+
+impl Tbtc for NamedChain {
+    fn tbtc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Tbtc)
+    }
+}
+
+impl Tbtc for Chain {
+    fn tbtc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Tbtc)
+    }
+}
+
+impl Tbtc for u64 {
+    fn tbtc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Tbtc)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{AsyncUsdc, UsdcError};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// An in-memory cache in front of an [`AsyncUsdc`] resolver, so repeated lookups
+/// do not hit external APIs.
+///
+/// Both found addresses and unsupported-chain answers are cached for the
+/// configured TTL; other errors, such as network failures, are not, so the next
+/// lookup retries. When the cache is full, expired entries are dropped first,
+/// then the oldest entry.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{AsyncUsdc, CachedResolver, UsdcError};
+/// use alloy_chains::Chain;
+/// use alloy_primitives::{address, Address};
+/// use std::{sync::atomic::{AtomicUsize, Ordering}, time::Duration};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+///
+/// #[derive(Default)]
+/// struct Counting(AtomicUsize);
+///
+/// impl AsyncUsdc for Counting {
+///     async fn usdc_address(&self, _chain: Chain) -> Result<Address, UsdcError> {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///         Ok(address!("0x1111111111111111111111111111111111111111"))
+///     }
+/// }
+///
+/// let cached = CachedResolver::new(Counting::default())
+///     .with_ttl(Duration::from_secs(60))
+///     .with_max_entries(100);
+/// for _ in 0..3 {
+///     block_on(cached.usdc_address(Chain::from_id(12_345))).unwrap();
+/// }
+/// assert_eq!(cached.inner().0.load(Ordering::Relaxed), 1);
+///
+/// // A zero TTL disables caching.
+/// let uncached = CachedResolver::new(Counting::default()).with_ttl(Duration::ZERO);
+/// block_on(uncached.usdc_address(Chain::from_id(12_345))).unwrap();
+/// block_on(uncached.usdc_address(Chain::from_id(12_345))).unwrap();
+/// assert_eq!(uncached.inner().0.load(Ordering::Relaxed), 2);
+/// ```
+#[derive(Debug)]
+pub struct CachedResolver<R> {
+    inner: R,
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<HashMap<u64, CacheEntry>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CacheEntry {
+    /// `None` records that the chain is unsupported.
+    address: Option<Address>,
+    inserted: Instant,
+}
+
+impl<R> CachedResolver<R> {
+    /// The TTL used unless [`CachedResolver::with_ttl`] is called.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+    /// The size limit used unless [`CachedResolver::with_max_entries`] is called.
+    pub const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+    /// Wraps `inner` with the default TTL and size limit.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            ttl: Self::DEFAULT_TTL,
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how long an answer is served from the cache.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the maximum number of cached chains. Zero disables caching.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Returns the wrapped resolver.
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the number of cached chains, including expired entries not yet
+    /// evicted.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    /// Drops every cached entry.
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<u64, CacheEntry>> {
+        // Entries are plain data, so a panic elsewhere cannot leave them inconsistent.
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn cached(&self, chain: Chain) -> Option<Option<Address>> {
+        let entry = self.entries().get(&chain.id()).copied()?;
+        (entry.inserted.elapsed() < self.ttl).then_some(entry.address)
+    }
+
+    fn store(&self, chain: Chain, address: Option<Address>) {
+        if self.max_entries == 0 || self.ttl.is_zero() {
+            return;
+        }
+        let mut entries = self.entries();
+        if entries.len() >= self.max_entries && !entries.contains_key(&chain.id()) {
+            entries.retain(|_, entry| entry.inserted.elapsed() < self.ttl);
+            if entries.len() >= self.max_entries {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.inserted)
+                    .map(|(id, _)| *id);
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        entries.insert(
+            chain.id(),
+            CacheEntry {
+                address,
+                inserted: Instant::now(),
+            },
+        );
+    }
+}
+
+impl<R: AsyncUsdc + Sync> AsyncUsdc for CachedResolver<R> {
+    async fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        match self.cached(chain) {
+            Some(Some(address)) => return Ok(address),
+            Some(None) => return Err(UsdcError::unsupported(chain)),
+            None => {}
+        }
+        let result = self.inner.usdc_address(chain).await;
+        match result {
+            Ok(address) => self.store(chain, Some(address)),
+            Err(UsdcError::UnsupportedChain(_) | UsdcError::UnsupportedChainId(_)) => {
+                self.store(chain, None)
+            }
+            Err(_) => {}
+        }
+        result
+    }
+}
@@ -0,0 +1,241 @@
+use super::{cctp_contracts_for, cctp_domain, CctpVersion};
+use crate::{abi, TransactionRequest, Usdc, UsdcError};
+use alloc::vec;
+use alloy_chains::NamedChain;
+use alloy_primitives::{b256, Address, Bytes, Log, B256, U256};
+
+/// `topic0` of `MessageSent(bytes)`, emitted by the source chain's
+/// `MessageTransmitter` for every burn.
+pub const MESSAGE_SENT_TOPIC: B256 =
+    b256!("0x8c5261668696ce22758910d05bab8f186d6eb247ceac2af2e82c7dc17669b036");
+
+/// The V2 `minFinalityThreshold` of a fast transfer, which Circle attests to
+/// once the burn is confirmed, before hard finality, in exchange for a fee.
+pub const CCTP_V2_FAST_FINALITY_THRESHOLD: u32 = 1000;
+
+/// The V2 `minFinalityThreshold` of a standard transfer, which Circle attests
+/// to at hard finality, like a V1 transfer.
+pub const CCTP_V2_STANDARD_FINALITY_THRESHOLD: u32 = 2000;
+
+/// Builds the `depositForBurn` call that starts a CCTP transfer, burning USDC
+/// on the source chain so it can be minted on the destination.
+///
+/// The call goes to the source chain's `TokenMessenger`, which must first be
+/// approved to spend `amount`, e.g. with
+/// [`build_usdc_approve_raw`](crate::build_usdc_approve_raw). The burn token
+/// and the destination domain are filled in from the built-in data, and the
+/// mint recipient is left-padded to the `bytes32` CCTP expects.
+///
+/// Targets CCTP V1 unless [`version`](Self::version) is set.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{DepositForBurn, BASE_CCTP_TOKEN_MESSENGER};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, U256};
+///
+/// let recipient = address!("0x1111111111111111111111111111111111111111");
+/// // 25 USDC from Base to Arbitrum.
+/// let tx = DepositForBurn::new(NamedChain::Base, NamedChain::Arbitrum, U256::from(25_000_000), recipient)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(tx.to, BASE_CCTP_TOKEN_MESSENGER);
+/// assert_eq!(tx.data[..4], [0x6f, 0xd3, 0x50, 0x4e]);
+/// // The destination domain: Arbitrum is 3.
+/// assert_eq!(tx.data[67], 3);
+/// ```
+///
+/// A V2 fast transfer, which only `keeper` may complete on the destination:
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{cctp_route, DepositForBurn, CCTP_V2_FAST_FINALITY_THRESHOLD, CCTP_V2_TOKEN_MESSENGER};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, U256};
+///
+/// let recipient = address!("0x1111111111111111111111111111111111111111");
+/// let keeper = address!("0x2222222222222222222222222222222222222222");
+/// let (source, destination) = (NamedChain::Base, NamedChain::Linea);
+///
+/// let tx = DepositForBurn::new(source, destination, U256::from(25_000_000), recipient)
+///     .version(cctp_route(source, destination).unwrap())
+///     .destination_caller(keeper)
+///     .max_fee(U256::from(5_000))
+///     .min_finality_threshold(CCTP_V2_FAST_FINALITY_THRESHOLD)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(tx.to, CCTP_V2_TOKEN_MESSENGER);
+/// assert_eq!(tx.data[..4], [0x8e, 0x02, 0x50, 0xee]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositForBurn {
+    pub(super) source: NamedChain,
+    pub(super) destination: NamedChain,
+    pub(super) amount: U256,
+    mint_recipient: Address,
+    destination_caller: Option<Address>,
+    pub(super) version: CctpVersion,
+    max_fee: U256,
+    min_finality_threshold: u32,
+}
+
+impl DepositForBurn {
+    /// Starts a transfer of `amount` micro-USDC from `source` to
+    /// `mint_recipient` on `destination`.
+    pub fn new(
+        source: NamedChain,
+        destination: NamedChain,
+        amount: U256,
+        mint_recipient: Address,
+    ) -> Self {
+        Self {
+            source,
+            destination,
+            amount,
+            mint_recipient,
+            destination_caller: None,
+            version: CctpVersion::V1,
+            max_fee: U256::ZERO,
+            min_finality_threshold: CCTP_V2_STANDARD_FINALITY_THRESHOLD,
+        }
+    }
+
+    /// Restricts who may submit the message on the destination chain. Without
+    /// one, anyone holding the attestation can complete the transfer.
+    ///
+    /// On V1 this switches the call to `depositForBurnWithCaller`.
+    pub fn destination_caller(mut self, caller: Address) -> Self {
+        self.destination_caller = Some(caller);
+        self
+    }
+
+    /// Selects the CCTP version, e.g. from [`cctp_route`](super::cctp_route).
+    pub fn version(mut self, version: CctpVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the most the V2 burn may pay for a fast transfer, in micro-USDC.
+    /// Ignored on V1. Defaults to zero.
+    pub fn max_fee(mut self, max_fee: U256) -> Self {
+        self.max_fee = max_fee;
+        self
+    }
+
+    /// Sets the finality the V2 burn must reach before Circle attests to it.
+    /// Ignored on V1. Defaults to [`CCTP_V2_STANDARD_FINALITY_THRESHOLD`].
+    pub fn min_finality_threshold(mut self, threshold: u32) -> Self {
+        self.min_finality_threshold = threshold;
+        self
+    }
+
+    /// Encodes the call.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If either chain lacks the selected
+    ///   CCTP version or has no known USDC address.
+    pub fn build(&self) -> Result<TransactionRequest, UsdcError> {
+        let contracts = cctp_contracts_for(self.source, self.version)?;
+        if !self.version.is_available(self.destination) {
+            return Err(UsdcError::UnsupportedChain(self.destination));
+        }
+        let mut args = vec![
+            abi::uint_word(self.amount),
+            abi::uint_word(U256::from(cctp_domain(self.destination)?)),
+            abi::address_word(self.mint_recipient),
+            abi::address_word(self.source.usdc_address()?),
+        ];
+        let caller = self.destination_caller.map(abi::address_word);
+        let selector = match self.version {
+            CctpVersion::V1 => match caller {
+                Some(caller) => {
+                    args.push(caller);
+                    abi::DEPOSIT_FOR_BURN_WITH_CALLER
+                }
+                None => abi::DEPOSIT_FOR_BURN,
+            },
+            CctpVersion::V2 => {
+                args.extend([
+                    caller.unwrap_or(B256::ZERO),
+                    abi::uint_word(self.max_fee),
+                    abi::uint_word(U256::from(self.min_finality_threshold)),
+                ]);
+                abi::DEPOSIT_FOR_BURN_V2
+            }
+        };
+        Ok(TransactionRequest {
+            chain_id: self.source as u64,
+            to: contracts.token_messenger,
+            data: abi::encode_call(selector, &args),
+        })
+    }
+}
+
+/// Extracts the CCTP message from a `MessageSent` log, as found in the receipt
+/// of a `depositForBurn` transaction.
+///
+/// Its keccak-256 hash identifies the message to the attestation service, and
+/// the message itself is submitted with the attestation to mint on the
+/// destination chain. Returns `None` for any other log.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{decode_message_sent, BASE_CCTP_MESSAGE_TRANSMITTER, MESSAGE_SENT_TOPIC};
+/// use alloy_primitives::{Bytes, Log, B256, U256};
+///
+/// let message = [0x42; 40];
+/// let mut data = B256::from(U256::from(32).to_be_bytes::<32>()).to_vec();
+/// data.extend(B256::from(U256::from(40).to_be_bytes::<32>()));
+/// data.extend(message);
+/// data.resize(128, 0);
+/// let log = Log::new(BASE_CCTP_MESSAGE_TRANSMITTER, vec![MESSAGE_SENT_TOPIC], data.into()).unwrap();
+///
+/// assert_eq!(decode_message_sent(&log).unwrap()[..], message);
+/// ```
+pub fn decode_message_sent(log: &Log) -> Option<Bytes> {
+    if log.topics().first() != Some(&MESSAGE_SENT_TOPIC) {
+        return None;
+    }
+    abi::decode_bytes(&log.data.data).map(Bytes::copy_from_slice)
+}
+
+/// Builds the `receiveMessage(message, attestation)` call that completes a
+/// CCTP transfer by minting on `destination`.
+///
+/// `version` must match the version the burn was submitted with.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{build_receive_message, CctpVersion, ARBITRUM_CCTP_MESSAGE_TRANSMITTER};
+/// use alloy_chains::NamedChain;
+///
+/// let tx = build_receive_message(NamedChain::Arbitrum, CctpVersion::V1, &[0x42; 40], &[0xab; 65])
+///     .unwrap();
+/// assert_eq!(tx.to, ARBITRUM_CCTP_MESSAGE_TRANSMITTER);
+/// assert_eq!(tx.data[..4], [0x57, 0xec, 0xfd, 0x28]);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If `destination` lacks `version`.
+pub fn build_receive_message(
+    destination: NamedChain,
+    version: CctpVersion,
+    message: &[u8],
+    attestation: &[u8],
+) -> Result<TransactionRequest, UsdcError> {
+    let contracts = cctp_contracts_for(destination, version)?;
+    Ok(TransactionRequest {
+        chain_id: destination as u64,
+        to: contracts.message_transmitter,
+        data: abi::encode_bytes_call(abi::RECEIVE_MESSAGE, &[message, attestation]),
+    })
+}
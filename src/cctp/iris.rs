@@ -0,0 +1,349 @@
+use crate::{
+    json::Value,
+    remote::{get_json, HttpClient, HttpRequest, RemoteError, RetryPolicy},
+};
+use alloy_primitives::{hex, Bytes, B256};
+use core::future::Future;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Represents errors that can occur while waiting for a CCTP attestation.
+#[derive(Error, Debug)]
+pub enum AttestationError {
+    /// The attestation service could not be queried.
+    #[error(transparent)]
+    Remote(#[from] RemoteError),
+
+    /// The message was not attested to within the configured timeout.
+    #[error("No attestation for message {message_hash} after {waited:?}")]
+    Timeout {
+        /// The hash that was looked up: the burn message for V1, the burn
+        /// transaction for V2.
+        message_hash: B256,
+        /// How long the client polled for.
+        waited: Duration,
+    },
+}
+
+/// A client for Circle's attestation service (Iris), which signs CCTP burn
+/// messages once they reach the required finality on the source chain.
+///
+/// CCTP V1 messages are looked up by the keccak-256 hash of the `message`
+/// bytes emitted in the source chain's `MessageSent` event, with
+/// [`wait_for_attestation`](Self::wait_for_attestation). CCTP V2 messages are
+/// looked up by the source domain and the hash of the burn transaction, with
+/// [`wait_for_attestation_v2`](Self::wait_for_attestation_v2), which also
+/// returns the message. Like the remote resolvers, the client is generic over
+/// an [`HttpClient`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{HttpClient, HttpRequest, HttpResponse, IrisClient};
+/// use alloy_primitives::keccak256;
+/// use std::{
+///     sync::atomic::{AtomicBool, Ordering},
+///     time::Duration,
+/// };
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+///
+/// // A canned client that reports the attestation as pending once.
+/// struct Canned(AtomicBool);
+///
+/// impl HttpClient for Canned {
+///     type Error = std::io::Error;
+///
+///     async fn get(&self, request: HttpRequest) -> Result<HttpResponse, Self::Error> {
+///         assert!(request.url.contains("/v1/attestations/0x"));
+///         let body = if self.0.swap(true, Ordering::Relaxed) {
+///             r#"{"attestation": "0xabcd", "status": "complete"}"#
+///         } else {
+///             r#"{"attestation": "PENDING", "status": "pending_confirmations"}"#
+///         };
+///         Ok(HttpResponse { status: 200, body: body.to_string() })
+///     }
+///
+///     async fn sleep(&self, _duration: std::time::Duration) {}
+/// }
+///
+/// let iris = IrisClient::new(Canned(Default::default()))
+///     .with_poll_interval(Duration::from_millis(1), Duration::from_millis(10));
+/// let message_hash = keccak256(b"message bytes from the MessageSent event");
+///
+/// let attestation = block_on(iris.wait_for_attestation(message_hash)).unwrap();
+/// assert_eq!(attestation[..], [0xab, 0xcd]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IrisClient<C> {
+    client: C,
+    endpoint: String,
+    retry: RetryPolicy,
+    initial_interval: Duration,
+    max_interval: Duration,
+    timeout: Duration,
+}
+
+impl<C> IrisClient<C> {
+    /// The attestation service for mainnets.
+    pub const DEFAULT_ENDPOINT: &'static str = "https://iris-api.circle.com";
+
+    /// The attestation service for testnets.
+    pub const SANDBOX_ENDPOINT: &'static str = "https://iris-api-sandbox.circle.com";
+
+    /// Creates a client that queries [`IrisClient::DEFAULT_ENDPOINT`] through
+    /// `client`, polling every 2 s at first and backing off to every 30 s, for
+    /// up to 30 minutes.
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            endpoint: Self::DEFAULT_ENDPOINT.to_string(),
+            retry: RetryPolicy::default(),
+            initial_interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(30 * 60),
+        }
+    }
+
+    /// Queries `endpoint` instead of the default, e.g.
+    /// [`IrisClient::SANDBOX_ENDPOINT`] for testnet transfers.
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// Sets the retry policy for failed requests; the default is
+    /// [`RetryPolicy::default`]. A pending attestation is not a failure and is
+    /// polled for regardless.
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets the wait between two polls: `initial` at first, doubling up to
+    /// `max`.
+    pub fn with_poll_interval(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_interval = initial;
+        self.max_interval = max;
+        self
+    }
+
+    /// Sets how long [`wait_for_attestation`](Self::wait_for_attestation)
+    /// polls before giving up.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl<C: HttpClient> IrisClient<C> {
+    /// Fetches the attestation for `message_hash` once, returning `None` while
+    /// it is pending or the service has not seen the burn yet.
+    ///
+    /// # Errors
+    ///
+    /// - [`RemoteError`]: If the request fails or the response is malformed.
+    pub async fn attestation(&self, message_hash: B256) -> Result<Option<Bytes>, RemoteError> {
+        let url = format!("{}/v1/attestations/{message_hash}", self.endpoint);
+        let document = match get_json(&self.client, HttpRequest::get(&url), &self.retry).await {
+            Ok(document) => document,
+            // The service answers 404 until it has indexed the burn.
+            Err(RemoteError::Status { status: 404, .. }) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        attested_field(&document, "attestation", &url)
+    }
+
+    /// Fetches the CCTP V2 message burned in `transaction_hash` on the chain
+    /// with CCTP domain `source_domain`, e.g. from
+    /// [`cctp_domain`](crate::cctp_domain), with its attestation. Returns
+    /// `None` while the attestation is pending or the service has not seen the
+    /// burn yet.
+    ///
+    /// A `depositForBurn` transaction emits one message; if the transaction
+    /// emitted several, the first is returned.
+    ///
+    /// # Errors
+    ///
+    /// - [`RemoteError`]: If the request fails or the response is malformed.
+    pub async fn attestation_v2(
+        &self,
+        source_domain: u32,
+        transaction_hash: B256,
+    ) -> Result<Option<AttestedMessage>, RemoteError> {
+        let url = format!(
+            "{}/v2/messages/{source_domain}?transactionHash={transaction_hash}",
+            self.endpoint
+        );
+        let document = match get_json(&self.client, HttpRequest::get(&url), &self.retry).await {
+            Ok(document) => document,
+            Err(RemoteError::Status { status: 404, .. }) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let Some(entry) = document
+            .get("messages")
+            .and_then(|messages| messages.as_array())
+            .ok_or_else(|| RemoteError::InvalidResponse {
+                url: url.clone(),
+                reason: "expected a `messages` array",
+            })?
+            .first()
+        else {
+            return Ok(None);
+        };
+        let Some(attestation) = attested_field(entry, "attestation", &url)? else {
+            return Ok(None);
+        };
+        let message = attested_field(entry, "message", &url)?.unwrap_or_default();
+        Ok(Some(AttestedMessage {
+            message,
+            attestation,
+        }))
+    }
+
+    /// Polls until `message_hash` is attested to, returning the attestation
+    /// to submit with the message to the destination chain's
+    /// `MessageTransmitter.receiveMessage`.
+    ///
+    /// Waits with [`HttpClient::sleep`] between polls.
+    ///
+    /// # Errors
+    ///
+    /// - [`AttestationError::Timeout`]: If the message is still unattested
+    ///   after the configured timeout.
+    /// - [`AttestationError::Remote`]: If a request fails.
+    pub async fn wait_for_attestation(
+        &self,
+        message_hash: B256,
+    ) -> Result<Bytes, AttestationError> {
+        self.poll(message_hash, || self.attestation(message_hash))
+            .await
+    }
+
+    /// Polls until the CCTP V2 burn in `transaction_hash` on `source_domain`
+    /// is attested to, returning the message and attestation to submit to the
+    /// destination chain's `MessageTransmitter.receiveMessage`.
+    ///
+    /// Polls like [`wait_for_attestation`](Self::wait_for_attestation).
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use usdshe::{HttpClient, HttpRequest, HttpResponse, IrisClient};
+    /// use alloy_primitives::b256;
+    /// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    /// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    /// #     let mut future = std::pin::pin!(future);
+    /// #     loop {
+    /// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+    /// #             return output;
+    /// #         }
+    /// #     }
+    /// # }
+    ///
+    /// // A canned client that reports the burn as attested.
+    /// struct Canned;
+    ///
+    /// impl HttpClient for Canned {
+    ///     type Error = std::io::Error;
+    ///
+    ///     async fn get(&self, request: HttpRequest) -> Result<HttpResponse, Self::Error> {
+    ///         assert!(request.url.contains("/v2/messages/6?transactionHash=0x"));
+    ///         let body = r#"{"messages": [{"message": "0x0102", "attestation": "0xabcd", "status": "complete"}]}"#;
+    ///         Ok(HttpResponse { status: 200, body: body.to_string() })
+    ///     }
+    ///
+    ///     async fn sleep(&self, _duration: std::time::Duration) {}
+    /// }
+    ///
+    /// // The burn transaction on Base, CCTP domain 6.
+    /// let burn = b256!("0x1111111111111111111111111111111111111111111111111111111111111111");
+    /// let attested = block_on(IrisClient::new(Canned).wait_for_attestation_v2(6, burn)).unwrap();
+    /// assert_eq!(attested.message[..], [0x01, 0x02]);
+    /// assert_eq!(attested.attestation[..], [0xab, 0xcd]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AttestationError::Timeout`]: If the burn is still unattested after
+    ///   the configured timeout.
+    /// - [`AttestationError::Remote`]: If a request fails.
+    pub async fn wait_for_attestation_v2(
+        &self,
+        source_domain: u32,
+        transaction_hash: B256,
+    ) -> Result<AttestedMessage, AttestationError> {
+        self.poll(transaction_hash, || {
+            self.attestation_v2(source_domain, transaction_hash)
+        })
+        .await
+    }
+
+    /// Calls `fetch` until it returns a value, backing off between calls, or
+    /// times out waiting for `hash`.
+    async fn poll<T, F>(
+        &self,
+        hash: B256,
+        mut fetch: impl FnMut() -> F,
+    ) -> Result<T, AttestationError>
+    where
+        F: Future<Output = Result<Option<T>, RemoteError>>,
+    {
+        let started = Instant::now();
+        let mut interval = self.initial_interval;
+        loop {
+            if let Some(value) = fetch().await? {
+                return Ok(value);
+            }
+            let waited = started.elapsed();
+            if waited >= self.timeout {
+                return Err(AttestationError::Timeout {
+                    message_hash: hash,
+                    waited,
+                });
+            }
+            self.client.sleep(interval.min(self.timeout - waited)).await;
+            interval = interval.saturating_mul(2).min(self.max_interval);
+        }
+    }
+}
+
+/// A CCTP V2 message and its attestation, returned by
+/// [`IrisClient::wait_for_attestation_v2`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttestedMessage {
+    /// The message emitted by the burn on the source chain.
+    pub message: Bytes,
+    /// Circle's signature over [`message`](Self::message).
+    pub attestation: Bytes,
+}
+
+/// Decodes the hex `field` of an Iris response entry, or returns `None` if the
+/// entry's `status` is not yet `complete`.
+fn attested_field(entry: &Value, field: &str, url: &str) -> Result<Option<Bytes>, RemoteError> {
+    let invalid = |reason| RemoteError::InvalidResponse {
+        url: url.to_string(),
+        reason,
+    };
+    let status = entry
+        .get("status")
+        .and_then(|status| status.as_str())
+        .ok_or_else(|| invalid("expected a `status` string"))?;
+    if status != "complete" {
+        return Ok(None);
+    }
+    let value = entry
+        .get(field)
+        .and_then(|value| value.as_str())
+        .ok_or_else(|| invalid("expected hex `attestation` and `message` strings"))?;
+    hex::decode(value)
+        .map(|value| Some(value.into()))
+        .map_err(|_| invalid("expected hex `attestation` and `message` strings"))
+}
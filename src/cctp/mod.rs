@@ -0,0 +1,529 @@
+//! Addresses of Circle's Cross-Chain Transfer Protocol (CCTP) contracts.
+//!
+//! CCTP moves native USDC between chains by burning it on the source chain and
+//! minting it on the destination. A burn is submitted to the source chain's
+//! `TokenMessenger` with the destination's CCTP domain, a Circle-assigned ID
+//! that is distinct from the chain ID. Once Circle attests to the burn, the
+//! message and attestation are submitted to the destination chain's
+//! `MessageTransmitter`, which mints the USDC.
+//!
+//! CCTP V2 adds fast transfers and hooks through a second set of contracts,
+//! deployed at the same addresses on every supported chain. The lookups below
+//! without a version return the V1 contracts; see [`CctpVersion`] and
+//! [`cctp_route`] to pick a version per route.
+
+mod burn;
+#[cfg(feature = "cctp")]
+mod iris;
+#[cfg(feature = "cctp")]
+mod transfer;
+
+pub use burn::{
+    build_receive_message, decode_message_sent, DepositForBurn, CCTP_V2_FAST_FINALITY_THRESHOLD,
+    CCTP_V2_STANDARD_FINALITY_THRESHOLD, MESSAGE_SENT_TOPIC,
+};
+#[cfg(feature = "cctp")]
+pub use iris::{AttestationError, AttestedMessage, IrisClient};
+#[cfg(feature = "cctp")]
+pub use transfer::{CctpError, CctpProgress, CctpReceipt, CctpTransfer, TransactionSender};
+
+use crate::UsdcError;
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+
+/// The CCTP `TokenMessenger` on Ethereum mainnet.
+pub const ETHEREUM_CCTP_TOKEN_MESSENGER: Address =
+    address!("0xBd3fa81B58Ba92a82136038B25aDec7066af3155");
+/// The CCTP `TokenMessenger` on Avalanche C-Chain.
+pub const AVALANCHE_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x6B25532e1060CE10cc3B0A99e5683b91BFDe6982");
+/// The CCTP `TokenMessenger` on Optimism.
+pub const OPTIMISM_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x2B4069517957735bE00ceE0fadAE88a26365528f");
+/// The CCTP `TokenMessenger` on Arbitrum One.
+pub const ARBITRUM_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x19330d10D9Cc8751218eaf51E8885D058642E08A");
+/// The CCTP `TokenMessenger` on Base.
+pub const BASE_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x1682Ae6375C4E4A97e4B583BC394c861A46D8962");
+/// The CCTP `TokenMessenger` on Polygon PoS.
+pub const POLYGON_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x9daF8c91AEFAE50b9c0E69629D3F6Ca40cA3B3FE");
+/// The CCTP `TokenMessenger` on Unichain.
+pub const UNICHAIN_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x4e744b28E787c3aD0e810eD65A24461D4ac5a762");
+/// The CCTP `TokenMessenger` on Ethereum Sepolia.
+pub const ETHEREUM_SEPOLIA_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5");
+/// The CCTP `TokenMessenger` on Arbitrum Sepolia.
+pub const ARBITRUM_SEPOLIA_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5");
+/// The CCTP `TokenMessenger` on Base Sepolia.
+pub const BASE_SEPOLIA_CCTP_TOKEN_MESSENGER: Address =
+    address!("0x9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5");
+
+/// The CCTP `MessageTransmitter` on Ethereum mainnet.
+pub const ETHEREUM_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0x0a992d191DEeC32aFe36203Ad87D7d289a738F81");
+/// The CCTP `MessageTransmitter` on Avalanche C-Chain.
+pub const AVALANCHE_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0x8186359aF5F57FbB40c6b14A588d2A59C0C29880");
+/// The CCTP `MessageTransmitter` on Optimism.
+pub const OPTIMISM_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0x4D41f22c5a0e5c74090899E5a8Fb597a8842b3e8");
+/// The CCTP `MessageTransmitter` on Arbitrum One.
+pub const ARBITRUM_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0xC30362313FBBA5cf9163F0bb16a0e01f01A896ca");
+/// The CCTP `MessageTransmitter` on Base.
+pub const BASE_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0xAD09780d193884d503182aD4588450C416D6F9D4");
+/// The CCTP `MessageTransmitter` on Polygon PoS.
+pub const POLYGON_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0xF3be9355363857F3e001be68856A2f96b4C39Ba9");
+/// The CCTP `MessageTransmitter` on Unichain.
+pub const UNICHAIN_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0x353bE9E2E38AB1D19104534e4edC21c643Df86f4");
+/// The CCTP `MessageTransmitter` on Ethereum Sepolia.
+pub const ETHEREUM_SEPOLIA_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0x7865fAfC2db2093669d92c0F33AeEF291086BEFD");
+/// The CCTP `MessageTransmitter` on Arbitrum Sepolia.
+pub const ARBITRUM_SEPOLIA_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0xaCF1ceeF35caAc005e15888dDb8A3515C41B4872");
+/// The CCTP `MessageTransmitter` on Base Sepolia.
+pub const BASE_SEPOLIA_CCTP_MESSAGE_TRANSMITTER: Address =
+    address!("0x7865fAfC2db2093669d92c0F33AeEF291086BEFD");
+
+/// The CCTP `TokenMinter` on Ethereum mainnet.
+pub const ETHEREUM_CCTP_TOKEN_MINTER: Address =
+    address!("0xc4922d64a24675E16e1586e3e3Aa56C06fABe907");
+/// The CCTP `TokenMinter` on Avalanche C-Chain.
+pub const AVALANCHE_CCTP_TOKEN_MINTER: Address =
+    address!("0x420F5035fd5dC62a167E7e7f08B604335aE272b8");
+/// The CCTP `TokenMinter` on Optimism.
+pub const OPTIMISM_CCTP_TOKEN_MINTER: Address =
+    address!("0x33E76C5C31cb928dc6FE6487AB3b2C0769B1A1e3");
+/// The CCTP `TokenMinter` on Arbitrum One.
+pub const ARBITRUM_CCTP_TOKEN_MINTER: Address =
+    address!("0xE7Ed1fa7f45D05C508232aa32649D89b73b8bA48");
+/// The CCTP `TokenMinter` on Base.
+pub const BASE_CCTP_TOKEN_MINTER: Address = address!("0xe45B133ddc64bE80252b0e9c75A8E74EF280eEd6");
+/// The CCTP `TokenMinter` on Polygon PoS.
+pub const POLYGON_CCTP_TOKEN_MINTER: Address =
+    address!("0x10f7835F827D6Cf035115E10c50A853d7FB2D2EC");
+/// The CCTP `TokenMinter` on Unichain.
+pub const UNICHAIN_CCTP_TOKEN_MINTER: Address =
+    address!("0x726bFEF3cBb3f8AF7d8CB141E78F86Ae43C34163");
+/// The CCTP `TokenMinter` on Ethereum Sepolia.
+pub const ETHEREUM_SEPOLIA_CCTP_TOKEN_MINTER: Address =
+    address!("0xE997d7d2F6E065a9A93Fa2175E878Fb9081F1f0A");
+/// The CCTP `TokenMinter` on Arbitrum Sepolia.
+pub const ARBITRUM_SEPOLIA_CCTP_TOKEN_MINTER: Address =
+    address!("0xE997d7d2F6E065a9A93Fa2175E878Fb9081F1f0A");
+/// The CCTP `TokenMinter` on Base Sepolia.
+pub const BASE_SEPOLIA_CCTP_TOKEN_MINTER: Address =
+    address!("0xE997d7d2F6E065a9A93Fa2175E878Fb9081F1f0A");
+
+/// The CCTP V2 `TokenMessengerV2`, at the same address on every mainnet.
+pub const CCTP_V2_TOKEN_MESSENGER: Address = address!("0x28b5a0e9C621a5BadaA536219b3a228C8168cf5d");
+/// The CCTP V2 `MessageTransmitterV2`, at the same address on every mainnet.
+pub const CCTP_V2_MESSAGE_TRANSMITTER: Address =
+    address!("0x81D40F21F12A8F0E3252Bccb954D722d4c464B64");
+/// The CCTP V2 `TokenMinterV2`, at the same address on every mainnet.
+pub const CCTP_V2_TOKEN_MINTER: Address = address!("0xfd78EE919681417d192449715b2594ab58f5D002");
+/// The CCTP V2 `TokenMessengerV2`, at the same address on every testnet.
+pub const CCTP_V2_TESTNET_TOKEN_MESSENGER: Address =
+    address!("0x8FE6B999Dc680CcFDD5Bf7EB0974218be2542DAA");
+/// The CCTP V2 `MessageTransmitterV2`, at the same address on every testnet.
+pub const CCTP_V2_TESTNET_MESSAGE_TRANSMITTER: Address =
+    address!("0xE737e5cEBEEBa77EFE34D4aa090756590b1CE275");
+/// The CCTP V2 `TokenMinterV2`, at the same address on every testnet.
+pub const CCTP_V2_TESTNET_TOKEN_MINTER: Address =
+    address!("0xb43db544E2c27092c107639Ad201b3dEfAbcF192");
+
+/// A release of the CCTP contracts. Availability differs by chain, and both
+/// ends of a transfer must use the same version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum CctpVersion {
+    /// The original release, where a transfer waits for hard finality.
+    V1,
+    /// Adds fast transfers, which are attested before hard finality for a fee,
+    /// and post-mint hooks.
+    V2,
+}
+
+impl CctpVersion {
+    /// Returns `true` if this version is deployed on `chain`.
+    pub const fn is_available(&self, chain: NamedChain) -> bool {
+        match self {
+            Self::V1 => token_messenger_on(chain).is_some(),
+            Self::V2 => v2_contracts_on(chain).is_some(),
+        }
+    }
+}
+
+/// The V2 contracts on `chain`: the mainnet set, or the testnet set.
+const fn v2_contracts_on(chain: NamedChain) -> Option<(Address, Address, Address)> {
+    match v2_network_of(chain) {
+        Some(V2Network::Mainnet) => Some((
+            CCTP_V2_TOKEN_MESSENGER,
+            CCTP_V2_MESSAGE_TRANSMITTER,
+            CCTP_V2_TOKEN_MINTER,
+        )),
+        Some(V2Network::Testnet) => Some((
+            CCTP_V2_TESTNET_TOKEN_MESSENGER,
+            CCTP_V2_TESTNET_MESSAGE_TRANSMITTER,
+            CCTP_V2_TESTNET_TOKEN_MINTER,
+        )),
+        None => None,
+    }
+}
+
+/// Which set of V2 contracts a chain has.
+enum V2Network {
+    Mainnet,
+    Testnet,
+}
+
+const fn v2_network_of(chain: NamedChain) -> Option<V2Network> {
+    let supported = match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum
+        | NamedChain::Base
+        | NamedChain::Linea
+        | NamedChain::Optimism
+        | NamedChain::Unichain => true,
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche | NamedChain::Polygon => true,
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => true,
+        #[cfg(feature = "testnets")]
+        NamedChain::ArbitrumSepolia | NamedChain::BaseSepolia | NamedChain::Sepolia => true,
+        _ => false,
+    };
+    if !supported {
+        None
+    } else if chain.is_testnet() {
+        Some(V2Network::Testnet)
+    } else {
+        Some(V2Network::Mainnet)
+    }
+}
+
+pub(crate) const fn token_messenger_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "testnets")]
+        NamedChain::ArbitrumSepolia => Some(ARBITRUM_SEPOLIA_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "testnets")]
+        NamedChain::BaseSepolia => Some(BASE_SEPOLIA_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "testnets")]
+        NamedChain::Sepolia => Some(ETHEREUM_SEPOLIA_CCTP_TOKEN_MESSENGER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Unichain => Some(UNICHAIN_CCTP_TOKEN_MESSENGER),
+        _ => None,
+    }
+}
+
+pub(crate) const fn token_minter_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "testnets")]
+        NamedChain::ArbitrumSepolia => Some(ARBITRUM_SEPOLIA_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "testnets")]
+        NamedChain::BaseSepolia => Some(BASE_SEPOLIA_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "testnets")]
+        NamedChain::Sepolia => Some(ETHEREUM_SEPOLIA_CCTP_TOKEN_MINTER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Unichain => Some(UNICHAIN_CCTP_TOKEN_MINTER),
+        _ => None,
+    }
+}
+
+pub(crate) const fn message_transmitter_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "testnets")]
+        NamedChain::ArbitrumSepolia => Some(ARBITRUM_SEPOLIA_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "testnets")]
+        NamedChain::BaseSepolia => Some(BASE_SEPOLIA_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "testnets")]
+        NamedChain::Sepolia => Some(ETHEREUM_SEPOLIA_CCTP_MESSAGE_TRANSMITTER),
+        #[cfg(feature = "l2s")]
+        NamedChain::Unichain => Some(UNICHAIN_CCTP_MESSAGE_TRANSMITTER),
+        _ => None,
+    }
+}
+
+/// Returns the CCTP domain of `chain`. Testnets share the domain of their
+/// mainnet, e.g. `0` for both Ethereum and Sepolia.
+pub(crate) const fn domain_of(chain: NamedChain) -> Option<u32> {
+    match chain {
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(0),
+        #[cfg(feature = "testnets")]
+        NamedChain::Sepolia => Some(0),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(1),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(2),
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(3),
+        #[cfg(feature = "testnets")]
+        NamedChain::ArbitrumSepolia => Some(3),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(6),
+        #[cfg(feature = "testnets")]
+        NamedChain::BaseSepolia => Some(6),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(7),
+        #[cfg(feature = "l2s")]
+        NamedChain::Unichain => Some(10),
+        #[cfg(feature = "l2s")]
+        NamedChain::Linea => Some(11),
+        _ => None,
+    }
+}
+
+/// The CCTP deployment on one chain, returned by [`cctp_contracts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CctpContracts {
+    /// The CCTP release the contracts belong to.
+    pub version: CctpVersion,
+    /// The chain's CCTP domain.
+    pub domain: u32,
+    /// Burns USDC with `depositForBurn` on the source chain.
+    pub token_messenger: Address,
+    /// Receives attested messages with `receiveMessage` on the destination
+    /// chain.
+    pub message_transmitter: Address,
+    /// Mints and burns USDC on behalf of the `TokenMessenger`, and enforces
+    /// burn limits.
+    pub token_minter: Address,
+}
+
+/// Returns every CCTP V1 contract on `chain`, together with its domain.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{cctp_contracts, ETHEREUM_CCTP_TOKEN_MINTER};
+/// use alloy_chains::NamedChain;
+///
+/// let contracts = cctp_contracts(NamedChain::Mainnet).unwrap();
+/// assert_eq!(contracts.domain, 0);
+/// assert_eq!(contracts.token_minter, ETHEREUM_CCTP_TOKEN_MINTER);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If CCTP is not deployed on the chain.
+pub fn cctp_contracts(chain: NamedChain) -> Result<CctpContracts, UsdcError> {
+    Ok(CctpContracts {
+        version: CctpVersion::V1,
+        domain: cctp_domain(chain)?,
+        token_messenger: cctp_token_messenger(chain)?,
+        message_transmitter: cctp_message_transmitter(chain)?,
+        token_minter: cctp_token_minter(chain)?,
+    })
+}
+
+/// Returns the CCTP contracts of `version` on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{cctp_contracts_for, CctpVersion, CCTP_V2_TOKEN_MESSENGER};
+/// use alloy_chains::NamedChain;
+///
+/// let v2 = cctp_contracts_for(NamedChain::Linea, CctpVersion::V2).unwrap();
+/// assert_eq!(v2.domain, 11);
+/// assert_eq!(v2.token_messenger, CCTP_V2_TOKEN_MESSENGER);
+///
+/// // Linea only has the V2 contracts.
+/// assert!(cctp_contracts_for(NamedChain::Linea, CctpVersion::V1).is_err());
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If `version` is not deployed on the chain.
+pub fn cctp_contracts_for(
+    chain: NamedChain,
+    version: CctpVersion,
+) -> Result<CctpContracts, UsdcError> {
+    match version {
+        CctpVersion::V1 => cctp_contracts(chain),
+        CctpVersion::V2 => {
+            let (token_messenger, message_transmitter, token_minter) =
+                v2_contracts_on(chain).ok_or(UsdcError::UnsupportedChain(chain))?;
+            Ok(CctpContracts {
+                version,
+                domain: cctp_domain(chain)?,
+                token_messenger,
+                message_transmitter,
+                token_minter,
+            })
+        }
+    }
+}
+
+/// Returns the newest CCTP version deployed on both `source` and
+/// `destination`, preferring V2 for its fast transfers.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{cctp_route, CctpVersion};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(cctp_route(NamedChain::Base, NamedChain::Linea).unwrap(), CctpVersion::V2);
+/// assert!(cctp_route(NamedChain::Base, NamedChain::BinanceSmartChain).is_err());
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chains share no CCTP version,
+///   naming the first chain that lacks one.
+pub fn cctp_route(source: NamedChain, destination: NamedChain) -> Result<CctpVersion, UsdcError> {
+    [CctpVersion::V2, CctpVersion::V1]
+        .into_iter()
+        .find(|version| version.is_available(source) && version.is_available(destination))
+        .ok_or_else(|| {
+            let unsupported = if domain_of(source).is_none() {
+                source
+            } else {
+                destination
+            };
+            UsdcError::UnsupportedChain(unsupported)
+        })
+}
+
+/// Returns the CCTP V1 `TokenMessenger` on `chain`, the contract burns are
+/// submitted to with `depositForBurn`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{cctp_token_messenger, UsdcError, BASE_CCTP_TOKEN_MESSENGER};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(cctp_token_messenger(NamedChain::Base).unwrap(), BASE_CCTP_TOKEN_MESSENGER);
+/// assert!(matches!(
+///     cctp_token_messenger(NamedChain::BinanceSmartChain),
+///     Err(UsdcError::UnsupportedChain(NamedChain::BinanceSmartChain))
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If CCTP is not deployed on the chain.
+pub fn cctp_token_messenger(chain: NamedChain) -> Result<Address, UsdcError> {
+    token_messenger_on(chain).ok_or(UsdcError::UnsupportedChain(chain))
+}
+
+/// Returns the CCTP V1 `MessageTransmitter` on `chain`, the contract attested
+/// messages are submitted to with `receiveMessage` to mint on the destination.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{cctp_message_transmitter, ARBITRUM_CCTP_MESSAGE_TRANSMITTER};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(
+///     cctp_message_transmitter(NamedChain::Arbitrum).unwrap(),
+///     ARBITRUM_CCTP_MESSAGE_TRANSMITTER
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If CCTP is not deployed on the chain.
+pub fn cctp_message_transmitter(chain: NamedChain) -> Result<Address, UsdcError> {
+    message_transmitter_on(chain).ok_or(UsdcError::UnsupportedChain(chain))
+}
+
+/// Returns the CCTP V1 `TokenMinter` on `chain`, which holds the per-message burn
+/// limits.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "alt-l1s", doc = "```rust")]
+#[cfg_attr(not(feature = "alt-l1s"), doc = "```ignore")]
+/// use usdshe::{cctp_token_minter, POLYGON_CCTP_TOKEN_MINTER};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(cctp_token_minter(NamedChain::Polygon).unwrap(), POLYGON_CCTP_TOKEN_MINTER);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If CCTP is not deployed on the chain.
+pub fn cctp_token_minter(chain: NamedChain) -> Result<Address, UsdcError> {
+    token_minter_on(chain).ok_or(UsdcError::UnsupportedChain(chain))
+}
+
+/// Returns the CCTP domain of `chain`, passed as `destinationDomain` when
+/// burning USDC for that chain.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "ethereum", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "ethereum", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::cctp_domain;
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(cctp_domain(NamedChain::Mainnet).unwrap(), 0);
+/// assert_eq!(cctp_domain(NamedChain::Arbitrum).unwrap(), 3);
+/// assert_eq!(cctp_domain(NamedChain::Base).unwrap(), 6);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If CCTP is not deployed on the chain.
+pub fn cctp_domain(chain: NamedChain) -> Result<u32, UsdcError> {
+    domain_of(chain).ok_or(UsdcError::UnsupportedChain(chain))
+}
@@ -0,0 +1,234 @@
+use super::{
+    build_receive_message, cctp_token_messenger, decode_message_sent, AttestationError,
+    CctpVersion, DepositForBurn, IrisClient,
+};
+use crate::{build_usdc_approve_raw, remote::HttpClient, TransactionRequest, UsdcError};
+use alloy_chains::NamedChain;
+use alloy_primitives::{keccak256, Bytes, Log, B256};
+use core::future::Future;
+use std::error::Error as StdError;
+use thiserror::Error;
+
+/// Signs and submits transactions from the application's wallet on one chain.
+///
+/// Like [`HttpClient`], this is a small trait that applications implement on
+/// top of whichever wallet and RPC client they already use.
+pub trait TransactionSender {
+    /// The error returned when a transaction cannot be sent or reverts.
+    type Error: StdError + Send + Sync + 'static;
+
+    /// Signs and sends `tx`, waits until it is mined, and returns the logs of
+    /// its receipt. Must fail if the transaction reverts.
+    fn send_transaction(
+        &self,
+        tx: TransactionRequest,
+    ) -> impl Future<Output = Result<Vec<Log>, Self::Error>> + Send;
+}
+
+impl<S: TransactionSender + ?Sized> TransactionSender for &S {
+    type Error = S::Error;
+
+    fn send_transaction(
+        &self,
+        tx: TransactionRequest,
+    ) -> impl Future<Output = Result<Vec<Log>, Self::Error>> + Send {
+        (**self).send_transaction(tx)
+    }
+}
+
+/// A step of a [`CctpTransfer`] that has completed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CctpProgress {
+    /// The source chain's `TokenMessenger` may spend the amount.
+    Approved,
+    /// The USDC was burned on the source chain.
+    Burned {
+        /// The hash the attestation service knows the message by.
+        message_hash: B256,
+    },
+    /// Circle attested to the burn.
+    Attested {
+        /// The hash of the attested message.
+        message_hash: B256,
+    },
+    /// The USDC was minted on the destination chain.
+    Minted,
+}
+
+/// The outcome of a completed [`CctpTransfer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CctpReceipt {
+    /// The CCTP message emitted by the burn.
+    pub message: Bytes,
+    /// The keccak-256 hash of [`message`](Self::message).
+    pub message_hash: B256,
+    /// Circle's attestation of the message.
+    pub attestation: Bytes,
+    /// The logs of the `receiveMessage` transaction on the destination chain.
+    pub mint_logs: Vec<Log>,
+}
+
+/// Represents errors that can occur during a [`CctpTransfer`].
+#[derive(Error, Debug)]
+pub enum CctpError {
+    /// A chain has no known USDC or CCTP deployment.
+    #[error(transparent)]
+    Usdc(#[from] UsdcError),
+
+    /// The transfer uses a version the orchestrator cannot complete.
+    #[error("{0:?} transfers are not supported by CctpTransfer")]
+    UnsupportedVersion(CctpVersion),
+
+    /// A transaction could not be sent or reverted.
+    #[error("Transaction on {chain} failed: {source}")]
+    Transaction {
+        /// The chain the transaction was sent to.
+        chain: NamedChain,
+        /// The error reported by the [`TransactionSender`].
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+
+    /// The burn receipt holds no `MessageSent` log.
+    #[error("No MessageSent event in the burn receipt on {0}")]
+    MissingMessage(NamedChain),
+
+    /// The attestation could not be fetched.
+    #[error(transparent)]
+    Attestation(#[from] AttestationError),
+}
+
+/// Runs a CCTP transfer end to end: approve, `depositForBurn`, poll for the
+/// attestation, and `receiveMessage` on the destination chain.
+///
+/// Attestations are looked up by message hash, which only identifies V1
+/// messages; V2 transfers are rejected with [`CctpError::UnsupportedVersion`].
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{
+///     CctpProgress, CctpTransfer, DepositForBurn, HttpClient, HttpRequest, HttpResponse,
+///     IrisClient, TransactionRequest, TransactionSender, MESSAGE_SENT_TOPIC,
+/// };
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, Log, B256, U256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Iris;
+/// # impl HttpClient for Iris {
+/// #     type Error = std::io::Error;
+/// #     async fn get(&self, _: HttpRequest) -> Result<HttpResponse, Self::Error> {
+/// #         Ok(HttpResponse { status: 200, body: r#"{"attestation": "0xabcd", "status": "complete"}"#.into() })
+/// #     }
+/// #     async fn sleep(&self, _: std::time::Duration) {}
+/// # }
+/// # struct Wallet;
+/// # impl TransactionSender for Wallet {
+/// #     type Error = std::io::Error;
+/// #     async fn send_transaction(&self, tx: TransactionRequest) -> Result<Vec<Log>, Self::Error> {
+/// #         let mut data = B256::from(U256::from(32).to_be_bytes::<32>()).to_vec();
+/// #         data.extend(B256::from(U256::from(1).to_be_bytes::<32>()));
+/// #         data.extend([0x42; 32]);
+/// #         Ok(vec![Log::new(tx.to, vec![MESSAGE_SENT_TOPIC], data.into()).unwrap()])
+/// #     }
+/// # }
+///
+/// // `Wallet` implements `TransactionSender`, and `Iris` implements `HttpClient`.
+/// let recipient = address!("0x1111111111111111111111111111111111111111");
+/// let burn = DepositForBurn::new(NamedChain::Base, NamedChain::Arbitrum, U256::from(25_000_000), recipient);
+/// let transfer = CctpTransfer::new(burn, IrisClient::new(Iris));
+///
+/// let mut steps = Vec::new();
+/// let receipt = block_on(transfer.execute(&Wallet, &Wallet, |step| steps.push(step))).unwrap();
+/// assert_eq!(receipt.attestation[..], [0xab, 0xcd]);
+/// assert_eq!(steps.last(), Some(&CctpProgress::Minted));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CctpTransfer<C> {
+    burn: DepositForBurn,
+    iris: IrisClient<C>,
+}
+
+impl<C> CctpTransfer<C> {
+    /// Creates a transfer that submits `burn` and fetches its attestation
+    /// through `iris`.
+    pub fn new(burn: DepositForBurn, iris: IrisClient<C>) -> Self {
+        Self { burn, iris }
+    }
+}
+
+impl<C: HttpClient> CctpTransfer<C> {
+    /// Runs the transfer, sending the approval and burn through `source` and
+    /// the mint through `destination`, and reporting each completed step to
+    /// `on_progress`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CctpError`] at the first step that fails. Steps already
+    /// completed are not undone: a failure after the burn leaves the message
+    /// to be completed with [`build_receive_message`] once attested.
+    pub async fn execute<S: TransactionSender, D: TransactionSender>(
+        &self,
+        source: S,
+        destination: D,
+        mut on_progress: impl FnMut(CctpProgress),
+    ) -> Result<CctpReceipt, CctpError> {
+        let burn = &self.burn;
+        if burn.version != CctpVersion::V1 {
+            return Err(CctpError::UnsupportedVersion(burn.version));
+        }
+        let deposit = burn.build()?;
+        let messenger = cctp_token_messenger(burn.source)?;
+        let approve = build_usdc_approve_raw(burn.source, messenger, burn.amount)?;
+
+        send(&source, burn.source, approve).await?;
+        on_progress(CctpProgress::Approved);
+
+        let burn_logs = send(&source, burn.source, deposit).await?;
+        let message = burn_logs
+            .iter()
+            .find_map(decode_message_sent)
+            .ok_or(CctpError::MissingMessage(burn.source))?;
+        let message_hash = keccak256(&message);
+        on_progress(CctpProgress::Burned { message_hash });
+
+        let attestation = self.iris.wait_for_attestation(message_hash).await?;
+        on_progress(CctpProgress::Attested { message_hash });
+
+        let receive =
+            build_receive_message(burn.destination, burn.version, &message, &attestation)?;
+        let mint_logs = send(&destination, burn.destination, receive).await?;
+        on_progress(CctpProgress::Minted);
+
+        Ok(CctpReceipt {
+            message,
+            message_hash,
+            attestation,
+            mint_logs,
+        })
+    }
+}
+
+async fn send<S: TransactionSender>(
+    sender: &S,
+    chain: NamedChain,
+    tx: TransactionRequest,
+) -> Result<Vec<Log>, CctpError> {
+    sender
+        .send_transaction(tx)
+        .await
+        .map_err(|source| CctpError::Transaction {
+            chain,
+            source: Box::new(source),
+        })
+}
@@ -0,0 +1,690 @@
+use crate::{address::*, FiatTokenVersion, Usdc, UsdcError, UsdcTokenInfo, UsdcVariant};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+#[cfg(any(feature = "config", feature = "remote-defillama", feature = "server"))]
+use alloy_chains::Chain;
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+use core::str::FromStr;
+
+/// The chains for which this crate knows a USDC address.
+///
+/// Unlike [`NamedChain`], every variant of this enum is guaranteed to have a
+/// USDC address, so [`SupportedUsdcChain::usdc_address`] is infallible. Convert
+/// from a [`NamedChain`] once with [`TryFrom`] and look up addresses freely
+/// afterwards.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{SupportedUsdcChain, UsdcError, BASE_USDC};
+/// use alloy_chains::NamedChain;
+///
+/// let chain = SupportedUsdcChain::try_from(NamedChain::Base).unwrap();
+/// assert_eq!(chain.usdc_address(), BASE_USDC);
+/// assert_eq!(NamedChain::from(chain), NamedChain::Base);
+///
+/// assert!(matches!(
+///     SupportedUsdcChain::try_from(NamedChain::Gnosis),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Gnosis))
+/// ));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "NamedChain", try_from = "NamedChain")
+)]
+pub enum SupportedUsdcChain {
+    #[cfg(feature = "l2s")]
+    Arbitrum,
+    #[cfg(feature = "testnets")]
+    ArbitrumSepolia,
+    #[cfg(feature = "alt-l1s")]
+    Avalanche,
+    #[cfg(feature = "l2s")]
+    Base,
+    #[cfg(feature = "testnets")]
+    BaseSepolia,
+    #[cfg(feature = "alt-l1s")]
+    Berachain,
+    #[cfg(feature = "alt-l1s")]
+    BinanceSmartChain,
+    #[cfg(feature = "alt-l1s")]
+    Fantom,
+    #[cfg(feature = "l2s")]
+    Fraxtal,
+    #[cfg(feature = "l2s")]
+    Linea,
+    #[cfg(feature = "ethereum")]
+    Mainnet,
+    #[cfg(feature = "l2s")]
+    Mantle,
+    #[cfg(feature = "l2s")]
+    Mode,
+    #[cfg(feature = "l2s")]
+    Optimism,
+    #[cfg(feature = "alt-l1s")]
+    Polygon,
+    #[cfg(feature = "l2s")]
+    Scroll,
+    #[cfg(feature = "testnets")]
+    Sepolia,
+    #[cfg(feature = "alt-l1s")]
+    Sonic,
+    #[cfg(feature = "l2s")]
+    Unichain,
+    #[cfg(feature = "l2s")]
+    ZkSync,
+}
+
+impl SupportedUsdcChain {
+    /// Every supported chain, in declaration order.
+    pub const ALL: &'static [SupportedUsdcChain] = &[
+        #[cfg(feature = "l2s")]
+        Self::Arbitrum,
+        #[cfg(feature = "testnets")]
+        Self::ArbitrumSepolia,
+        #[cfg(feature = "alt-l1s")]
+        Self::Avalanche,
+        #[cfg(feature = "l2s")]
+        Self::Base,
+        #[cfg(feature = "testnets")]
+        Self::BaseSepolia,
+        #[cfg(feature = "alt-l1s")]
+        Self::Berachain,
+        #[cfg(feature = "alt-l1s")]
+        Self::BinanceSmartChain,
+        #[cfg(feature = "alt-l1s")]
+        Self::Fantom,
+        #[cfg(feature = "l2s")]
+        Self::Fraxtal,
+        #[cfg(feature = "l2s")]
+        Self::Linea,
+        #[cfg(feature = "ethereum")]
+        Self::Mainnet,
+        #[cfg(feature = "l2s")]
+        Self::Mantle,
+        #[cfg(feature = "l2s")]
+        Self::Mode,
+        #[cfg(feature = "l2s")]
+        Self::Optimism,
+        #[cfg(feature = "alt-l1s")]
+        Self::Polygon,
+        #[cfg(feature = "l2s")]
+        Self::Scroll,
+        #[cfg(feature = "testnets")]
+        Self::Sepolia,
+        #[cfg(feature = "alt-l1s")]
+        Self::Sonic,
+        #[cfg(feature = "l2s")]
+        Self::Unichain,
+        #[cfg(feature = "l2s")]
+        Self::ZkSync,
+    ];
+
+    /// Returns the USDC contract address on this chain.
+    pub const fn usdc_address(&self) -> Address {
+        match *self {
+            #[cfg(feature = "l2s")]
+            Self::Arbitrum => ARBITRUM_USDC,
+            #[cfg(feature = "testnets")]
+            Self::ArbitrumSepolia => ARBITRUM_SEPOLIA_USDC,
+            #[cfg(feature = "alt-l1s")]
+            Self::Avalanche => AVALANCHE_USDC,
+            #[cfg(feature = "l2s")]
+            Self::Base => BASE_USDC,
+            #[cfg(feature = "testnets")]
+            Self::BaseSepolia => BASE_SEPOLIA_USDC,
+            #[cfg(feature = "alt-l1s")]
+            Self::Berachain => BERACHAIN_USDC,
+            #[cfg(feature = "alt-l1s")]
+            Self::BinanceSmartChain => BSC_USDC,
+            #[cfg(feature = "alt-l1s")]
+            Self::Fantom => FANTOM_USDC,
+            #[cfg(feature = "l2s")]
+            Self::Fraxtal => FRAXTAL_USDC,
+            #[cfg(feature = "l2s")]
+            Self::Linea => LINEA_USDC,
+            #[cfg(feature = "ethereum")]
+            Self::Mainnet => ETHEREUM_USDC,
+            #[cfg(feature = "l2s")]
+            Self::Mantle => MANTLE_USDC,
+            #[cfg(feature = "l2s")]
+            Self::Mode => MODE_USDC,
+            #[cfg(feature = "l2s")]
+            Self::Optimism => OPTIMISM_USDC,
+            #[cfg(feature = "alt-l1s")]
+            Self::Polygon => POLYGON_USDC,
+            #[cfg(feature = "l2s")]
+            Self::Scroll => SCROLL_USDC,
+            #[cfg(feature = "testnets")]
+            Self::Sepolia => ETHEREUM_SEPOLIA_USDC,
+            #[cfg(feature = "alt-l1s")]
+            Self::Sonic => SONIC_USDC,
+            #[cfg(feature = "l2s")]
+            Self::Unichain => UNICHAIN_USDC,
+            #[cfg(feature = "l2s")]
+            Self::ZkSync => ZKSYNC_USDC,
+        }
+    }
+
+    /// Returns the legacy bridged USDC.e address on this chain, if one exists.
+    ///
+    /// These are the tokens minted by the chain's canonical bridge before Circle
+    /// issued native USDC there. [`SupportedUsdcChain::usdc_address`] always returns
+    /// the native token where one exists.
+    ///
+    #[cfg_attr(feature = "l2s", doc = "```rust")]
+    #[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+    /// use usdshe::{SupportedUsdcChain, ARBITRUM_USDC_E};
+    ///
+    /// assert_eq!(SupportedUsdcChain::Arbitrum.usdc_e_address(), Some(ARBITRUM_USDC_E));
+    /// assert_eq!(SupportedUsdcChain::Base.usdc_e_address(), None);
+    /// ```
+    pub const fn usdc_e_address(&self) -> Option<Address> {
+        match *self {
+            #[cfg(feature = "l2s")]
+            Self::Arbitrum => Some(ARBITRUM_USDC_E),
+            #[cfg(feature = "alt-l1s")]
+            Self::Avalanche => Some(AVALANCHE_USDC_E),
+            #[cfg(feature = "l2s")]
+            Self::Optimism => Some(OPTIMISM_USDC_E),
+            #[cfg(feature = "alt-l1s")]
+            Self::Polygon => Some(POLYGON_USDC_E),
+            _ => None,
+        }
+    }
+
+    /// Returns who issues the USDC token on this chain.
+    ///
+    #[cfg_attr(all(feature = "alt-l1s", feature = "l2s"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "alt-l1s", feature = "l2s")), doc = "```ignore")]
+    /// use usdshe::{SupportedUsdcChain, UsdcVariant};
+    ///
+    /// assert_eq!(SupportedUsdcChain::Base.usdc_variant(), UsdcVariant::Native);
+    /// assert_eq!(SupportedUsdcChain::BinanceSmartChain.usdc_variant(), UsdcVariant::BinancePeg);
+    /// ```
+    pub const fn usdc_variant(&self) -> UsdcVariant {
+        use UsdcVariant::*;
+
+        match *self {
+            #[cfg(feature = "alt-l1s")]
+            Self::Berachain => BridgedUsdcE,
+            #[cfg(feature = "alt-l1s")]
+            Self::BinanceSmartChain => BinancePeg,
+            #[cfg(feature = "alt-l1s")]
+            Self::Fantom => MultichainBridged,
+            #[cfg(feature = "l2s")]
+            Self::Fraxtal => BridgedUsdcE,
+            #[cfg(feature = "l2s")]
+            Self::Mantle => BridgedUsdcE,
+            #[cfg(feature = "l2s")]
+            Self::Mode => BridgedUsdcE,
+            #[cfg(feature = "l2s")]
+            Self::Scroll => BridgedUsdcE,
+            #[cfg(feature = "alt-l1s")]
+            Self::Sonic => BridgedUsdcE,
+            _ => Native,
+        }
+    }
+
+    /// Returns the number of decimals used by the USDC token on this chain.
+    ///
+    /// Circle-issued USDC uses 6 decimals everywhere, but bridged tokens may not:
+    /// Binance-Peg USDC on BNB Smart Chain uses 18.
+    ///
+    #[cfg_attr(all(feature = "alt-l1s", feature = "ethereum"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "alt-l1s", feature = "ethereum")), doc = "```ignore")]
+    /// use usdshe::SupportedUsdcChain;
+    ///
+    /// assert_eq!(SupportedUsdcChain::Mainnet.usdc_decimals(), 6);
+    /// assert_eq!(SupportedUsdcChain::BinanceSmartChain.usdc_decimals(), 18);
+    /// ```
+    pub const fn usdc_decimals(&self) -> u8 {
+        match *self {
+            #[cfg(feature = "alt-l1s")]
+            Self::BinanceSmartChain => 18,
+            _ => 6,
+        }
+    }
+
+    /// Returns the implementation contract behind the USDC proxy on this chain,
+    /// where recorded.
+    ///
+    /// The registered [`usdc_address`](Self::usdc_address) is a proxy, so this
+    /// value changes whenever Circle upgrades the token; compare it with the live
+    /// proxy slot to notice upgrades.
+    ///
+    #[cfg_attr(feature = "ethereum", doc = "```rust")]
+    #[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+    /// use usdshe::SupportedUsdcChain;
+    /// use alloy_primitives::address;
+    ///
+    /// assert_eq!(
+    ///     SupportedUsdcChain::Mainnet.usdc_implementation(),
+    ///     Some(address!("0x43506849D7C04F9138D1A2050bbF3A0c054402dd"))
+    /// );
+    /// ```
+    pub const fn usdc_implementation(&self) -> Option<Address> {
+        match self.named_chain() {
+            // FiatTokenV2_2
+            NamedChain::Mainnet => Some(address!("0x43506849D7C04F9138D1A2050bbF3A0c054402dd")),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the USDC contract on this chain is a FiatToken: native
+    /// USDC, and Circle's Bridged USDC Standard tokens on Berachain, Scroll and
+    /// Sonic. Other bridged tokens, such as Binance-Peg USDC or the OP Stack
+    /// bridge tokens on Mantle, Mode and Fraxtal, are not.
+    ///
+    #[cfg_attr(feature = "l2s", doc = "```rust")]
+    #[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+    /// use usdshe::SupportedUsdcChain;
+    ///
+    /// assert!(SupportedUsdcChain::Base.is_fiat_token());
+    /// assert!(SupportedUsdcChain::Scroll.is_fiat_token());
+    /// assert!(!SupportedUsdcChain::Mantle.is_fiat_token());
+    /// ```
+    pub const fn is_fiat_token(&self) -> bool {
+        match self.usdc_variant() {
+            UsdcVariant::Native => true,
+            UsdcVariant::BridgedUsdcE => matches!(
+                self.named_chain(),
+                NamedChain::Berachain | NamedChain::Scroll | NamedChain::Sonic
+            ),
+            _ => false,
+        }
+    }
+
+    /// Returns the FiatToken release the USDC contract on this chain runs, where
+    /// recorded.
+    ///
+    /// `None` means the release is not recorded, which is the case both for
+    /// tokens that are not FiatToken contracts and for FiatToken deployments
+    /// whose release has not been confirmed yet, e.g. on Linea or zkSync. Tell
+    /// them apart with [`is_fiat_token`](Self::is_fiat_token).
+    ///
+    #[cfg_attr(all(feature = "alt-l1s", feature = "l2s"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "alt-l1s", feature = "l2s")), doc = "```ignore")]
+    /// use usdshe::{FiatTokenVersion, SupportedUsdcChain};
+    ///
+    /// assert_eq!(SupportedUsdcChain::Base.fiat_token_version(), Some(FiatTokenVersion::V2_2));
+    ///
+    /// // A FiatToken whose release is not recorded.
+    /// assert_eq!(SupportedUsdcChain::Linea.fiat_token_version(), None);
+    /// assert!(SupportedUsdcChain::Linea.is_fiat_token());
+    ///
+    /// // Not a FiatToken at all.
+    /// assert_eq!(SupportedUsdcChain::BinanceSmartChain.fiat_token_version(), None);
+    /// assert!(!SupportedUsdcChain::BinanceSmartChain.is_fiat_token());
+    /// ```
+    pub const fn fiat_token_version(&self) -> Option<FiatTokenVersion> {
+        match self.named_chain() {
+            NamedChain::Arbitrum
+            | NamedChain::Avalanche
+            | NamedChain::Base
+            | NamedChain::Mainnet
+            | NamedChain::Optimism
+            | NamedChain::Polygon => Some(FiatTokenVersion::V2_2),
+            _ => None,
+        }
+    }
+
+    /// Returns the token metadata for USDC on this chain.
+    ///
+    #[cfg_attr(feature = "ethereum", doc = "```rust")]
+    #[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+    /// use usdshe::{SupportedUsdcChain, UsdcVariant, ETHEREUM_USDC};
+    ///
+    /// let info = SupportedUsdcChain::Mainnet.usdc_token_info();
+    /// assert_eq!(info.address, ETHEREUM_USDC);
+    /// assert_eq!(info.decimals, 6);
+    /// assert_eq!(info.symbol, "USDC");
+    /// assert_eq!(info.variant, UsdcVariant::Native);
+    /// assert_eq!(
+    ///     info.explorer_url,
+    ///     "https://etherscan.io/token/0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+    /// );
+    /// ```
+    pub fn usdc_token_info(&self) -> UsdcTokenInfo {
+        let address = self.usdc_address();
+        let (symbol, name) = match *self {
+            #[cfg(feature = "alt-l1s")]
+            Self::Berachain => ("USDC.e", "Bridged USDC (Stargate)"),
+            #[cfg(feature = "alt-l1s")]
+            Self::Sonic => ("USDC.e", "Bridged USDC (Sonic Labs)"),
+            #[cfg(feature = "testnets")]
+            Self::BaseSepolia | Self::Sepolia => ("USDC", "USDC"),
+            _ => ("USDC", "USD Coin"),
+        };
+        let deployed_block = match self.named_chain() {
+            NamedChain::Mainnet => Some(6_082_465),
+            _ => None,
+        };
+
+        UsdcTokenInfo {
+            address,
+            decimals: self.usdc_decimals(),
+            symbol: symbol.to_string(),
+            name: name.to_string(),
+            variant: self.usdc_variant(),
+            explorer_url: self.usdc_explorer_url(),
+            deployed_block,
+            fiat_token_version: self.fiat_token_version(),
+        }
+    }
+
+    /// Returns the USDC token page on the chain's canonical block explorer,
+    /// e.g. Etherscan, Basescan or Arbiscan, for deep links from UIs.
+    ///
+    #[cfg_attr(feature = "l2s", doc = "```rust")]
+    #[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+    /// use usdshe::SupportedUsdcChain;
+    ///
+    /// assert_eq!(
+    ///     SupportedUsdcChain::Arbitrum.usdc_explorer_url(),
+    ///     "https://arbiscan.io/token/0xaf88d065e77c8cC2239327C5EDb3A432268e5831"
+    /// );
+    /// ```
+    pub fn usdc_explorer_url(&self) -> String {
+        format!("{}/token/{}", self.explorer(), self.usdc_address())
+    }
+
+    /// The base URL of the chain's canonical block explorer.
+    const fn explorer(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "l2s")]
+            Self::Arbitrum => "https://arbiscan.io",
+            #[cfg(feature = "testnets")]
+            Self::ArbitrumSepolia => "https://sepolia.arbiscan.io",
+            #[cfg(feature = "alt-l1s")]
+            Self::Avalanche => "https://snowtrace.io",
+            #[cfg(feature = "l2s")]
+            Self::Base => "https://basescan.org",
+            #[cfg(feature = "testnets")]
+            Self::BaseSepolia => "https://sepolia.basescan.org",
+            #[cfg(feature = "alt-l1s")]
+            Self::Berachain => "https://berascan.com",
+            #[cfg(feature = "alt-l1s")]
+            Self::BinanceSmartChain => "https://bscscan.com",
+            #[cfg(feature = "alt-l1s")]
+            Self::Fantom => "https://ftmscan.com",
+            #[cfg(feature = "l2s")]
+            Self::Fraxtal => "https://fraxscan.com",
+            #[cfg(feature = "l2s")]
+            Self::Linea => "https://lineascan.build",
+            #[cfg(feature = "ethereum")]
+            Self::Mainnet => "https://etherscan.io",
+            #[cfg(feature = "l2s")]
+            Self::Mantle => "https://mantlescan.xyz",
+            #[cfg(feature = "l2s")]
+            Self::Mode => "https://explorer.mode.network",
+            #[cfg(feature = "l2s")]
+            Self::Optimism => "https://optimistic.etherscan.io",
+            #[cfg(feature = "alt-l1s")]
+            Self::Polygon => "https://polygonscan.com",
+            #[cfg(feature = "l2s")]
+            Self::Scroll => "https://scrollscan.com",
+            #[cfg(feature = "testnets")]
+            Self::Sepolia => "https://sepolia.etherscan.io",
+            #[cfg(feature = "alt-l1s")]
+            Self::Sonic => "https://sonicscan.org",
+            #[cfg(feature = "l2s")]
+            Self::Unichain => "https://uniscan.xyz",
+            #[cfg(feature = "l2s")]
+            Self::ZkSync => "https://era.zksync.network",
+        }
+    }
+
+    /// Returns the CAIP-19 asset identifier for USDC on this chain, e.g.
+    /// `eip155:1/erc20:0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48`.
+    ///
+    /// The address is rendered with its EIP-55 checksum.
+    ///
+    #[cfg_attr(feature = "l2s", doc = "```rust")]
+    #[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+    /// use usdshe::SupportedUsdcChain;
+    ///
+    /// assert_eq!(
+    ///     SupportedUsdcChain::Base.usdc_caip19(),
+    ///     "eip155:8453/erc20:0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"
+    /// );
+    /// ```
+    pub fn usdc_caip19(&self) -> String {
+        format!("eip155:{}/erc20:{}", self.chain_id(), self.usdc_address())
+    }
+
+    /// Returns the EIP-155 chain ID.
+    pub const fn chain_id(&self) -> u64 {
+        self.named_chain() as u64
+    }
+
+    /// Returns the corresponding [`NamedChain`].
+    pub const fn named_chain(&self) -> NamedChain {
+        match *self {
+            #[cfg(feature = "l2s")]
+            Self::Arbitrum => NamedChain::Arbitrum,
+            #[cfg(feature = "testnets")]
+            Self::ArbitrumSepolia => NamedChain::ArbitrumSepolia,
+            #[cfg(feature = "alt-l1s")]
+            Self::Avalanche => NamedChain::Avalanche,
+            #[cfg(feature = "l2s")]
+            Self::Base => NamedChain::Base,
+            #[cfg(feature = "testnets")]
+            Self::BaseSepolia => NamedChain::BaseSepolia,
+            #[cfg(feature = "alt-l1s")]
+            Self::Berachain => NamedChain::Berachain,
+            #[cfg(feature = "alt-l1s")]
+            Self::BinanceSmartChain => NamedChain::BinanceSmartChain,
+            #[cfg(feature = "alt-l1s")]
+            Self::Fantom => NamedChain::Fantom,
+            #[cfg(feature = "l2s")]
+            Self::Fraxtal => NamedChain::Fraxtal,
+            #[cfg(feature = "l2s")]
+            Self::Linea => NamedChain::Linea,
+            #[cfg(feature = "ethereum")]
+            Self::Mainnet => NamedChain::Mainnet,
+            #[cfg(feature = "l2s")]
+            Self::Mantle => NamedChain::Mantle,
+            #[cfg(feature = "l2s")]
+            Self::Mode => NamedChain::Mode,
+            #[cfg(feature = "l2s")]
+            Self::Optimism => NamedChain::Optimism,
+            #[cfg(feature = "alt-l1s")]
+            Self::Polygon => NamedChain::Polygon,
+            #[cfg(feature = "l2s")]
+            Self::Scroll => NamedChain::Scroll,
+            #[cfg(feature = "testnets")]
+            Self::Sepolia => NamedChain::Sepolia,
+            #[cfg(feature = "alt-l1s")]
+            Self::Sonic => NamedChain::Sonic,
+            #[cfg(feature = "l2s")]
+            Self::Unichain => NamedChain::Unichain,
+            #[cfg(feature = "l2s")]
+            Self::ZkSync => NamedChain::ZkSync,
+        }
+    }
+}
+
+/// Common alternative spellings accepted by [`SupportedUsdcChain::from_str`], in
+/// addition to the canonical [`NamedChain`] names. Keys are lowercase and
+/// dash-separated.
+const ALIASES: &[(&str, SupportedUsdcChain)] = &[
+    #[cfg(feature = "l2s")]
+    ("arb", SupportedUsdcChain::Arbitrum),
+    #[cfg(feature = "l2s")]
+    ("arb1", SupportedUsdcChain::Arbitrum),
+    #[cfg(feature = "l2s")]
+    ("arbitrum-one", SupportedUsdcChain::Arbitrum),
+    #[cfg(feature = "testnets")]
+    ("arb-sepolia", SupportedUsdcChain::ArbitrumSepolia),
+    #[cfg(feature = "alt-l1s")]
+    ("avax", SupportedUsdcChain::Avalanche),
+    #[cfg(feature = "alt-l1s")]
+    ("avalanche-c", SupportedUsdcChain::Avalanche),
+    #[cfg(feature = "alt-l1s")]
+    ("c-chain", SupportedUsdcChain::Avalanche),
+    #[cfg(feature = "alt-l1s")]
+    ("bera", SupportedUsdcChain::Berachain),
+    #[cfg(feature = "alt-l1s")]
+    ("bnb", SupportedUsdcChain::BinanceSmartChain),
+    #[cfg(feature = "alt-l1s")]
+    ("binance", SupportedUsdcChain::BinanceSmartChain),
+    #[cfg(feature = "alt-l1s")]
+    ("ftm", SupportedUsdcChain::Fantom),
+    #[cfg(feature = "ethereum")]
+    ("eth", SupportedUsdcChain::Mainnet),
+    #[cfg(feature = "ethereum")]
+    ("ethereum", SupportedUsdcChain::Mainnet),
+    #[cfg(feature = "ethereum")]
+    ("ethereum-mainnet", SupportedUsdcChain::Mainnet),
+    #[cfg(feature = "l2s")]
+    ("op", SupportedUsdcChain::Optimism),
+    #[cfg(feature = "l2s")]
+    ("op-mainnet", SupportedUsdcChain::Optimism),
+    #[cfg(feature = "alt-l1s")]
+    ("matic", SupportedUsdcChain::Polygon),
+    #[cfg(feature = "alt-l1s")]
+    ("pol", SupportedUsdcChain::Polygon),
+    #[cfg(feature = "alt-l1s")]
+    ("polygon-pos", SupportedUsdcChain::Polygon),
+    #[cfg(feature = "testnets")]
+    ("eth-sepolia", SupportedUsdcChain::Sepolia),
+    #[cfg(feature = "testnets")]
+    ("ethereum-sepolia", SupportedUsdcChain::Sepolia),
+    #[cfg(feature = "l2s")]
+    ("zksync-era", SupportedUsdcChain::ZkSync),
+    #[cfg(feature = "l2s")]
+    ("zk-sync", SupportedUsdcChain::ZkSync),
+];
+
+/// Parses a chain name, case-insensitively.
+///
+/// Underscores and spaces are treated as dashes, and common aliases such as
+/// `"eth"`, `"arb"`, `"arbitrum-one"` or `"matic"` are accepted alongside the
+/// canonical [`NamedChain`] names.
+///
+#[cfg_attr(
+    all(feature = "ethereum", feature = "l2s", feature = "testnets"),
+    doc = "```rust"
+)]
+#[cfg_attr(
+    not(all(feature = "ethereum", feature = "l2s", feature = "testnets")),
+    doc = "```ignore"
+)]
+/// use usdshe::SupportedUsdcChain;
+///
+/// assert_eq!("ETH".parse::<SupportedUsdcChain>().unwrap(), SupportedUsdcChain::Mainnet);
+/// assert_eq!("arbitrum_one".parse::<SupportedUsdcChain>().unwrap(), SupportedUsdcChain::Arbitrum);
+/// assert_eq!("base-sepolia".parse::<SupportedUsdcChain>().unwrap(), SupportedUsdcChain::BaseSepolia);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the name is a known [`NamedChain`] without a
+///   USDC address.
+/// - [`UsdcError::UnknownChainName`]: If the name is not recognized at all.
+impl FromStr for SupportedUsdcChain {
+    type Err = UsdcError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let normalized = name.trim().to_ascii_lowercase().replace(['_', ' '], "-");
+
+        if let Some((_, chain)) = ALIASES.iter().find(|(alias, _)| *alias == normalized) {
+            return Ok(*chain);
+        }
+
+        match NamedChain::from_str(&normalized) {
+            Ok(named) => Self::try_from(named),
+            Err(_) => Err(UsdcError::UnknownChainName(name.to_string())),
+        }
+    }
+}
+
+/// Resolves a key that is either a numeric chain ID or a chain name, as found in
+/// config files and remote data sources.
+///
+/// Unlike `SupportedUsdcChain::from_str`, any [`NamedChain`] is accepted, since a
+/// known chain without a built-in address is exactly what overrides and remote
+/// resolvers are for.
+#[cfg(any(feature = "config", feature = "remote-defillama", feature = "server"))]
+pub(crate) fn parse_chain_key(key: &str) -> Result<Chain, UsdcError> {
+    if let Ok(id) = key.parse::<u64>() {
+        return Ok(Chain::from_id(id));
+    }
+    match SupportedUsdcChain::from_str(key) {
+        Ok(chain) => Ok(chain.named_chain().into()),
+        Err(UsdcError::UnsupportedChain(named)) => Ok(named.into()),
+        Err(err) => NamedChain::from_str(key).map(Chain::from).map_err(|_| err),
+    }
+}
+
+impl TryFrom<NamedChain> for SupportedUsdcChain {
+    type Error = UsdcError;
+
+    fn try_from(chain: NamedChain) -> Result<Self, Self::Error> {
+        use NamedChain::*;
+
+        match chain {
+            #[cfg(feature = "l2s")]
+            Arbitrum => Ok(Self::Arbitrum),
+            #[cfg(feature = "testnets")]
+            ArbitrumSepolia => Ok(Self::ArbitrumSepolia),
+            #[cfg(feature = "alt-l1s")]
+            Avalanche => Ok(Self::Avalanche),
+            #[cfg(feature = "l2s")]
+            Base => Ok(Self::Base),
+            #[cfg(feature = "testnets")]
+            BaseSepolia => Ok(Self::BaseSepolia),
+            #[cfg(feature = "alt-l1s")]
+            Berachain => Ok(Self::Berachain),
+            #[cfg(feature = "alt-l1s")]
+            BinanceSmartChain => Ok(Self::BinanceSmartChain),
+            #[cfg(feature = "alt-l1s")]
+            Fantom => Ok(Self::Fantom),
+            #[cfg(feature = "l2s")]
+            Fraxtal => Ok(Self::Fraxtal),
+            #[cfg(feature = "l2s")]
+            Linea => Ok(Self::Linea),
+            #[cfg(feature = "ethereum")]
+            Mainnet => Ok(Self::Mainnet),
+            #[cfg(feature = "l2s")]
+            Mantle => Ok(Self::Mantle),
+            #[cfg(feature = "l2s")]
+            Mode => Ok(Self::Mode),
+            #[cfg(feature = "l2s")]
+            Optimism => Ok(Self::Optimism),
+            #[cfg(feature = "alt-l1s")]
+            Polygon => Ok(Self::Polygon),
+            #[cfg(feature = "l2s")]
+            Scroll => Ok(Self::Scroll),
+            #[cfg(feature = "testnets")]
+            Sepolia => Ok(Self::Sepolia),
+            #[cfg(feature = "alt-l1s")]
+            Sonic => Ok(Self::Sonic),
+            #[cfg(feature = "l2s")]
+            Unichain => Ok(Self::Unichain),
+            #[cfg(feature = "l2s")]
+            ZkSync => Ok(Self::ZkSync),
+            unsupported_chain => Err(UsdcError::UnsupportedChain(unsupported_chain)),
+        }
+    }
+}
+
+impl From<SupportedUsdcChain> for NamedChain {
+    fn from(chain: SupportedUsdcChain) -> Self {
+        chain.named_chain()
+    }
+}
+
+impl Usdc for SupportedUsdcChain {
+    /// Always succeeds; see [`SupportedUsdcChain::usdc_address`].
+    fn usdc_address(&self) -> Result<Address, UsdcError> {
+        Ok(SupportedUsdcChain::usdc_address(self))
+    }
+}
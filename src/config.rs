@@ -0,0 +1,323 @@
+//! Loading [`UsdcRegistry`] overrides from TOML or JSON configuration files.
+
+use crate::{chain::parse_chain_key, json, parse_address, JsonError, UsdcError, UsdcRegistry};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Represents errors that can occur when loading registry overrides.
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    /// The configuration file could not be read.
+    #[error("Failed to read config file '{}': {source}", path.display())]
+    Io {
+        /// The path that failed to read.
+        path: PathBuf,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The file extension does not identify a supported format.
+    #[error("Unsupported config format for '{}': expected a .toml or .json file", .0.display())]
+    UnsupportedFormat(PathBuf),
+
+    /// The document is not valid TOML.
+    #[error("Invalid TOML: {0}")]
+    Toml(#[from] toml_edit::TomlError),
+
+    /// The document is not valid JSON.
+    #[error(transparent)]
+    Json(#[from] JsonError),
+
+    /// A field has the wrong type.
+    #[error("Invalid config field '{field}': expected {expected}")]
+    InvalidField {
+        /// The offending field or entry.
+        field: String,
+        /// A description of the expected value.
+        expected: &'static str,
+    },
+
+    /// A chain key or address value could not be resolved.
+    #[error(transparent)]
+    Usdc(#[from] UsdcError),
+}
+
+/// A set of changes to apply on top of a [`UsdcRegistry`].
+///
+/// Overrides are usually read from a configuration file. In TOML:
+///
+/// ```toml
+/// # Start from the built-in table (the default). Set to false to use only
+/// # the entries below.
+/// include_builtin = true
+///
+/// # Chains to drop from the table, by chain ID or name.
+/// remove = ["fantom"]
+///
+/// # Entries to add or override, keyed by chain ID or name.
+/// [usdc]
+/// 8453 = "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"
+/// 12345 = "0x1111111111111111111111111111111111111111"
+/// ```
+///
+/// And the equivalent JSON:
+///
+/// ```json
+/// {
+///   "include_builtin": true,
+///   "remove": ["fantom"],
+///   "usdc": {
+///     "8453": "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913",
+///     "12345": "0x1111111111111111111111111111111111111111"
+///   }
+/// }
+/// ```
+///
+/// Chain names accept the same aliases as [`SupportedUsdcChain`](crate::SupportedUsdcChain)'s `FromStr`, plus
+/// any other [`NamedChain`](alloy_chains::NamedChain) name.
+///
+/// ## Precedence
+///
+/// Overrides are applied in a fixed order: if `include_builtin` is false the
+/// registry is cleared first, then the `remove` entries are dropped, and finally
+/// the `usdc` entries are inserted. A chain listed in both `remove` and `usdc`
+/// therefore ends up with the address from `usdc`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistryOverrides {
+    /// Whether to keep the entries already in the registry.
+    pub include_builtin: bool,
+    /// Chains to remove.
+    pub remove: Vec<Chain>,
+    /// Entries to insert or replace.
+    pub usdc: Vec<(Chain, Address)>,
+}
+
+impl Default for RegistryOverrides {
+    fn default() -> Self {
+        Self {
+            include_builtin: true,
+            remove: Vec::new(),
+            usdc: Vec::new(),
+        }
+    }
+}
+
+impl RegistryOverrides {
+    /// Parses overrides from a TOML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if the document is malformed or contains an
+    /// unrecognized chain or invalid address.
+    pub fn from_toml_str(input: &str) -> Result<Self, ConfigError> {
+        let document = toml_edit::Document::parse(input)?;
+        let table = document.as_table();
+        let mut overrides = Self::default();
+
+        if let Some(item) = table.get("include_builtin") {
+            overrides.include_builtin =
+                item.as_bool().ok_or_else(|| ConfigError::InvalidField {
+                    field: "include_builtin".to_string(),
+                    expected: "a boolean",
+                })?;
+        }
+        if let Some(item) = table.get("remove") {
+            let array = item.as_array().ok_or_else(|| ConfigError::InvalidField {
+                field: "remove".to_string(),
+                expected: "an array of chain IDs or names",
+            })?;
+            for value in array {
+                let key = match (value.as_integer(), value.as_str()) {
+                    (Some(id), _) => id.to_string(),
+                    (None, Some(name)) => name.to_string(),
+                    _ => return Err(invalid_chain_field("remove")),
+                };
+                overrides.remove.push(parse_chain_key(&key)?);
+            }
+        }
+        if let Some(item) = table.get("usdc") {
+            let entries = item
+                .as_table_like()
+                .ok_or_else(|| ConfigError::InvalidField {
+                    field: "usdc".to_string(),
+                    expected: "a table of chain to address",
+                })?;
+            for (key, value) in entries.iter() {
+                let address = value.as_str().ok_or_else(|| invalid_address_field(key))?;
+                overrides
+                    .usdc
+                    .push((parse_chain_key(key)?, parse_address(address)?));
+            }
+        }
+
+        Ok(overrides)
+    }
+
+    /// Parses overrides from a JSON document.
+    ///
+    /// ```rust
+    /// use usdshe::RegistryOverrides;
+    /// use alloy_chains::{Chain, NamedChain};
+    ///
+    /// let overrides = RegistryOverrides::from_json_str(
+    ///     r#"{"remove": [250, "bsc"], "usdc": {"gnosis": "0x2a22f9c3b484c3629090FeED35F17Ff8F88f76F0"}}"#,
+    /// )
+    /// .unwrap();
+    /// assert!(overrides.include_builtin);
+    /// assert_eq!(overrides.remove, vec![Chain::from_id(250), NamedChain::BinanceSmartChain.into()]);
+    /// assert_eq!(overrides.usdc[0].0, Chain::from(NamedChain::Gnosis));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if the document is malformed or contains an
+    /// unrecognized chain or invalid address.
+    pub fn from_json_str(input: &str) -> Result<Self, ConfigError> {
+        let document = json::parse(input)?;
+        document
+            .as_object()
+            .ok_or_else(|| ConfigError::InvalidField {
+                field: "<root>".to_string(),
+                expected: "an object",
+            })?;
+        let mut overrides = Self::default();
+
+        if let Some(value) = document.get("include_builtin") {
+            overrides.include_builtin =
+                value.as_bool().ok_or_else(|| ConfigError::InvalidField {
+                    field: "include_builtin".to_string(),
+                    expected: "a boolean",
+                })?;
+        }
+        if let Some(value) = document.get("remove") {
+            let array = value.as_array().ok_or_else(|| ConfigError::InvalidField {
+                field: "remove".to_string(),
+                expected: "an array of chain IDs or names",
+            })?;
+            for value in array {
+                let key = match (value.as_u64(), value.as_str()) {
+                    (Some(id), _) => id.to_string(),
+                    (None, Some(name)) => name.to_string(),
+                    _ => return Err(invalid_chain_field("remove")),
+                };
+                overrides.remove.push(parse_chain_key(&key)?);
+            }
+        }
+        if let Some(value) = document.get("usdc") {
+            let entries = value.as_object().ok_or_else(|| ConfigError::InvalidField {
+                field: "usdc".to_string(),
+                expected: "an object of chain to address",
+            })?;
+            for (key, value) in entries {
+                let address = value.as_str().ok_or_else(|| invalid_address_field(key))?;
+                overrides
+                    .usdc
+                    .push((parse_chain_key(key)?, parse_address(address)?));
+            }
+        }
+
+        Ok(overrides)
+    }
+
+    /// Reads overrides from a `.toml` or `.json` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if the file cannot be read, has an unknown
+    /// extension, or fails to parse.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        let parse = match extension.as_deref() {
+            Some("toml") => Self::from_toml_str,
+            Some("json") => Self::from_json_str,
+            _ => return Err(ConfigError::UnsupportedFormat(path.to_path_buf())),
+        };
+        let contents = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        parse(&contents)
+    }
+}
+
+impl UsdcRegistry {
+    /// Creates a registry from the built-in data with the overrides in `path`
+    /// applied. See [`RegistryOverrides`] for the file format and precedence rules.
+    ///
+    /// ## Examples
+    ///
+    #[cfg_attr(feature = "ethereum", doc = "```rust")]
+    #[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+    /// use usdshe::UsdcRegistry;
+    /// use alloy_chains::{Chain, NamedChain};
+    /// use alloy_primitives::address;
+    ///
+    /// let path = std::env::temp_dir().join("usdshe-doctest-overrides.toml");
+    /// std::fs::write(
+    ///     &path,
+    ///     r#"
+    ///     remove = ["fantom"]
+    ///
+    ///     [usdc]
+    ///     12345 = "0x1111111111111111111111111111111111111111"
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let registry = UsdcRegistry::from_file(&path).unwrap();
+    /// assert_eq!(
+    ///     registry.get(Chain::from_id(12345)),
+    ///     Some(address!("0x1111111111111111111111111111111111111111"))
+    /// );
+    /// assert_eq!(registry.get(NamedChain::Fantom), None);
+    /// assert!(registry.get(NamedChain::Mainnet).is_some());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if the file cannot be read or parsed.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let mut registry = Self::new();
+        registry.apply(RegistryOverrides::from_file(path)?);
+        Ok(registry)
+    }
+
+    /// Applies `overrides` to this registry, following the precedence rules
+    /// described on [`RegistryOverrides`].
+    pub fn apply(&mut self, overrides: RegistryOverrides) {
+        if !overrides.include_builtin {
+            *self = Self::empty();
+        }
+        for chain in overrides.remove {
+            self.remove(chain);
+        }
+        for (chain, address) in overrides.usdc {
+            self.insert(chain, address);
+        }
+    }
+}
+
+fn invalid_chain_field(field: &str) -> ConfigError {
+    ConfigError::InvalidField {
+        field: field.to_string(),
+        expected: "a chain ID or name",
+    }
+}
+
+fn invalid_address_field(key: &str) -> ConfigError {
+    ConfigError::InvalidField {
+        field: format!("usdc.{key}"),
+        expected: "an address string",
+    }
+}
@@ -0,0 +1,112 @@
+//! Canonical DAI addresses, including the bridged deployments on L2s.
+
+use crate::{TokenKind, Tokens, UsdcError};
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::{address, Address};
+
+/// Dai Stablecoin on Ethereum mainnet, issued by MakerDAO.
+pub const ETHEREUM_DAI: Address = address!("0x6B175474E89094C44Da98b954EedeAC495271d0F");
+/// Bridged DAI on Arbitrum One. Shares its address with [`OPTIMISM_DAI`].
+pub const ARBITRUM_DAI: Address = address!("0xDA10009cBd5D07dd0CeCc66161FC93D7c9000da1");
+/// Bridged DAI.e on Avalanche C-Chain.
+pub const AVALANCHE_DAI: Address = address!("0xd586E7F844cEa2F87f50152665BCbc2C279D8d70");
+/// Bridged DAI on Base.
+pub const BASE_DAI: Address = address!("0x50c5725949A6F0c72E6C4a641F24049A917DB0Cb");
+/// Binance-Peg DAI on BNB Smart Chain.
+pub const BSC_DAI: Address = address!("0x1AF3F329e8BE154074D8769D1FFa4eE058B1DBc3");
+/// Bridged DAI on Fantom.
+pub const FANTOM_DAI: Address = address!("0x8D11eC38a3EB5E956B052f67Da8Bdc9bef8Abf3E");
+/// Bridged DAI on Linea.
+pub const LINEA_DAI: Address = address!("0x4AF15ec2A0BD43Db75dd04E62FAA3B8EF36b00d5");
+/// Bridged DAI on OP Mainnet. Shares its address with [`ARBITRUM_DAI`].
+pub const OPTIMISM_DAI: Address = address!("0xDA10009cBd5D07dd0CeCc66161FC93D7c9000da1");
+/// Bridged DAI on Polygon PoS.
+pub const POLYGON_DAI: Address = address!("0x8f3Cf7ad23Cd3CaDbD9735AFf958023239c6A063");
+/// Bridged DAI on Scroll.
+pub const SCROLL_DAI: Address = address!("0xcA77eB3fEFe3725Dc33bccB54eDEFc3D9f764f97");
+/// Bridged DAI on zkSync Era.
+pub const ZKSYNC_DAI: Address = address!("0x4B9eb6c0b6ea15176BBF62841C6B2A8a398cb656");
+
+/// A trait for types that can provide a DAI contract address, the counterpart to
+/// [`Usdc`](crate::Usdc).
+///
+/// Only Ethereum hosts MakerDAO's own contract; other chains resolve to the
+/// bridged DAI that their canonical bridge mints.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "ethereum", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "ethereum", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::{Dai, UsdcError, ETHEREUM_DAI, OPTIMISM_DAI};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(NamedChain::Mainnet.dai_address().unwrap(), ETHEREUM_DAI);
+/// assert_eq!(10u64.dai_address().unwrap(), OPTIMISM_DAI);
+/// assert!(matches!(
+///     NamedChain::Mantle.dai_address(),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Mantle))
+/// ));
+/// ```
+pub trait Dai {
+    /// Returns the DAI contract address for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but has no known
+    ///   DAI address.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn dai_address(&self) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical DAI contract for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_dai(&self, address: Address) -> bool {
+        self.dai_address().is_ok_and(|dai| dai == address)
+    }
+}
+
+pub(crate) const fn dai_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_DAI),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_DAI),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_DAI),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::BinanceSmartChain => Some(BSC_DAI),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Fantom => Some(FANTOM_DAI),
+        #[cfg(feature = "l2s")]
+        NamedChain::Linea => Some(LINEA_DAI),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_DAI),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_DAI),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_DAI),
+        #[cfg(feature = "l2s")]
+        NamedChain::Scroll => Some(SCROLL_DAI),
+        #[cfg(feature = "l2s")]
+        NamedChain::ZkSync => Some(ZKSYNC_DAI),
+        _ => None,
+    }
+}
+
+impl Dai for NamedChain {
+    fn dai_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Dai)
+    }
+}
+
+impl Dai for Chain {
+    fn dai_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Dai)
+    }
+}
+
+impl Dai for u64 {
+    fn dai_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Dai)
+    }
+}
@@ -0,0 +1,162 @@
+//! A uniform view of every USDC deployment, EVM or not.
+
+use crate::{MultiChainAddress, NonEvmChain, SupportedUsdcChain, UsdcError, UsdcVariant};
+use alloc::vec::Vec;
+use alloy_chains::NamedChain;
+use core::fmt;
+
+/// A family of chains that share an address format and tooling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum ChainFamily {
+    /// Ethereum and EVM-compatible chains, with 20-byte hex addresses.
+    Evm,
+    /// Solana, with base58 public keys.
+    Solana,
+    /// Cosmos SDK chains, where tokens are bank denominations.
+    Cosmos,
+    /// Move chains (Aptos and Sui), with 32-byte hex addresses.
+    Move,
+    /// Stellar, where assets are a code and an issuing account.
+    Stellar,
+    /// Tron, whose TVM is EVM-derived but whose addresses are base58check.
+    Tron,
+    /// NEAR, with human-readable account IDs.
+    Near,
+}
+
+/// A chain with a USDC deployment, EVM or not.
+///
+/// Converts from [`NamedChain`], [`SupportedUsdcChain`] and [`NonEvmChain`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChainRef {
+    /// An EVM chain.
+    Evm(NamedChain),
+    /// A non-EVM chain.
+    NonEvm(NonEvmChain),
+}
+
+impl ChainRef {
+    /// Returns the chain's family.
+    pub const fn family(&self) -> ChainFamily {
+        match self {
+            Self::Evm(_) => ChainFamily::Evm,
+            Self::NonEvm(chain) => chain.family(),
+        }
+    }
+}
+
+impl From<NamedChain> for ChainRef {
+    fn from(chain: NamedChain) -> Self {
+        Self::Evm(chain)
+    }
+}
+
+impl From<SupportedUsdcChain> for ChainRef {
+    fn from(chain: SupportedUsdcChain) -> Self {
+        Self::Evm(chain.named_chain())
+    }
+}
+
+impl From<NonEvmChain> for ChainRef {
+    fn from(chain: NonEvmChain) -> Self {
+        Self::NonEvm(chain)
+    }
+}
+
+impl fmt::Display for ChainRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Evm(chain) => chain.fmt(f),
+            Self::NonEvm(chain) => chain.fmt(f),
+        }
+    }
+}
+
+/// USDC on one chain, described the same way for every chain family.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UsdcDeployment {
+    /// The chain the token is deployed on.
+    pub chain: ChainRef,
+    /// The chain's family.
+    pub family: ChainFamily,
+    /// The token's identifier in the chain's native format.
+    pub location: MultiChainAddress,
+    /// The number of decimals of the token.
+    pub decimals: u8,
+    /// Who issues the token.
+    pub variant: UsdcVariant,
+}
+
+/// Returns the USDC deployment on `chain`, whichever family it belongs to.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{usdc_deployment, ChainFamily, NonEvmChain, BASE_USDC};
+/// use alloy_chains::NamedChain;
+///
+/// let base = usdc_deployment(NamedChain::Base).unwrap();
+/// assert_eq!(base.family, ChainFamily::Evm);
+/// assert_eq!(base.location.as_evm(), Some(BASE_USDC));
+///
+/// let stellar = usdc_deployment(NonEvmChain::Stellar).unwrap();
+/// assert_eq!(stellar.family, ChainFamily::Stellar);
+/// assert_eq!(stellar.decimals, 7);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If an EVM chain has no known USDC address.
+pub fn usdc_deployment(chain: impl Into<ChainRef>) -> Result<UsdcDeployment, UsdcError> {
+    match chain.into() {
+        ChainRef::Evm(chain) => SupportedUsdcChain::try_from(chain).map(evm_deployment),
+        ChainRef::NonEvm(chain) => Ok(non_evm_deployment(chain)),
+    }
+}
+
+/// Returns every recorded USDC deployment: the compiled-in EVM chains, then
+/// the non-EVM chains.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{usdc_deployments, ChainFamily};
+///
+/// let move_chains = usdc_deployments()
+///     .into_iter()
+///     .filter(|deployment| deployment.family == ChainFamily::Move)
+///     .count();
+/// assert_eq!(move_chains, 2);
+/// ```
+pub fn usdc_deployments() -> Vec<UsdcDeployment> {
+    let evm = SupportedUsdcChain::ALL.iter().copied().map(evm_deployment);
+    let non_evm = NonEvmChain::ALL.iter().copied().map(non_evm_deployment);
+    evm.chain(non_evm).collect()
+}
+
+fn evm_deployment(chain: SupportedUsdcChain) -> UsdcDeployment {
+    UsdcDeployment {
+        chain: chain.into(),
+        family: ChainFamily::Evm,
+        location: MultiChainAddress::Evm(chain.usdc_address()),
+        decimals: chain.usdc_decimals(),
+        variant: chain.usdc_variant(),
+    }
+}
+
+fn non_evm_deployment(chain: NonEvmChain) -> UsdcDeployment {
+    UsdcDeployment {
+        chain: chain.into(),
+        family: chain.family(),
+        location: chain.usdc(),
+        decimals: chain.usdc_decimals(),
+        variant: UsdcVariant::Native,
+    }
+}
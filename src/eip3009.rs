@@ -0,0 +1,259 @@
+//! EIP-3009 transfers with authorization.
+//!
+//! FiatToken lets a holder sign a transfer that anyone, typically a relayer
+//! paying the gas, submits with `transferWithAuthorization`. Each authorization
+//! carries a random 32-byte nonce instead of a sequential one, so several can be
+//! outstanding at once, and is only valid inside its time window.
+
+use crate::{abi, usdc_eip712_domain, Eip712Message, TypedData, UsdcError};
+use alloc::{vec, vec::Vec};
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, B256, U256};
+
+/// The Unix timestamps between which an authorization can be executed,
+/// exclusive at both ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValidityWindow {
+    /// The authorization is rejected at or before this time.
+    pub valid_after: u64,
+    /// The authorization is rejected at or after this time.
+    pub valid_before: u64,
+}
+
+impl ValidityWindow {
+    /// Creates a window from Unix timestamps.
+    pub const fn new(valid_after: u64, valid_before: u64) -> Self {
+        Self {
+            valid_after,
+            valid_before,
+        }
+    }
+
+    /// Creates a window that is open immediately and closes at `valid_before`.
+    pub const fn until(valid_before: u64) -> Self {
+        Self::new(0, valid_before)
+    }
+
+    /// Creates a window that is open immediately and closes `ttl` from now.
+    #[cfg(feature = "std")]
+    pub fn from_now(ttl: std::time::Duration) -> Self {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        Self::until(now.saturating_add(ttl).as_secs())
+    }
+}
+
+/// Returns a random authorization nonce from the operating system's random
+/// number generator.
+#[cfg(feature = "getrandom")]
+pub fn random_authorization_nonce() -> B256 {
+    B256::random()
+}
+
+/// Signed by `from` to let anyone execute a transfer to `to` with
+/// `transferWithAuthorization`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferWithAuthorization {
+    /// The payer, who signs the authorization.
+    pub from: Address,
+    /// The payee.
+    pub to: Address,
+    /// The amount, in raw token units.
+    pub value: U256,
+    /// The Unix time after which the authorization is valid.
+    pub valid_after: U256,
+    /// The Unix time before which the authorization is valid.
+    pub valid_before: U256,
+    /// A unique random nonce.
+    pub nonce: B256,
+}
+
+/// Like [`TransferWithAuthorization`], but can only be executed by the payee
+/// with `receiveWithAuthorization`, which prevents front-running when the
+/// transfer is part of a larger contract call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceiveWithAuthorization {
+    /// The payer, who signs the authorization.
+    pub from: Address,
+    /// The payee, which must be the caller.
+    pub to: Address,
+    /// The amount, in raw token units.
+    pub value: U256,
+    /// The Unix time after which the authorization is valid.
+    pub valid_after: U256,
+    /// The Unix time before which the authorization is valid.
+    pub valid_before: U256,
+    /// A unique random nonce.
+    pub nonce: B256,
+}
+
+/// Signed by `authorizer` to void an unused authorization with
+/// `cancelAuthorization`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CancelAuthorization {
+    /// The signer of the authorization being cancelled.
+    pub authorizer: Address,
+    /// The nonce of the authorization being cancelled.
+    pub nonce: B256,
+}
+
+const AUTHORIZATION_FIELDS: &[(&str, &str)] = &[
+    ("from", "address"),
+    ("to", "address"),
+    ("value", "uint256"),
+    ("validAfter", "uint256"),
+    ("validBefore", "uint256"),
+    ("nonce", "bytes32"),
+];
+
+impl Eip712Message for TransferWithAuthorization {
+    const PRIMARY_TYPE: &'static str = "TransferWithAuthorization";
+
+    const FIELDS: &'static [(&'static str, &'static str)] = AUTHORIZATION_FIELDS;
+
+    fn encode_values(&self) -> Vec<B256> {
+        vec![
+            abi::address_word(self.from),
+            abi::address_word(self.to),
+            abi::uint_word(self.value),
+            abi::uint_word(self.valid_after),
+            abi::uint_word(self.valid_before),
+            self.nonce,
+        ]
+    }
+}
+
+impl Eip712Message for ReceiveWithAuthorization {
+    const PRIMARY_TYPE: &'static str = "ReceiveWithAuthorization";
+
+    const FIELDS: &'static [(&'static str, &'static str)] = AUTHORIZATION_FIELDS;
+
+    fn encode_values(&self) -> Vec<B256> {
+        vec![
+            abi::address_word(self.from),
+            abi::address_word(self.to),
+            abi::uint_word(self.value),
+            abi::uint_word(self.valid_after),
+            abi::uint_word(self.valid_before),
+            self.nonce,
+        ]
+    }
+}
+
+impl Eip712Message for CancelAuthorization {
+    const PRIMARY_TYPE: &'static str = "CancelAuthorization";
+
+    const FIELDS: &'static [(&'static str, &'static str)] =
+        &[("authorizer", "address"), ("nonce", "bytes32")];
+
+    fn encode_values(&self) -> Vec<B256> {
+        vec![abi::address_word(self.authorizer), self.nonce]
+    }
+}
+
+/// Builds the EIP-712 typed data of a `transferWithAuthorization` from `from`
+/// to `to` on `chain`.
+///
+/// `value` is in the deployment's raw units. With the `getrandom` feature,
+/// [`random_authorization_nonce`] generates a suitable nonce.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{build_transfer_with_authorization, Eip712Message, ValidityWindow};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, b256, U256};
+///
+/// let payer = address!("0x1111111111111111111111111111111111111111");
+/// let payee = address!("0x2222222222222222222222222222222222222222");
+/// let nonce = b256!("0x0101010101010101010101010101010101010101010101010101010101010101");
+///
+/// let authorization = build_transfer_with_authorization(
+///     NamedChain::Base,
+///     payer,
+///     payee,
+///     U256::from(1_000_000),
+///     ValidityWindow::until(1_800_000_000),
+///     nonce,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     usdshe::TransferWithAuthorization::type_hash(),
+///     b256!("0x7c7c6cdb67a18743f49ec6fa9b35f50d52ed05cbed4cc592e13b44501c1a2267")
+/// );
+/// let digest = authorization.signing_hash(); // signed by the payer
+/// # let _ = digest;
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+/// - [`UsdcError::SignaturesUnsupported`]: If the token on the chain does not
+///   implement EIP-3009.
+pub fn build_transfer_with_authorization(
+    chain: NamedChain,
+    from: Address,
+    to: Address,
+    value: U256,
+    window: ValidityWindow,
+    nonce: B256,
+) -> Result<TypedData<TransferWithAuthorization>, UsdcError> {
+    Ok(TypedData {
+        domain: usdc_eip712_domain(chain)?,
+        message: TransferWithAuthorization {
+            from,
+            to,
+            value,
+            valid_after: U256::from(window.valid_after),
+            valid_before: U256::from(window.valid_before),
+            nonce,
+        },
+    })
+}
+
+/// Builds the EIP-712 typed data of a `receiveWithAuthorization` from `from`
+/// to `to` on `chain`. See [`build_transfer_with_authorization`].
+///
+/// # Errors
+///
+/// See [`build_transfer_with_authorization`].
+pub fn build_receive_with_authorization(
+    chain: NamedChain,
+    from: Address,
+    to: Address,
+    value: U256,
+    window: ValidityWindow,
+    nonce: B256,
+) -> Result<TypedData<ReceiveWithAuthorization>, UsdcError> {
+    Ok(TypedData {
+        domain: usdc_eip712_domain(chain)?,
+        message: ReceiveWithAuthorization {
+            from,
+            to,
+            value,
+            valid_after: U256::from(window.valid_after),
+            valid_before: U256::from(window.valid_before),
+            nonce,
+        },
+    })
+}
+
+/// Builds the EIP-712 typed data of a `cancelAuthorization` of the unused
+/// authorization with `nonce`, signed by `authorizer`.
+///
+/// # Errors
+///
+/// See [`build_transfer_with_authorization`].
+pub fn build_cancel_authorization(
+    chain: NamedChain,
+    authorizer: Address,
+    nonce: B256,
+) -> Result<TypedData<CancelAuthorization>, UsdcError> {
+    Ok(TypedData {
+        domain: usdc_eip712_domain(chain)?,
+        message: CancelAuthorization { authorizer, nonce },
+    })
+}
@@ -0,0 +1,277 @@
+//! EIP-712 typed data for USDC's signature-based entry points.
+//!
+//! FiatToken's EIP-712 domain is `(name, version, chainId, verifyingContract)`,
+//! where `name` is the token's `name()` and `version` its `version()`. Getting
+//! these right per deployment is what makes a signature valid, so the domain is
+//! derived from the registry instead of being assembled by hand.
+
+use crate::{SupportedUsdcChain, UsdcError};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_chains::NamedChain;
+use alloy_primitives::{b256, keccak256, Address, B256, U256};
+
+/// The EIP-712 domain of a USDC deployment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eip712Domain {
+    /// The signing domain name, the token's `name()`.
+    pub name: String,
+    /// The signing domain version, the token's `version()`.
+    pub version: &'static str,
+    /// The chain ID the signature is valid on.
+    pub chain_id: u64,
+    /// The token contract that verifies the signature.
+    pub verifying_contract: Address,
+}
+
+impl Eip712Domain {
+    /// Returns the domain separator, as returned by the token's
+    /// `DOMAIN_SEPARATOR()`.
+    pub fn separator(&self) -> B256 {
+        let type_hash = keccak256(
+            "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+        );
+        let mut encoded = Vec::with_capacity(5 * 32);
+        encoded.extend_from_slice(type_hash.as_slice());
+        encoded.extend_from_slice(keccak256(self.name.as_bytes()).as_slice());
+        encoded.extend_from_slice(keccak256(self.version.as_bytes()).as_slice());
+        encoded.extend_from_slice(&U256::from(self.chain_id).to_be_bytes::<32>());
+        encoded.extend_from_slice(self.verifying_contract.into_word().as_slice());
+        keccak256(encoded)
+    }
+}
+
+/// Returns the EIP-712 domain of the USDC contract on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "ethereum", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "ethereum", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::{usdc_eip712_domain, UsdcError, ETHEREUM_USDC};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::b256;
+///
+/// let domain = usdc_eip712_domain(NamedChain::Mainnet).unwrap();
+/// assert_eq!(domain.name, "USD Coin");
+/// assert_eq!(domain.version, "2");
+/// assert_eq!(domain.verifying_contract, ETHEREUM_USDC);
+/// assert_eq!(
+///     domain.separator(),
+///     b256!("0x06c37168a7db5138defc7866392bb87a741f9b3d104deb5094588ce041cae335")
+/// );
+///
+/// assert!(matches!(
+///     usdc_eip712_domain(NamedChain::Mantle),
+///     Err(UsdcError::SignaturesUnsupported(NamedChain::Mantle))
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+/// - [`UsdcError::SignaturesUnsupported`]: If the token on the chain is not a
+///   FiatToken, e.g. Binance-Peg USDC on BSC or the bridged USDC.e on Mantle,
+///   and has no EIP-712 domain.
+pub fn usdc_eip712_domain(chain: NamedChain) -> Result<Eip712Domain, UsdcError> {
+    let supported = SupportedUsdcChain::try_from(chain)?;
+    let version =
+        fiat_token_eip712_version(supported).ok_or(UsdcError::SignaturesUnsupported(chain))?;
+    let info = supported.usdc_token_info();
+    Ok(Eip712Domain {
+        name: info.name,
+        version,
+        chain_id: chain as u64,
+        verifying_contract: info.address,
+    })
+}
+
+/// Returns the `DOMAIN_SEPARATOR()` of the USDC contract on `chain`, where it
+/// has been checked against the deployed contract, so signers and verifiers can
+/// skip both the RPC round-trip and the hashing.
+///
+/// Separators are recorded for native USDC on Ethereum, Arbitrum, Avalanche,
+/// Base, Optimism and Polygon. Elsewhere this returns `None`, even where
+/// [`usdc_eip712_domain`] knows the domain: compute the separator from it, and
+/// confirm it with `verify_domain_separator` under the `onchain` feature before
+/// trusting it.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{usdc_domain_separator, usdc_eip712_domain};
+/// use alloy_chains::NamedChain;
+///
+/// let separator = usdc_domain_separator(NamedChain::Arbitrum).unwrap();
+/// assert_eq!(separator, usdc_eip712_domain(NamedChain::Arbitrum).unwrap().separator());
+///
+/// assert_eq!(usdc_domain_separator(NamedChain::BaseSepolia), None);
+/// ```
+pub fn usdc_domain_separator(chain: NamedChain) -> Option<B256> {
+    domain_separator(SupportedUsdcChain::try_from(chain).ok()?)
+}
+
+/// The domain separators of [`usdc_eip712_domain`], hashed ahead of time. Only
+/// separators read back from the deployed contract's `DOMAIN_SEPARATOR()` are
+/// listed.
+const fn domain_separator(chain: SupportedUsdcChain) -> Option<B256> {
+    Some(match chain.named_chain() {
+        NamedChain::Arbitrum => {
+            b256!("0x08d11903f8419e68b1b8721bcbe2e9fc68569122a77ef18c216f10b3b5112c78")
+        }
+        NamedChain::Avalanche => {
+            b256!("0xbbea200329a938bc3438984a49cb0732e66d66d7bd59c127abacc1710e77f7b3")
+        }
+        NamedChain::Base => {
+            b256!("0x02fa7265e7c5d81118673727957699e4d68f74cd74b7db77da710fe8a2c7834f")
+        }
+        NamedChain::Mainnet => {
+            b256!("0x06c37168a7db5138defc7866392bb87a741f9b3d104deb5094588ce041cae335")
+        }
+        NamedChain::Optimism => {
+            b256!("0x26d9c34bb1a1c312f69c53b2d93b8be20faafba63af2438c6811713c9b1f933f")
+        }
+        NamedChain::Polygon => {
+            b256!("0xcaa2ce1a5703ccbe253a34eb3166df60a705c561b44b192061e28f2a985be2ca")
+        }
+        _ => return None,
+    })
+}
+
+/// The EIP-712 version of the FiatToken contract on a chain. Native USDC and
+/// Circle's Bridged USDC Standard tokens have signed with version `"2"` since
+/// FiatToken v2; other tokens have no EIP-712 domain.
+const fn fiat_token_eip712_version(chain: SupportedUsdcChain) -> Option<&'static str> {
+    if chain.is_fiat_token() {
+        Some("2")
+    } else {
+        None
+    }
+}
+
+/// A struct that can be signed under EIP-712.
+///
+/// Every member has a static ABI type (`address`, `uint256` or `bytes32`), which
+/// covers the structs FiatToken verifies.
+pub trait Eip712Message {
+    /// The struct's type name, e.g. `Permit`.
+    const PRIMARY_TYPE: &'static str;
+
+    /// The struct's members as `(name, type)` pairs, in declaration order.
+    const FIELDS: &'static [(&'static str, &'static str)];
+
+    /// Returns the ABI-encoded member values, in [`FIELDS`](Self::FIELDS) order.
+    fn encode_values(&self) -> Vec<B256>;
+
+    /// Returns the hash of the struct's type string.
+    fn type_hash() -> B256 {
+        let members: Vec<String> = Self::FIELDS
+            .iter()
+            .map(|(name, ty)| format!("{ty} {name}"))
+            .collect();
+        keccak256(format!("{}({})", Self::PRIMARY_TYPE, members.join(",")))
+    }
+
+    /// Returns `hashStruct(message)`.
+    fn struct_hash(&self) -> B256 {
+        let mut encoded = Vec::with_capacity(32 * (Self::FIELDS.len() + 1));
+        encoded.extend_from_slice(Self::type_hash().as_slice());
+        for word in self.encode_values() {
+            encoded.extend_from_slice(word.as_slice());
+        }
+        keccak256(encoded)
+    }
+}
+
+/// A message bound to the domain it is signed under.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedData<M> {
+    /// The signing domain.
+    pub domain: Eip712Domain,
+    /// The message to sign.
+    pub message: M,
+}
+
+impl<M: Eip712Message> TypedData<M> {
+    /// Returns the digest to sign, `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+    pub fn signing_hash(&self) -> B256 {
+        let mut encoded = Vec::with_capacity(66);
+        encoded.extend_from_slice(&[0x19, 0x01]);
+        encoded.extend_from_slice(self.domain.separator().as_slice());
+        encoded.extend_from_slice(self.message.struct_hash().as_slice());
+        keccak256(encoded)
+    }
+
+    /// Returns the typed data as the JSON document accepted by
+    /// `eth_signTypedData_v4`, for signing with a browser or hardware wallet.
+    pub fn to_json(&self) -> String {
+        let domain = &self.domain;
+        let message: Vec<String> = M::FIELDS
+            .iter()
+            .zip(self.message.encode_values())
+            .map(|((name, ty), word)| format!(r#""{name}":"{}""#, json_value(ty, word)))
+            .collect();
+        format!(
+            concat!(
+                r#"{{"types":{{"EIP712Domain":[{{"name":"name","type":"string"}},"#,
+                r#"{{"name":"version","type":"string"}},{{"name":"chainId","type":"uint256"}},"#,
+                r#"{{"name":"verifyingContract","type":"address"}}],"{}":[{}]}},"#,
+                r#""primaryType":"{}","domain":{{"name":"{}","version":"{}","chainId":{},"#,
+                r#""verifyingContract":"{}"}},"message":{{{}}}}}"#
+            ),
+            M::PRIMARY_TYPE,
+            json_fields::<M>(),
+            M::PRIMARY_TYPE,
+            domain.name,
+            domain.version,
+            domain.chain_id,
+            domain.verifying_contract,
+            message.join(","),
+        )
+    }
+}
+
+fn json_fields<M: Eip712Message>() -> String {
+    let fields: Vec<String> = M::FIELDS
+        .iter()
+        .map(|(name, ty)| format!(r#"{{"name":"{name}","type":"{ty}"}}"#))
+        .collect();
+    fields.join(",")
+}
+
+/// Renders a member value the way wallets expect it: checksummed addresses,
+/// decimal integers and hex-encoded bytes.
+fn json_value(ty: &str, word: B256) -> String {
+    match ty {
+        "address" => Address::from_word(word).to_string(),
+        "uint256" => U256::from_be_bytes(word.0).to_string(),
+        _ => word.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_separators_match_the_domains() {
+        for (chain, _) in crate::all() {
+            if let Some(separator) = usdc_domain_separator(chain) {
+                let domain = usdc_eip712_domain(chain).unwrap();
+                assert_eq!(separator, domain.separator(), "{chain:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn only_fiat_token_deployments_have_a_domain() {
+        for (chain, _) in crate::all() {
+            let supported = SupportedUsdcChain::try_from(chain).unwrap();
+            let expected = fiat_token_eip712_version(supported).is_some();
+            assert_eq!(usdc_eip712_domain(chain).is_ok(), expected, "{chain:?}");
+        }
+    }
+}
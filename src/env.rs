@@ -0,0 +1,101 @@
+//! Overriding [`UsdcRegistry`] entries from environment variables.
+
+use crate::{parse_address, UsdcError, UsdcRegistry};
+use alloy_chains::Chain;
+
+/// The prefix of the environment variables read by [`UsdcRegistry::from_env`].
+///
+/// A variable named `USDSHE_USDC_<chain ID>` sets the USDC address for that
+/// chain, e.g. `USDSHE_USDC_8453=0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913`. An
+/// empty value removes the chain from the registry.
+pub const ENV_PREFIX: &str = "USDSHE_USDC_";
+
+impl UsdcRegistry {
+    /// Creates a registry from the built-in data with overrides from the process
+    /// environment applied. See [`ENV_PREFIX`] for the variable format.
+    ///
+    /// Variables whose names are not valid UTF-8 are ignored, like any other
+    /// variable outside the prefix.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::NonUnicodeEnvVar`]: If a `USDSHE_USDC_*` value is not valid
+    ///   UTF-8.
+    /// - Otherwise, see [`UsdcRegistry::apply_env_vars`].
+    pub fn from_env() -> Result<Self, UsdcError> {
+        let vars = std::env::vars_os()
+            .filter_map(|(name, value)| Some((name.into_string().ok()?, value)))
+            .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+            .map(|(name, value)| match value.into_string() {
+                Ok(value) => Ok((name, value)),
+                Err(_) => Err(UsdcError::NonUnicodeEnvVar(name)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut registry = Self::new();
+        registry.apply_env_vars(vars)?;
+        Ok(registry)
+    }
+
+    /// Applies overrides from `(name, value)` pairs, ignoring names that do not
+    /// start with [`ENV_PREFIX`].
+    ///
+    /// Taking the variables as an iterator keeps the logic testable without
+    /// touching the process environment.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use usdshe::UsdcRegistry;
+    /// use alloy_chains::{Chain, NamedChain};
+    /// use alloy_primitives::address;
+    ///
+    /// let mut registry = UsdcRegistry::new();
+    /// registry
+    ///     .apply_env_vars([
+    ///         ("USDSHE_USDC_12345", "0x1111111111111111111111111111111111111111"),
+    ///         ("USDSHE_USDC_250", ""),
+    ///         ("PATH", "/usr/bin"),
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     registry.get(Chain::from_id(12345)),
+    ///     Some(address!("0x1111111111111111111111111111111111111111"))
+    /// );
+    /// assert_eq!(registry.get(NamedChain::Fantom), None);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnknownChainName`]: If the suffix after the prefix is not a
+    ///   numeric chain ID.
+    /// - [`UsdcError::AddressParseError`]: If a value is not a valid address.
+    pub fn apply_env_vars<K, V>(
+        &mut self,
+        vars: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<(), UsdcError>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        for (name, value) in vars {
+            let Some(suffix) = name.as_ref().strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            let id = suffix
+                .parse::<u64>()
+                .map_err(|_| UsdcError::UnknownChainName(suffix.to_string()))?;
+            let chain = Chain::from_id(id);
+
+            match value.as_ref().trim() {
+                "" => {
+                    self.remove(chain);
+                }
+                address => {
+                    self.insert(chain, parse_address(address)?);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! Circle's Euro Coin (EURC) addresses.
+
+use crate::{TokenKind, Tokens, UsdcError};
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::{address, Address};
+
+/// EURC on Ethereum mainnet.
+pub const ETHEREUM_EURC: Address = address!("0x1aBaEA1f7C830bD89Acc67eC4af516284b1bC33c");
+/// EURC on Avalanche C-Chain.
+pub const AVALANCHE_EURC: Address = address!("0xC891EB4cbdEFf6e073e859e987815Ed1505c2ACD");
+/// EURC on Base.
+pub const BASE_EURC: Address = address!("0x60a3E35Cc302bFA44Cb288Bc5a4F316Fdb1adb42");
+/// EURC on Base Sepolia.
+pub const BASE_SEPOLIA_EURC: Address = address!("0x808456652fdb597867f38412077A9182bf77359F");
+/// EURC on Ethereum Sepolia.
+pub const ETHEREUM_SEPOLIA_EURC: Address = address!("0x08210F9170F89Ab7658F0B5E3fF39b0E03C594D4");
+
+/// A trait for types that can provide an EURC contract address, mirroring
+/// [`Usdc`](crate::Usdc) for euro-denominated flows.
+///
+/// EURC is issued natively by Circle, like USDC, and uses 6 decimals on every
+/// chain.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{Eurc, UsdcError, BASE_EURC};
+/// use alloy_chains::{Chain, NamedChain};
+///
+/// assert_eq!(NamedChain::Base.eurc_address().unwrap(), BASE_EURC);
+/// assert!(Chain::from_id(8453).is_eurc(BASE_EURC));
+/// assert!(matches!(
+///     NamedChain::Arbitrum.eurc_address(),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Arbitrum))
+/// ));
+/// ```
+pub trait Eurc {
+    /// Returns the EURC contract address for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but has no known
+    ///   EURC address.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn eurc_address(&self) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical EURC contract for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_eurc(&self, address: Address) -> bool {
+        self.eurc_address().is_ok_and(|eurc| eurc == address)
+    }
+}
+
+pub(crate) const fn eurc_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_EURC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_EURC),
+        #[cfg(feature = "testnets")]
+        NamedChain::BaseSepolia => Some(BASE_SEPOLIA_EURC),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_EURC),
+        #[cfg(feature = "testnets")]
+        NamedChain::Sepolia => Some(ETHEREUM_SEPOLIA_EURC),
+        _ => None,
+    }
+}
+
+impl Eurc for NamedChain {
+    fn eurc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Eurc)
+    }
+}
+
+impl Eurc for Chain {
+    fn eurc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Eurc)
+    }
+}
+
+impl Eurc for u64 {
+    fn eurc_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Eurc)
+    }
+}
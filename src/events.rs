@@ -0,0 +1,339 @@
+//! Decoding USDC `Transfer` and `Approval` logs.
+
+use crate::{abi, Usdc, UsdcError};
+use alloc::{format, string::String, vec::Vec};
+use alloy_chains::NamedChain;
+use alloy_primitives::{b256, Address, Log, B256, U256};
+use thiserror::Error;
+
+/// `topic0` of `Transfer(address,address,uint256)`.
+pub const TRANSFER_TOPIC: B256 =
+    b256!("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+/// `topic0` of `Approval(address,address,uint256)`.
+pub const APPROVAL_TOPIC: B256 =
+    b256!("0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+
+/// A decoded `Transfer` event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TransferEvent {
+    /// The sender, or the zero address for a mint.
+    pub from: Address,
+    /// The recipient, or the zero address for a burn.
+    pub to: Address,
+    /// The amount, in raw token units.
+    pub value: U256,
+}
+
+/// A decoded `Approval` event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ApprovalEvent {
+    /// The token holder.
+    pub owner: Address,
+    /// The account allowed to spend the tokens.
+    pub spender: Address,
+    /// The new allowance, in raw token units.
+    pub value: U256,
+}
+
+/// An event emitted by a USDC contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UsdcEvent {
+    /// A `Transfer`, including mints and burns.
+    Transfer(TransferEvent),
+    /// An `Approval`.
+    Approval(ApprovalEvent),
+}
+
+/// Represents errors that can occur when decoding a log.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum LogError {
+    /// The log was not emitted by the chain's USDC contract, e.g. a spoofed
+    /// token with the same events.
+    #[error("Log emitted by {address}, which is not USDC on chain {chain:?}")]
+    NotUsdc {
+        /// The chain the log was expected from.
+        chain: NamedChain,
+        /// The address that emitted the log.
+        address: Address,
+    },
+
+    /// The log's `topic0` is neither `Transfer` nor `Approval`.
+    #[error("Unsupported event topic: {0}")]
+    UnknownEvent(B256),
+
+    /// The topics or data do not match the event's layout.
+    #[error("Malformed '{event}' log")]
+    Malformed {
+        /// The name of the event.
+        event: &'static str,
+    },
+}
+
+/// Decodes a `Transfer` or `Approval` log emitted on `chain`, after checking
+/// that it was emitted by the chain's registered USDC contract.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{decode_usdc_log, LogError, UsdcEvent, BASE_USDC, TRANSFER_TOPIC};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, Address, Log, B256, U256};
+///
+/// let from = address!("0x1111111111111111111111111111111111111111");
+/// let to = address!("0x2222222222222222222222222222222222222222");
+/// let log = Log::new(
+///     BASE_USDC,
+///     vec![TRANSFER_TOPIC, from.into_word(), to.into_word()],
+///     U256::from(1_000_000).to_be_bytes_vec().into(),
+/// )
+/// .unwrap();
+///
+/// match decode_usdc_log(NamedChain::Base, &log).unwrap() {
+///     UsdcEvent::Transfer(transfer) => assert_eq!(transfer.value, U256::from(1_000_000)),
+///     UsdcEvent::Approval(_) => unreachable!(),
+/// }
+///
+/// // The same log from any other contract is rejected.
+/// let spoofed = Log { address: Address::repeat_byte(0x42), ..log };
+/// assert!(matches!(
+///     decode_usdc_log(NamedChain::Base, &spoofed),
+///     Err(LogError::NotUsdc { .. })
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// - [`LogError::NotUsdc`]: If the log was not emitted by USDC on `chain`,
+///   including when the chain has no known USDC address.
+/// - [`LogError::UnknownEvent`]: If the log is another event.
+/// - [`LogError::Malformed`]: If the log does not match the event's layout.
+pub fn decode_usdc_log(chain: NamedChain, log: &Log) -> Result<UsdcEvent, LogError> {
+    if !chain.is_usdc(log.address) {
+        return Err(LogError::NotUsdc {
+            chain,
+            address: log.address,
+        });
+    }
+    decode_event(log)
+}
+
+/// A log filter for USDC `Transfer` events on one chain, created with
+/// [`usdc_transfer_filter`].
+///
+/// The fields map onto alloy's `Filter`:
+///
+/// ```rust,ignore
+/// let mut filter = Filter::new().address(f.address).event_signature(TRANSFER_TOPIC);
+/// if let Some(from) = f.from {
+///     filter = filter.topic1(from.into_word());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TransferFilter {
+    /// The USDC contract address.
+    pub address: Address,
+    /// Only match transfers sent by this address (`topic1`).
+    pub from: Option<Address>,
+    /// Only match transfers received by this address (`topic2`).
+    pub to: Option<Address>,
+    /// The first block to search, inclusive.
+    pub from_block: Option<u64>,
+    /// The last block to search, inclusive.
+    pub to_block: Option<u64>,
+    /// Only match transfers of at least this many raw token units. Nodes
+    /// cannot filter on log data, so this is applied by [`decode`](Self::decode)
+    /// and [`matches`](Self::matches) rather than sent to the node.
+    pub min_value: Option<U256>,
+}
+
+impl TransferFilter {
+    /// Only matches transfers sent by `from`.
+    pub fn from(mut self, from: Address) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Only matches transfers received by `to`.
+    pub fn to(mut self, to: Address) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Starts the search at `block`.
+    pub fn from_block(mut self, block: u64) -> Self {
+        self.from_block = Some(block);
+        self
+    }
+
+    /// Ends the search at `block`.
+    pub fn to_block(mut self, block: u64) -> Self {
+        self.to_block = Some(block);
+        self
+    }
+
+    /// Only matches transfers of at least `value` raw token units.
+    pub fn min_value(mut self, value: U256) -> Self {
+        self.min_value = Some(value);
+        self
+    }
+
+    /// Returns the filter's topics: `Transfer`, then the sender and recipient
+    /// words, `None` matching any value.
+    pub fn topics(&self) -> [Option<B256>; 3] {
+        [
+            Some(TRANSFER_TOPIC),
+            self.from.map(abi::address_word),
+            self.to.map(abi::address_word),
+        ]
+    }
+
+    /// Returns `true` if `log` is a transfer matching the address, topics and
+    /// minimum value. Block bounds are not checked, since a [`Log`] does not
+    /// carry its block number.
+    pub fn matches(&self, log: &Log) -> bool {
+        self.decode(log).is_some()
+    }
+
+    /// Decodes `log` if it is a transfer matching the filter, e.g. to turn a
+    /// page of `eth_getLogs` results into transfers with `filter_map`. For live
+    /// subscriptions, see `subscribe_usdc_transfers` under the `onchain`
+    /// feature.
+    ///
+    #[cfg_attr(feature = "l2s", doc = "```rust")]
+    #[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+    /// use usdshe::usdc_transfer_filter;
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::{Address, Log, U256};
+    ///
+    /// let filter = usdc_transfer_filter(NamedChain::Base)
+    ///     .unwrap()
+    ///     .min_value(U256::from(10_000_000_000u64));
+    /// # let logs: Vec<Log> = Vec::new();
+    ///
+    /// // `logs` is any iterator of logs from the node.
+    /// let whales = logs.iter().filter_map(|log| filter.decode(log));
+    /// # assert_eq!(whales.count(), 0);
+    /// ```
+    pub fn decode(&self, log: &Log) -> Option<TransferEvent> {
+        if log.address != self.address
+            || !self
+                .topics()
+                .iter()
+                .zip(log.topics())
+                .all(|(expected, topic)| expected.is_none_or(|expected| expected == *topic))
+        {
+            return None;
+        }
+        match decode_event(log) {
+            Ok(UsdcEvent::Transfer(transfer))
+                if self.min_value.is_none_or(|min| transfer.value >= min) =>
+            {
+                Some(transfer)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the filter object passed to `eth_getLogs` and
+    /// `eth_subscribe("logs", ...)`. [`min_value`](Self::min_value) is not part
+    /// of it.
+    pub fn to_json(&self) -> String {
+        let mut topics = Vec::from(self.topics());
+        while topics.last() == Some(&None) {
+            topics.pop();
+        }
+        let topics: Vec<String> = topics
+            .iter()
+            .map(|topic| match topic {
+                Some(topic) => format!(r#""{topic}""#),
+                None => String::from("null"),
+            })
+            .collect();
+        let mut json = format!(
+            r#"{{"address":"{}","topics":[{}]"#,
+            self.address,
+            topics.join(",")
+        );
+        if let Some(block) = self.from_block {
+            json.push_str(&format!(r#","fromBlock":"{block:#x}""#));
+        }
+        if let Some(block) = self.to_block {
+            json.push_str(&format!(r#","toBlock":"{block:#x}""#));
+        }
+        json.push('}');
+        json
+    }
+}
+
+/// Returns a filter for `Transfer` events of the USDC contract on `chain`,
+/// to narrow down with the [`TransferFilter`] builder methods.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{usdc_transfer_filter, TRANSFER_TOPIC};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::address;
+///
+/// let treasury = address!("0x1111111111111111111111111111111111111111");
+/// let filter = usdc_transfer_filter(NamedChain::Mainnet)
+///     .unwrap()
+///     .to(treasury)
+///     .from_block(21_000_000);
+///
+/// assert_eq!(filter.topics(), [Some(TRANSFER_TOPIC), None, Some(treasury.into_word())]);
+/// assert!(filter.to_json().ends_with(r#""fromBlock":"0x1406f40"}"#));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_transfer_filter(chain: NamedChain) -> Result<TransferFilter, UsdcError> {
+    Ok(TransferFilter {
+        address: chain.usdc_address()?,
+        from: None,
+        to: None,
+        from_block: None,
+        to_block: None,
+        min_value: None,
+    })
+}
+
+/// Decodes a log by its `topic0`, without checking who emitted it.
+fn decode_event(log: &Log) -> Result<UsdcEvent, LogError> {
+    let topic0 = log.topics().first().copied().unwrap_or_default();
+    let event = match topic0 {
+        TRANSFER_TOPIC => "Transfer",
+        APPROVAL_TOPIC => "Approval",
+        other => return Err(LogError::UnknownEvent(other)),
+    };
+    let malformed = || LogError::Malformed { event };
+
+    let [_, first, second] = log.topics() else {
+        return Err(malformed());
+    };
+    let first = abi::word_to_address(*first).ok_or_else(malformed)?;
+    let second = abi::word_to_address(*second).ok_or_else(malformed)?;
+    let data = &log.data.data;
+    if data.len() != 32 {
+        return Err(malformed());
+    }
+    let value = U256::from_be_slice(data);
+
+    Ok(if topic0 == TRANSFER_TOPIC {
+        UsdcEvent::Transfer(TransferEvent {
+            from: first,
+            to: second,
+            value,
+        })
+    } else {
+        UsdcEvent::Approval(ApprovalEvent {
+            owner: first,
+            spender: second,
+            value,
+        })
+    })
+}
@@ -0,0 +1,323 @@
+//! A C ABI over the built-in lookup table.
+//!
+//! Every function returns one of the `USDSHE_*` status codes and writes its
+//! result through an out-pointer. The codes are stable: new ones may be added,
+//! but existing values never change meaning. Arguments are only integers and
+//! byte buffers, so `cbindgen` can generate a header directly from this module.
+//!
+//! Build a linkable library with
+//! `cargo rustc --release --features ffi --crate-type staticlib` (or `cdylib`).
+//!
+//! `bindings/python/usdshe.py` wraps the `cdylib` with `ctypes`, exposing
+//! `usdc_address(chain_id)` and a `UsdcTokenInfo` dataclass to Python.
+
+use crate::{chains_for_address, format_usdc, parse_usdc, usdc_decimals, Usdc, UsdcError};
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, U256};
+use core::{ffi::c_char, ffi::CStr, ptr, slice};
+
+/// The call succeeded.
+pub const USDSHE_OK: i32 = 0;
+/// No USDC address is known for the chain ID.
+pub const USDSHE_ERR_UNSUPPORTED_CHAIN: i32 = 1;
+/// A required pointer argument was null.
+pub const USDSHE_ERR_NULL_POINTER: i32 = 2;
+/// The output buffer is too small for the result.
+pub const USDSHE_ERR_BUFFER_TOO_SMALL: i32 = 3;
+/// The amount is not a decimal number with at most the token's decimals, or
+/// does not fit in a `uint256`.
+pub const USDSHE_ERR_INVALID_AMOUNT: i32 = 4;
+
+/// The buffer size needed by [`usdshe_usdc_address`]: a `0x`-prefixed,
+/// checksummed address plus a NUL terminator.
+pub const USDSHE_ADDRESS_STR_LEN: usize = 43;
+/// The length of a raw address, as written by [`usdshe_usdc_address_bytes`].
+pub const USDSHE_ADDRESS_LEN: usize = 20;
+/// The length of a raw amount: a big-endian `uint256`.
+pub const USDSHE_AMOUNT_LEN: usize = 32;
+/// A buffer size that fits any amount written by [`usdshe_format_usdc`], plus
+/// a NUL terminator.
+pub const USDSHE_AMOUNT_STR_LEN: usize = 258;
+
+/// Writes the checksummed USDC address on `chain_id` into `out_buf` as a
+/// NUL-terminated string.
+///
+/// Returns [`USDSHE_OK`], [`USDSHE_ERR_UNSUPPORTED_CHAIN`],
+/// [`USDSHE_ERR_NULL_POINTER`] or [`USDSHE_ERR_BUFFER_TOO_SMALL`]. The buffer is
+/// left untouched on error.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::ffi::{usdshe_usdc_address, USDSHE_ADDRESS_STR_LEN, USDSHE_ERR_UNSUPPORTED_CHAIN, USDSHE_OK};
+///
+/// let mut buf = [0u8; USDSHE_ADDRESS_STR_LEN];
+/// let status = unsafe { usdshe_usdc_address(8453, buf.as_mut_ptr(), buf.len()) };
+/// assert_eq!(status, USDSHE_OK);
+/// assert_eq!(&buf[..42], b"0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913");
+///
+/// let status = unsafe { usdshe_usdc_address(12_345, buf.as_mut_ptr(), buf.len()) };
+/// assert_eq!(status, USDSHE_ERR_UNSUPPORTED_CHAIN);
+/// ```
+///
+/// # Safety
+///
+/// `out_buf` must be null or valid for writes of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn usdshe_usdc_address(
+    chain_id: u64,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> i32 {
+    if out_buf.is_null() {
+        return USDSHE_ERR_NULL_POINTER;
+    }
+    if out_len < USDSHE_ADDRESS_STR_LEN {
+        return USDSHE_ERR_BUFFER_TOO_SMALL;
+    }
+    let address = match lookup(chain_id) {
+        Ok(address) => address,
+        Err(code) => return code,
+    };
+    let checksummed = address.to_checksum(None);
+    // SAFETY: the caller guarantees `out_len` writable bytes, and we checked
+    // `out_len` covers the address and terminator.
+    let out = unsafe { slice::from_raw_parts_mut(out_buf, USDSHE_ADDRESS_STR_LEN) };
+    out[..USDSHE_ADDRESS_STR_LEN - 1].copy_from_slice(checksummed.as_bytes());
+    out[USDSHE_ADDRESS_STR_LEN - 1] = 0;
+    USDSHE_OK
+}
+
+/// Writes the 20 raw bytes of the USDC address on `chain_id` into `out`.
+///
+/// Returns [`USDSHE_OK`], [`USDSHE_ERR_UNSUPPORTED_CHAIN`] or
+/// [`USDSHE_ERR_NULL_POINTER`].
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of [`USDSHE_ADDRESS_LEN`] bytes.
+#[no_mangle]
+pub unsafe extern "C" fn usdshe_usdc_address_bytes(chain_id: u64, out: *mut u8) -> i32 {
+    if out.is_null() {
+        return USDSHE_ERR_NULL_POINTER;
+    }
+    match lookup(chain_id) {
+        Ok(address) => {
+            // SAFETY: the caller guarantees `USDSHE_ADDRESS_LEN` writable bytes.
+            unsafe { ptr::copy_nonoverlapping(address.as_ptr(), out, USDSHE_ADDRESS_LEN) };
+            USDSHE_OK
+        }
+        Err(code) => code,
+    }
+}
+
+/// Writes the decimals of the USDC token on `chain_id` into `out`.
+///
+/// Returns [`USDSHE_OK`], [`USDSHE_ERR_UNSUPPORTED_CHAIN`] or
+/// [`USDSHE_ERR_NULL_POINTER`].
+///
+/// # Safety
+///
+/// `out` must be null or valid for a one-byte write.
+#[no_mangle]
+pub unsafe extern "C" fn usdshe_usdc_decimals(chain_id: u64, out: *mut u8) -> i32 {
+    if out.is_null() {
+        return USDSHE_ERR_NULL_POINTER;
+    }
+    let decimals = NamedChain::try_from(chain_id)
+        .map_err(|_| UsdcError::UnsupportedChainId(chain_id))
+        .and_then(usdc_decimals);
+    match decimals {
+        Ok(decimals) => {
+            // SAFETY: the caller guarantees a writable byte.
+            unsafe { out.write(decimals) };
+            USDSHE_OK
+        }
+        Err(_) => USDSHE_ERR_UNSUPPORTED_CHAIN,
+    }
+}
+
+/// Writes `1` into `out` if the 20 bytes at `address` are the USDC contract on
+/// `chain_id`, and `0` otherwise, including for unsupported chains.
+///
+/// Returns [`USDSHE_OK`] or [`USDSHE_ERR_NULL_POINTER`].
+///
+/// # Safety
+///
+/// `address` must be null or valid for reads of [`USDSHE_ADDRESS_LEN`] bytes,
+/// and `out` must be null or valid for a one-byte write.
+#[no_mangle]
+pub unsafe extern "C" fn usdshe_is_usdc(chain_id: u64, address: *const u8, out: *mut u8) -> i32 {
+    if address.is_null() || out.is_null() {
+        return USDSHE_ERR_NULL_POINTER;
+    }
+    // SAFETY: the caller guarantees `USDSHE_ADDRESS_LEN` readable bytes.
+    let bytes = unsafe { slice::from_raw_parts(address, USDSHE_ADDRESS_LEN) };
+    let is_usdc = lookup(chain_id) == Ok(Address::from_slice(bytes));
+    // SAFETY: the caller guarantees a writable byte.
+    unsafe { out.write(u8::from(is_usdc)) };
+    USDSHE_OK
+}
+
+/// Writes the IDs of the chains where the 20 bytes at `address` are the USDC
+/// contract into `out_ids`, and their number into `out_count`.
+///
+/// Returns [`USDSHE_OK`], [`USDSHE_ERR_NULL_POINTER`] or
+/// [`USDSHE_ERR_BUFFER_TOO_SMALL`]. When `out_len` is too small, `out_count`
+/// still receives the number of chains, so the caller can retry with a larger
+/// buffer; `out_ids` is left untouched.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::ffi::{usdshe_usdc_chain_ids, USDSHE_ERR_BUFFER_TOO_SMALL, USDSHE_OK};
+/// use usdshe::BASE_USDC;
+///
+/// let mut ids = [0u64; 8];
+/// let mut count = 0;
+/// let status =
+///     unsafe { usdshe_usdc_chain_ids(BASE_USDC.as_ptr(), ids.as_mut_ptr(), ids.len(), &mut count) };
+/// assert_eq!(status, USDSHE_OK);
+/// assert_eq!(&ids[..count], [8453]);
+///
+/// let status = unsafe { usdshe_usdc_chain_ids(BASE_USDC.as_ptr(), ids.as_mut_ptr(), 0, &mut count) };
+/// assert_eq!((status, count), (USDSHE_ERR_BUFFER_TOO_SMALL, 1));
+/// ```
+///
+/// # Safety
+///
+/// `address` must be null or valid for reads of [`USDSHE_ADDRESS_LEN`] bytes,
+/// `out_ids` must be null or valid for writes of `out_len` chain IDs, and
+/// `out_count` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn usdshe_usdc_chain_ids(
+    address: *const u8,
+    out_ids: *mut u64,
+    out_len: usize,
+    out_count: *mut usize,
+) -> i32 {
+    if address.is_null() || out_ids.is_null() || out_count.is_null() {
+        return USDSHE_ERR_NULL_POINTER;
+    }
+    // SAFETY: the caller guarantees `USDSHE_ADDRESS_LEN` readable bytes.
+    let bytes = unsafe { slice::from_raw_parts(address, USDSHE_ADDRESS_LEN) };
+    let chains = chains_for_address(Address::from_slice(bytes));
+    // SAFETY: the caller guarantees a writable count.
+    unsafe { out_count.write(chains.len()) };
+    if chains.len() > out_len {
+        return USDSHE_ERR_BUFFER_TOO_SMALL;
+    }
+    // SAFETY: the caller guarantees `out_len` writable IDs, and we checked
+    // `out_len` covers every chain.
+    let out = unsafe { slice::from_raw_parts_mut(out_ids, chains.len()) };
+    for (out, chain) in out.iter_mut().zip(chains) {
+        *out = chain as u64;
+    }
+    USDSHE_OK
+}
+
+/// Writes `amount`, a big-endian `uint256` of raw units, as a NUL-terminated
+/// decimal string with `decimals` decimals into `out_buf`, as
+/// [`format_usdc`] does.
+///
+/// Returns [`USDSHE_OK`], [`USDSHE_ERR_NULL_POINTER`] or
+/// [`USDSHE_ERR_BUFFER_TOO_SMALL`]. The buffer is left untouched on error;
+/// [`USDSHE_AMOUNT_STR_LEN`] bytes are always enough.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::ffi::{usdshe_format_usdc, USDSHE_AMOUNT_STR_LEN, USDSHE_OK};
+/// use alloy_primitives::U256;
+///
+/// let amount = U256::from(1_500_000).to_be_bytes::<32>();
+/// let mut buf = [0u8; USDSHE_AMOUNT_STR_LEN];
+/// let status = unsafe { usdshe_format_usdc(amount.as_ptr(), 6, buf.as_mut_ptr(), buf.len()) };
+/// assert_eq!(status, USDSHE_OK);
+/// assert_eq!(&buf[..4], b"1.5\0");
+/// ```
+///
+/// # Safety
+///
+/// `amount` must be null or valid for reads of [`USDSHE_AMOUNT_LEN`] bytes, and
+/// `out_buf` must be null or valid for writes of `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn usdshe_format_usdc(
+    amount: *const u8,
+    decimals: u8,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> i32 {
+    if amount.is_null() || out_buf.is_null() {
+        return USDSHE_ERR_NULL_POINTER;
+    }
+    // SAFETY: the caller guarantees `USDSHE_AMOUNT_LEN` readable bytes.
+    let bytes = unsafe { slice::from_raw_parts(amount, USDSHE_AMOUNT_LEN) };
+    let formatted = format_usdc(U256::from_be_slice(bytes), decimals);
+    if out_len <= formatted.len() {
+        return USDSHE_ERR_BUFFER_TOO_SMALL;
+    }
+    // SAFETY: the caller guarantees `out_len` writable bytes, and we checked
+    // `out_len` covers the string and terminator.
+    let out = unsafe { slice::from_raw_parts_mut(out_buf, formatted.len() + 1) };
+    out[..formatted.len()].copy_from_slice(formatted.as_bytes());
+    out[formatted.len()] = 0;
+    USDSHE_OK
+}
+
+/// Parses `amount`, a NUL-terminated decimal string such as `"1.5"`, into raw
+/// units with `decimals` decimals, as [`parse_usdc`] does,
+/// and writes them into `out` as a big-endian `uint256`.
+///
+/// Returns [`USDSHE_OK`], [`USDSHE_ERR_NULL_POINTER`] or
+/// [`USDSHE_ERR_INVALID_AMOUNT`]. `out` is left untouched on error.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::ffi::{usdshe_parse_usdc, USDSHE_AMOUNT_LEN, USDSHE_ERR_INVALID_AMOUNT, USDSHE_OK};
+/// use alloy_primitives::U256;
+///
+/// let mut out = [0u8; USDSHE_AMOUNT_LEN];
+/// let status = unsafe { usdshe_parse_usdc(c"1.5".as_ptr(), 6, out.as_mut_ptr()) };
+/// assert_eq!(status, USDSHE_OK);
+/// assert_eq!(U256::from_be_bytes(out), U256::from(1_500_000));
+///
+/// let status = unsafe { usdshe_parse_usdc(c"0.0000001".as_ptr(), 6, out.as_mut_ptr()) };
+/// assert_eq!(status, USDSHE_ERR_INVALID_AMOUNT);
+/// ```
+///
+/// # Safety
+///
+/// `amount` must be null or a valid NUL-terminated string, and `out` must be
+/// null or valid for writes of [`USDSHE_AMOUNT_LEN`] bytes.
+#[no_mangle]
+pub unsafe extern "C" fn usdshe_parse_usdc(
+    amount: *const c_char,
+    decimals: u8,
+    out: *mut u8,
+) -> i32 {
+    if amount.is_null() || out.is_null() {
+        return USDSHE_ERR_NULL_POINTER;
+    }
+    // SAFETY: the caller guarantees a NUL-terminated string.
+    let amount = unsafe { CStr::from_ptr(amount) };
+    let Ok(raw) = amount
+        .to_str()
+        .map_err(drop)
+        .and_then(|amount| parse_usdc(amount, decimals).map_err(drop))
+    else {
+        return USDSHE_ERR_INVALID_AMOUNT;
+    };
+    // SAFETY: the caller guarantees `USDSHE_AMOUNT_LEN` writable bytes.
+    unsafe { ptr::copy_nonoverlapping(raw.to_be_bytes::<32>().as_ptr(), out, USDSHE_AMOUNT_LEN) };
+    USDSHE_OK
+}
+
+fn lookup(chain_id: u64) -> Result<Address, i32> {
+    chain_id
+        .usdc_address()
+        .map_err(|_| USDSHE_ERR_UNSUPPORTED_CHAIN)
+}
@@ -0,0 +1,36 @@
+use crate::{FiatTokenVersion, UsdcVariant};
+use alloc::string::String;
+use alloy_primitives::Address;
+
+/// Metadata describing the USDC token deployed on a chain.
+///
+/// Returned by [`SupportedUsdcChain::usdc_token_info`](crate::SupportedUsdcChain::usdc_token_info)
+/// and [`usdc_token_info`](crate::usdc_token_info).
+///
+/// With the `serde` feature, the address serializes as a checksummed hex string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsdcTokenInfo {
+    /// The token contract address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_helpers::checksummed")
+    )]
+    pub address: Address,
+    /// The number of decimals the token uses.
+    pub decimals: u8,
+    /// The token symbol, as returned by `symbol()`.
+    pub symbol: String,
+    /// The token name, as returned by `name()`.
+    pub name: String,
+    /// Whether the token is Circle-native or bridged.
+    pub variant: UsdcVariant,
+    /// The token page on the chain's canonical block explorer.
+    pub explorer_url: String,
+    /// The block in which the token contract was deployed, where recorded.
+    pub deployed_block: Option<u64>,
+    /// The FiatToken release the contract runs, where recorded. `None` does not
+    /// imply the token is not a FiatToken; see
+    /// [`SupportedUsdcChain::is_fiat_token`](crate::SupportedUsdcChain::is_fiat_token).
+    pub fiat_token_version: Option<FiatTokenVersion>,
+}
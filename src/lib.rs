@@ -7,11 +7,17 @@
 //! chain identifiers to retrieve the respective USDC contract address. Currently,
 //! an implementation for [`alloy_chains::NamedChain`] is provided.
 //!
+//! If you validate a chain once up front, convert it into a [`SupportedUsdcChain`],
+//! whose [`SupportedUsdcChain::usdc_address`] lookup is infallible.
+//!
+//! The addresses are also exported directly as [`Address`] constants (e.g.
+//! [`ETHEREUM_USDC`]), so they can be used in `const` contexts without any lookup.
+//!
 //! ## Examples
 //!
 //! ```rust
 //! use usdshe::{Usdc, UsdcError};
-//! use alloy_chains::NamedChain;
+//! use alloy_chains::{Chain, NamedChain};
 //! use alloy_primitives::Address;
 //! use std::str::FromStr;
 //!
@@ -42,33 +48,299 @@
 //!     Err(e) => panic!("Unexpected error: {}", e),
 //! }
 //! ```
+//!
+//! ## `no_std`
+//!
+//! The crate is `no_std` compatible (it requires `alloc`). Disable the default
+//! `std` feature to use it in environments such as zkVM guests or embedded
+//! signers:
+//!
+//! ```toml
+//! usdshe = { version = "0.3", default-features = false, features = ["all-chains"] }
+//! ```
+//!
+//! The same configuration builds for `wasm32-unknown-unknown`, e.g. for browser
+//! dapps and Cloudflare Workers. With `std` enabled on that target, avoid
+//! `CachedResolver`, which reads `std::time::Instant`; the browser runtime
+//! does not provide a clock to `std`.
+//!
+//! ## Chain groups
+//!
+//! The chains compiled into [`SupportedUsdcChain`] are selected with the
+//! `ethereum`, `l2s`, `alt-l1s` and `testnets` features, all enabled by default
+//! through `all-chains`. At least one group must be enabled. The address
+//! constants themselves are always available.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+#[cfg(not(any(
+    feature = "ethereum",
+    feature = "l2s",
+    feature = "alt-l1s",
+    feature = "testnets"
+)))]
+compile_error!("enable at least one chain group: `ethereum`, `l2s`, `alt-l1s` or `testnets`");
+
+mod abi;
 mod address;
+mod amount;
+mod btc;
+#[cfg(feature = "std")]
+mod cache;
+mod cctp;
+mod chain;
+#[cfg(feature = "config")]
+mod config;
+mod dai;
+mod deployment;
+mod eip3009;
+mod eip712;
+#[cfg(feature = "env-overrides")]
+mod env;
+mod eurc;
+mod events;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod info;
+#[cfg(any(
+    feature = "config",
+    feature = "remote",
+    feature = "server",
+    feature = "tokenlist"
+))]
+mod json;
+mod lookup;
+mod macros;
+#[cfg(feature = "test-utils")]
+mod mock;
+mod non_evm;
+#[cfg(feature = "onchain")]
+mod onchain;
+mod oracle;
+mod permit;
+mod pyusd;
+mod registry;
+#[cfg(feature = "remote")]
+mod remote;
+mod resolver;
+#[cfg(feature = "serde")]
+mod serde_helpers;
+#[cfg(feature = "server")]
+mod server;
+mod solidity;
+mod stablecoin;
+#[cfg(all(test, feature = "std"))]
+mod test_support;
+mod token;
+#[cfg(feature = "tokenlist")]
+mod tokenlist;
+mod tx;
+mod units;
+mod usdt;
+mod variant;
+mod version;
+mod wrapped_native;
+mod yield_wrappers;
 
-use alloy_chains::NamedChain;
+use alloc::string::String;
+use alloy_chains::{Chain, NamedChain};
 use alloy_primitives::Address;
-use std::str::FromStr;
 use thiserror::Error;
 
+pub use abi::{usdc_abi, usdc_proxy_abi};
 pub use address::*;
+pub use amount::{AmountFormat, UsdcAmount, UsdcAmountError};
+pub use btc::*;
+#[cfg(feature = "std")]
+pub use cache::CachedResolver;
+pub use cctp::*;
+pub use chain::SupportedUsdcChain;
+#[cfg(feature = "config")]
+pub use config::{ConfigError, RegistryOverrides};
+pub use dai::*;
+pub use deployment::{usdc_deployment, usdc_deployments, ChainFamily, ChainRef, UsdcDeployment};
+#[cfg(feature = "getrandom")]
+pub use eip3009::random_authorization_nonce;
+pub use eip3009::{
+    build_cancel_authorization, build_receive_with_authorization,
+    build_transfer_with_authorization, CancelAuthorization, ReceiveWithAuthorization,
+    TransferWithAuthorization, ValidityWindow,
+};
+pub use eip712::{
+    usdc_domain_separator, usdc_eip712_domain, Eip712Domain, Eip712Message, TypedData,
+};
+#[cfg(feature = "env-overrides")]
+pub use env::ENV_PREFIX;
+pub use eurc::*;
+pub use events::{
+    decode_usdc_log, usdc_transfer_filter, ApprovalEvent, LogError, TransferEvent, TransferFilter,
+    UsdcEvent, APPROVAL_TOPIC, TRANSFER_TOPIC,
+};
+pub use info::UsdcTokenInfo;
+#[cfg(any(
+    feature = "config",
+    feature = "remote",
+    feature = "server",
+    feature = "tokenlist"
+))]
+pub use json::JsonError;
+pub use lookup::*;
+#[cfg(all(feature = "test-utils", feature = "onchain"))]
+pub use mock::{deploy_mock_usdc, mock_fiat_token_source, ContractDeployer};
+#[cfg(feature = "test-utils")]
+pub use mock::{MockChain, MockUsdc};
+pub use non_evm::{
+    AptosAddress, CosmosChain, CosmosDenom, MultiChainAddress, MultiChainAddressError,
+    NearAccountId, NonEvmChain, SolanaAddress, StellarAccountId, StellarAsset, SuiCoinType,
+    TronAddress, APTOS_USDC, SOLANA_USDC, STELLAR_USDC_ISSUER, TRON_USDC,
+};
+#[cfg(feature = "onchain")]
+pub use onchain::{
+    cctp_burn_limit, chainlink_latest_price, pyth_latest_price, subscribe_usdc_transfers,
+    usdc_allowance, usdc_balance_of, usdc_balance_of_formatted, usdc_is_blacklisted,
+    usdc_live_implementation, usdc_paused, usdc_roles, usdc_total_supply, verify_domain_separator,
+    verify_onchain, ChainlinkPrice, EthProvider, FieldCheck, LogStream, LogSubscriber,
+    OnchainError, PythPrice, UsdcContract, UsdcProviderExt, UsdcRoles, UsdcSupply, UsdcTransfers,
+    VerificationReport,
+};
+pub use oracle::*;
+pub use permit::{build_permit, Permit};
+pub use pyusd::*;
+pub use registry::{RegistryChain, UsdcRegistry};
+#[cfg(feature = "remote-circle")]
+pub use remote::CircleResolver;
+#[cfg(feature = "remote-coingecko")]
+pub use remote::CoinGeckoResolver;
+#[cfg(feature = "remote-defillama")]
+pub use remote::{DefiLlamaDeployment, DefiLlamaResolver};
+#[cfg(feature = "remote")]
+pub use remote::{HttpClient, HttpRequest, HttpResponse, RemoteError, RetryPolicy};
+pub use resolver::{AsyncUsdc, CompositeResolver, UsdcResolver};
+#[cfg(feature = "server")]
+pub use server::{ServiceResponse, UsdcService};
+pub use stablecoin::*;
+pub use token::{TokenKind, Tokens};
+#[cfg(feature = "tokenlist")]
+pub use tokenlist::*;
+pub use tx::{
+    build_usdc_approve, build_usdc_approve_raw, build_usdc_revoke, build_usdc_transfer,
+    build_usdc_transfer_raw, TransactionRequest, UNLIMITED_APPROVAL,
+};
+pub use units::{format_usdc, normalize_amount, normalize_amount_truncating, parse_usdc};
+pub use usdt::*;
+pub use variant::UsdcVariant;
+pub use version::FiatTokenVersion;
+pub use wrapped_native::*;
+pub use yield_wrappers::*;
 
 /// Represents errors that can occur when retrieving a USDC address.
+///
+/// With the `serde` feature, errors can be serialized (e.g. into API responses);
+/// parse error sources are rendered as their display string.
 #[derive(Error, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UsdcError {
-    /// Indicates that a USDC address is not available or known for the specified chain.
-    #[error("USDC address not available for chain: {0:?}")]
+    /// Indicates that the requested token or contract, e.g. USDC, USDT or a CCTP
+    /// contract, has no known deployment on the specified chain.
+    #[error("Token or contract not available on chain: {0:?}")]
     UnsupportedChain(NamedChain),
 
-    /// Indicates that a known address string failed to parse into a valid [`Address`].
-    /// This should ideally not happen if the constants are well-formed.
+    /// Indicates that a chain ID does not correspond to any [`NamedChain`], so no
+    /// deployment can be known for it.
+    #[error("Token or contract not available on unknown chain ID: {0}")]
+    UnsupportedChainId(u64),
+
+    /// Indicates that a chain name could not be resolved to any known chain.
+    #[error("Unknown chain name: '{0}'")]
+    UnknownChainName(String),
+
+    /// Indicates that an amount does not fit in a `uint256` once scaled to a
+    /// deployment's decimals.
+    #[error("Amount overflows uint256 when scaled to {decimals} decimals")]
+    AmountOverflow {
+        /// The decimals of the deployment the amount was scaled to.
+        decimals: u8,
+    },
+
+    /// Indicates that an amount cannot be represented exactly with a
+    /// deployment's decimals, e.g. 18-decimal dust sent to a 6-decimal chain.
+    #[error("Amount cannot be represented with {decimals} decimals without losing precision")]
+    PrecisionLoss {
+        /// The decimals of the deployment the amount was scaled to.
+        decimals: u8,
+    },
+
+    /// Indicates that the USDC token on a chain is not a FiatToken contract and
+    /// does not verify EIP-712 signatures such as `permit`.
+    #[error("USDC on chain {0:?} does not support EIP-712 signatures")]
+    SignaturesUnsupported(NamedChain),
+
+    /// Indicates that an address string failed to parse into a valid [`Address`].
+    /// The built-in constants are [`Address`] values checked at compile time, so
+    /// lookups against them never return this variant.
     #[error("Failed to parse address string '{address_str}': {source}")]
     AddressParseError {
         /// The address string that failed to parse.
         address_str: String,
         /// The underlying parsing error.
         #[source]
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serde_helpers::display")
+        )]
         source: alloy_primitives::hex::FromHexError,
     },
+
+    /// Indicates that a `USDSHE_USDC_*` environment variable, named here, holds a
+    /// value that is not valid UTF-8.
+    #[cfg(feature = "env-overrides")]
+    #[error("Environment variable {0} is not valid UTF-8")]
+    NonUnicodeEnvVar(String),
+
+    /// Indicates that a remote resolver could not complete a lookup.
+    #[cfg(feature = "remote")]
+    #[error(transparent)]
+    Remote(
+        #[from]
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serde_helpers::display")
+        )]
+        RemoteError,
+    ),
+}
+
+impl UsdcError {
+    /// The error for a chain without a known address, preferring
+    /// [`UsdcError::UnsupportedChain`] when the chain is named.
+    pub(crate) fn unsupported(chain: Chain) -> Self {
+        match chain.named() {
+            Some(named) => Self::UnsupportedChain(named),
+            None => Self::UnsupportedChainId(chain.id()),
+        }
+    }
+}
+
+/// Parses a hex address string, reporting failures as
+/// [`UsdcError::AddressParseError`].
+#[cfg(any(
+    feature = "config",
+    feature = "env-overrides",
+    feature = "remote-circle",
+    feature = "remote-coingecko",
+    feature = "remote-defillama",
+    feature = "server",
+    feature = "tokenlist"
+))]
+pub(crate) fn parse_address(address: &str) -> Result<Address, UsdcError> {
+    use core::str::FromStr;
+
+    Address::from_str(address).map_err(|source| UsdcError::AddressParseError {
+        address_str: address.into(),
+        source,
+    })
 }
 
 /// A trait for types that can provide a USDC contract address.
@@ -79,9 +351,18 @@ pub trait Usdc {
     ///
     /// Returns [`UsdcError::UnsupportedChain`] if the address is not known for the
     /// given context (e.g., an unsupported blockchain).
-    /// Returns [`UsdcError::AddressParseError`] if a known address string is malformed
-    /// and cannot be parsed.
+    /// Implementations backed by address strings may return
+    /// [`UsdcError::AddressParseError`] if such a string is malformed.
     fn usdc_address(&self) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical USDC contract for the implementing
+    /// context.
+    ///
+    /// [`Address`] equality is byte-wise, so the comparison ignores the casing of
+    /// the string the address was parsed from. Unsupported contexts never match.
+    fn is_usdc(&self, address: Address) -> bool {
+        self.usdc_address().is_ok_and(|usdc| usdc == address)
+    }
 }
 
 /// Implementation of the [`Usdc`] trait for the [`alloy_chains::NamedChain`] enum.
@@ -96,9 +377,10 @@ impl Usdc for NamedChain {
     ///
     /// ## Examples
     ///
-    /// ```rust
+    #[cfg_attr(feature = "alt-l1s", doc = "```rust")]
+    #[cfg_attr(not(feature = "alt-l1s"), doc = "```ignore")]
     /// use usdshe::{Usdc, UsdcError};
-    /// use alloy_chains::NamedChain;
+    /// use alloy_chains::{Chain, NamedChain};
     /// use alloy_primitives::Address;
     /// use std::str::FromStr;
     ///
@@ -127,40 +409,63 @@ impl Usdc for NamedChain {
     ///
     /// - [`UsdcError::UnsupportedChain`]: If the USDC address for the specified `NamedChain`
     ///   is not defined in this crate.
-    /// - [`UsdcError::AddressParseError`]: If the predefined address string for a supported
-    ///   chain is malformed (this should be a bug in the crate if it occurs).
     fn usdc_address(&self) -> Result<Address, UsdcError> {
-        use NamedChain::*;
-
-        // Note: The address strings (ARBITRUM_USDC, etc.) are expected to be
-        // valid hexadecimal strings.
-        let address_s = match self {
-            Arbitrum => Ok(ARBITRUM_USDC),
-            ArbitrumSepolia => Ok(ARBITRUM_SEPOLIA_USDC),
-            Avalanche => Ok(AVALANCHE_USDC),
-            Base => Ok(BASE_USDC),
-            BaseSepolia => Ok(BASE_SEPOLIA_USDC),
-            Berachain => Ok(BERACHAIN_USDC),
-            BinanceSmartChain => Ok(BSC_USDC),
-            Fantom => Ok(FANTOM_USDC),
-            Fraxtal => Ok(FRAXTAL_USDC),
-            Sepolia => Ok(ETHEREUM_SEPOLIA_USDC),
-            Linea => Ok(LINEA_USDC),
-            Mainnet => Ok(ETHEREUM_USDC),
-            Mantle => Ok(MANTLE_USDC),
-            Mode => Ok(MODE_USDC),
-            Optimism => Ok(OPTIMISM_USDC),
-            Polygon => Ok(POLYGON_USDC),
-            Scroll => Ok(SCROLL_USDC),
-            Sonic => Ok(SONIC_USDC),
-            Unichain => Ok(UNICHAIN_USDC),
-            ZkSync => Ok(ZKSYNC_USDC),
-            unsupported_chain => Err(UsdcError::UnsupportedChain(*unsupported_chain)),
-        }?;
-
-        Address::from_str(address_s).map_err(|e| UsdcError::AddressParseError {
-            address_str: address_s.to_string(),
-            source: e,
-        })
+        self.token_address(TokenKind::Usdc)
+    }
+}
+
+/// Implementation of the [`Usdc`] trait for [`alloy_chains::Chain`].
+///
+/// Named chains are resolved through the [`NamedChain`] implementation.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{Usdc, UsdcError, ARBITRUM_USDC};
+/// use alloy_chains::Chain;
+///
+/// assert_eq!(Chain::from_id(42161).usdc_address().unwrap(), ARBITRUM_USDC);
+/// assert!(matches!(
+///     Chain::from_id(12_345).usdc_address(),
+///     Err(UsdcError::UnsupportedChainId(12_345))
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain is named but has no known USDC address.
+/// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a [`NamedChain`].
+impl Usdc for Chain {
+    fn usdc_address(&self) -> Result<Address, UsdcError> {
+        match self.named() {
+            Some(named) => named.usdc_address(),
+            None => Err(UsdcError::unsupported(*self)),
+        }
+    }
+}
+
+/// Implementation of the [`Usdc`] trait for raw numeric chain IDs.
+///
+/// The ID is resolved through [`Chain::from_id`], so it behaves exactly like the
+/// [`Chain`] implementation.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "ethereum", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "ethereum", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::{Usdc, UsdcError, BASE_USDC, ETHEREUM_USDC};
+///
+/// assert_eq!(1u64.usdc_address().unwrap(), ETHEREUM_USDC);
+/// assert_eq!(8453u64.usdc_address().unwrap(), BASE_USDC);
+/// assert!(matches!(12_345u64.usdc_address(), Err(UsdcError::UnsupportedChainId(12_345))));
+/// ```
+///
+/// # Errors
+///
+/// See the [`Chain`] implementation.
+impl Usdc for u64 {
+    fn usdc_address(&self) -> Result<Address, UsdcError> {
+        Chain::from_id(*self).usdc_address()
     }
 }
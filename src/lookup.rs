@@ -0,0 +1,375 @@
+use crate::{
+    FiatTokenVersion, SupportedUsdcChain, TokenKind, Tokens, Usdc, UsdcError, UsdcTokenInfo,
+    UsdcVariant,
+};
+use alloc::{string::String, vec::Vec};
+use alloy_chains::NamedChain;
+use alloy_primitives::Address;
+
+/// Returns `true` if `address` is the canonical USDC contract on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::is_usdc;
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::Address;
+/// use std::str::FromStr;
+///
+/// // User-supplied input, all lowercase.
+/// let token = Address::from_str("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").unwrap();
+/// assert!(is_usdc(NamedChain::Mainnet, token));
+/// assert!(!is_usdc(NamedChain::Base, token));
+/// assert!(!is_usdc(NamedChain::Gnosis, token));
+/// ```
+pub fn is_usdc(chain: NamedChain, address: Address) -> bool {
+    chain.is_usdc(address)
+}
+
+/// Returns every chain on which `address` is the canonical USDC contract.
+///
+/// Most addresses map to a single chain, but the result is a list because nothing
+/// prevents the same address from being deployed on several chains. Addresses that
+/// are not USDC anywhere yield an empty list.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{chains_for_address, BASE_USDC};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::Address;
+///
+/// assert_eq!(chains_for_address(BASE_USDC), vec![NamedChain::Base]);
+/// assert!(chains_for_address(Address::ZERO).is_empty());
+/// ```
+pub fn chains_for_address(address: Address) -> Vec<NamedChain> {
+    all()
+        .filter(|(_, usdc)| *usdc == address)
+        .map(|(chain, _)| chain)
+        .collect()
+}
+
+/// Returns an iterator over every supported `(chain, USDC address)` pair.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{all, ETHEREUM_USDC};
+/// use alloy_chains::NamedChain;
+///
+/// assert!(all().any(|entry| entry == (NamedChain::Mainnet, ETHEREUM_USDC)));
+/// ```
+pub fn all() -> impl Iterator<Item = (NamedChain, Address)> {
+    SupportedUsdcChain::ALL
+        .iter()
+        .map(|chain| (chain.named_chain(), chain.usdc_address()))
+}
+
+/// Returns every [`NamedChain`] with a known USDC address.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::supported_chains;
+/// use alloy_chains::NamedChain;
+///
+/// let chains = supported_chains();
+/// assert!(chains.contains(&NamedChain::Base));
+/// assert!(!chains.contains(&NamedChain::Gnosis));
+/// ```
+pub fn supported_chains() -> Vec<NamedChain> {
+    SupportedUsdcChain::ALL
+        .iter()
+        .map(|chain| chain.named_chain())
+        .collect()
+}
+
+/// Returns `true` if this crate knows the USDC address for `chain`.
+///
+/// Equivalent to `chain.usdc_address().is_ok()`, without building an error.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::is_supported;
+/// use alloy_chains::NamedChain;
+///
+/// assert!(is_supported(NamedChain::Arbitrum));
+/// assert!(!is_supported(NamedChain::Gnosis));
+/// ```
+pub fn is_supported(chain: NamedChain) -> bool {
+    SupportedUsdcChain::try_from(chain).is_ok()
+}
+
+/// Returns the USDC address for a chain given by name.
+///
+/// Matching is case-insensitive and accepts common aliases; see
+/// [`SupportedUsdcChain::from_str`](SupportedUsdcChain#impl-FromStr-for-SupportedUsdcChain)
+/// for details.
+///
+/// ## Examples
+///
+#[cfg_attr(
+    all(feature = "alt-l1s", feature = "ethereum", feature = "l2s"),
+    doc = "```rust"
+)]
+#[cfg_attr(
+    not(all(feature = "alt-l1s", feature = "ethereum", feature = "l2s")),
+    doc = "```ignore"
+)]
+/// use usdshe::{usdc_address_by_name, UsdcError, ARBITRUM_USDC, ETHEREUM_USDC, POLYGON_USDC};
+///
+/// assert_eq!(usdc_address_by_name("Ethereum").unwrap(), ETHEREUM_USDC);
+/// assert_eq!(usdc_address_by_name("arb").unwrap(), ARBITRUM_USDC);
+/// assert_eq!(usdc_address_by_name("MATIC").unwrap(), POLYGON_USDC);
+/// assert!(matches!(
+///     usdc_address_by_name("not-a-chain"),
+///     Err(UsdcError::UnknownChainName(_))
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the name is a known chain without a USDC address.
+/// - [`UsdcError::UnknownChainName`]: If the name is not recognized.
+pub fn usdc_address_by_name(name: &str) -> Result<Address, UsdcError> {
+    name.parse::<SupportedUsdcChain>()
+        .map(|chain| chain.usdc_address())
+}
+
+/// Returns whether the USDC token listed for `chain` is Circle-native or bridged.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "alt-l1s", feature = "ethereum"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "alt-l1s", feature = "ethereum")), doc = "```ignore")]
+/// use usdshe::{usdc_variant, UsdcVariant};
+/// use alloy_chains::NamedChain;
+///
+/// assert!(usdc_variant(NamedChain::Mainnet).unwrap().is_native());
+/// assert_eq!(usdc_variant(NamedChain::Fantom).unwrap(), UsdcVariant::MultichainBridged);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_variant(chain: NamedChain) -> Result<UsdcVariant, UsdcError> {
+    SupportedUsdcChain::try_from(chain).map(|chain| chain.usdc_variant())
+}
+
+/// Returns the legacy bridged USDC.e address for `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{usdc_e_address, UsdcError, OPTIMISM_USDC_E};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(usdc_e_address(NamedChain::Optimism).unwrap(), OPTIMISM_USDC_E);
+/// assert!(matches!(
+///     usdc_e_address(NamedChain::Base),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Base))
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If no USDC.e deployment is known for the chain.
+pub fn usdc_e_address(chain: NamedChain) -> Result<Address, UsdcError> {
+    SupportedUsdcChain::try_from(chain)
+        .ok()
+        .and_then(|supported| supported.usdc_e_address())
+        .ok_or(UsdcError::UnsupportedChain(chain))
+}
+
+/// Returns the number of decimals used by the USDC token on `chain`.
+///
+/// Amounts must be scaled with this value rather than a hardcoded 6, since some
+/// bridged deployments differ.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "alt-l1s", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "alt-l1s", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::usdc_decimals;
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(usdc_decimals(NamedChain::Base).unwrap(), 6);
+/// assert_eq!(usdc_decimals(NamedChain::BinanceSmartChain).unwrap(), 18);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_decimals(chain: NamedChain) -> Result<u8, UsdcError> {
+    SupportedUsdcChain::try_from(chain).map(|chain| chain.usdc_decimals())
+}
+
+/// Returns the token metadata for USDC on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "alt-l1s", doc = "```rust")]
+#[cfg_attr(not(feature = "alt-l1s"), doc = "```ignore")]
+/// use usdshe::usdc_token_info;
+/// use alloy_chains::NamedChain;
+///
+/// let info = usdc_token_info(NamedChain::BinanceSmartChain).unwrap();
+/// assert_eq!(info.decimals, 18);
+/// assert!(!info.variant.is_native());
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_token_info(chain: NamedChain) -> Result<UsdcTokenInfo, UsdcError> {
+    SupportedUsdcChain::try_from(chain).map(|chain| chain.usdc_token_info())
+}
+
+/// Returns the recorded implementation contract behind the USDC proxy on
+/// `chain`, or `None` if it is not recorded.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::usdc_implementation;
+/// use alloy_chains::NamedChain;
+///
+/// assert!(usdc_implementation(NamedChain::Mainnet).unwrap().is_some());
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_implementation(chain: NamedChain) -> Result<Option<Address>, UsdcError> {
+    SupportedUsdcChain::try_from(chain).map(|chain| chain.usdc_implementation())
+}
+
+/// Returns the FiatToken release the USDC contract on `chain` runs, or `None`
+/// if it is not recorded. `None` covers both tokens that are not FiatToken
+/// contracts and FiatToken deployments whose release is not confirmed; tell
+/// them apart with
+/// [`SupportedUsdcChain::is_fiat_token`](crate::SupportedUsdcChain::is_fiat_token).
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{usdc_fiat_token_version, FiatTokenVersion};
+/// use alloy_chains::NamedChain;
+///
+/// let version = usdc_fiat_token_version(NamedChain::Arbitrum).unwrap();
+/// assert_eq!(version, Some(FiatTokenVersion::V2_2));
+/// assert!(version.is_some_and(|version| version.supports_contract_signatures()));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_fiat_token_version(chain: NamedChain) -> Result<Option<FiatTokenVersion>, UsdcError> {
+    SupportedUsdcChain::try_from(chain).map(|chain| chain.fiat_token_version())
+}
+
+/// Returns the USDC address on `chain` as an EIP-55 mixed-case checksummed
+/// string, for validators that reject all-lowercase or all-uppercase hex.
+///
+/// `Address`'s `Display` already checksums; this spells out the guarantee for
+/// callers that only need the string.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "alt-l1s", doc = "```rust")]
+#[cfg_attr(not(feature = "alt-l1s"), doc = "```ignore")]
+/// use usdshe::usdc_address_checksummed;
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(
+///     usdc_address_checksummed(NamedChain::Avalanche).unwrap(),
+///     "0xB97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_address_checksummed(chain: NamedChain) -> Result<String, UsdcError> {
+    chain
+        .usdc_address()
+        .map(|address| address.to_checksum(None))
+}
+
+/// Returns the USDC token page on the canonical block explorer of `chain`, with
+/// the checksummed address interpolated.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::usdc_explorer_url;
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(
+///     usdc_explorer_url(NamedChain::Base).unwrap(),
+///     "https://basescan.org/token/0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_explorer_url(chain: NamedChain) -> Result<String, UsdcError> {
+    SupportedUsdcChain::try_from(chain).map(|chain| chain.usdc_explorer_url())
+}
+
+/// Returns the CAIP-19 asset identifier for USDC on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::usdc_caip19;
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(
+///     usdc_caip19(NamedChain::Mainnet).unwrap(),
+///     "eip155:1/erc20:0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn usdc_caip19(chain: NamedChain) -> Result<String, UsdcError> {
+    SupportedUsdcChain::try_from(chain).map(|chain| chain.usdc_caip19())
+}
+
+/// Returns the address of `kind` on `chain`, the single entry point behind every
+/// per-token lookup.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{token_address, TokenKind, ETHEREUM_USDT, ETHEREUM_WETH};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(token_address(NamedChain::Mainnet, TokenKind::Usdt).unwrap(), ETHEREUM_USDT);
+/// assert_eq!(
+///     token_address(NamedChain::Mainnet, TokenKind::WrappedNative).unwrap(),
+///     ETHEREUM_WETH
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If `kind` has no known address on the chain.
+pub fn token_address(chain: NamedChain, kind: TokenKind) -> Result<Address, UsdcError> {
+    chain.token_address(kind)
+}
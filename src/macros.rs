@@ -0,0 +1,31 @@
+/// Expands to the USDC [`Address`](alloy_primitives::Address) for a chain, evaluated
+/// at compile time.
+///
+/// The argument is a [`SupportedUsdcChain`](crate::SupportedUsdcChain) variant
+/// name, so unsupported chains and typos fail the build instead of returning an
+/// error at runtime. The expansion is a constant expression and can initialize
+/// `const` items.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "ethereum", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "ethereum", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::{usdc, BASE_USDC, ETHEREUM_USDC};
+/// use alloy_primitives::Address;
+///
+/// const MAINNET: Address = usdc!(Mainnet);
+/// assert_eq!(MAINNET, ETHEREUM_USDC);
+/// assert_eq!(usdc!(Base), BASE_USDC);
+/// ```
+///
+/// Chains without a known address do not compile:
+///
+/// ```compile_fail
+/// let address = usdshe::usdc!(Gnosis);
+/// ```
+#[macro_export]
+macro_rules! usdc {
+    ($chain:ident) => {
+        const { $crate::SupportedUsdcChain::$chain.usdc_address() }
+    };
+}
@@ -0,0 +1,108 @@
+use crate::{EthProvider, OnchainError, UsdcContract, UsdcRegistry};
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, Bytes};
+use core::future::Future;
+
+/// Returns the Solidity source of `MockFiatToken`, a 6-decimal USDC stand-in
+/// for local test nodes that anyone can mint, blacklist and pause.
+///
+/// The crate does not bundle compiled bytecode; build the contract with your
+/// own toolchain, e.g. `forge build`, and pass its creation code to
+/// [`deploy_mock_usdc`].
+///
+/// ## Examples
+///
+/// ```rust
+/// let source = usdshe::mock_fiat_token_source();
+/// assert!(source.contains("contract MockFiatToken"));
+/// assert!(source.contains("uint8 public constant decimals = 6;"));
+/// ```
+pub fn mock_fiat_token_source() -> &'static str {
+    include_str!("../../abi/MockFiatToken.sol")
+}
+
+/// A node that can deploy contracts, such as Anvil with its unlocked dev
+/// accounts.
+pub trait ContractDeployer: EthProvider {
+    /// Sends a contract-creation transaction with `init_code` from a funded
+    /// account, waits until it is mined, and returns the new contract's
+    /// address.
+    fn deploy(&self, init_code: Bytes)
+        -> impl Future<Output = Result<Address, Self::Error>> + Send;
+}
+
+impl<D: ContractDeployer + ?Sized> ContractDeployer for &D {
+    fn deploy(
+        &self,
+        init_code: Bytes,
+    ) -> impl Future<Output = Result<Address, Self::Error>> + Send {
+        (**self).deploy(init_code)
+    }
+}
+
+/// Deploys a mock USDC from `init_code` through `node`, registers it for
+/// `chain` in `registry`, and returns the contract bound to `node`.
+///
+/// `init_code` is typically the compiled [`mock_fiat_token_source`]. Code
+/// that resolves USDC through the registry then finds the mock, so
+/// integration tests get a working token without forking mainnet.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{deploy_mock_usdc, ContractDeployer, EthProvider, UsdcRegistry};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Anvil;
+/// # impl EthProvider for Anvil {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(31337) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(B256::with_last_byte(6).into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # impl ContractDeployer for Anvil {
+/// #     async fn deploy(&self, _: Bytes) -> Result<Address, Self::Error> {
+/// #         Ok(address!("0x5FbDB2315678afecb367f032d93F642f64180aa3"))
+/// #     }
+/// # }
+/// # let init_code = Bytes::new();
+///
+/// // `Anvil` implements `ContractDeployer` over a local node's RPC endpoint,
+/// // and `init_code` is the compiled `MockFiatToken`.
+/// let mut registry = UsdcRegistry::new();
+/// let usdc = block_on(deploy_mock_usdc(Anvil, &mut registry, NamedChain::AnvilHardhat, init_code))
+///     .unwrap();
+///
+/// assert_eq!(registry.usdc_address(NamedChain::AnvilHardhat).unwrap(), usdc.address());
+/// assert_eq!(block_on(usdc.decimals()).unwrap(), 6);
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Provider`]: If the deployment fails.
+pub async fn deploy_mock_usdc<D: ContractDeployer>(
+    node: D,
+    registry: &mut UsdcRegistry,
+    chain: NamedChain,
+    init_code: Bytes,
+) -> Result<UsdcContract<D>, OnchainError> {
+    let address = node
+        .deploy(init_code)
+        .await
+        .map_err(OnchainError::provider)?;
+    registry.insert(chain, address);
+    Ok(UsdcContract::new(node, chain, address))
+}
@@ -0,0 +1,164 @@
+//! Test doubles for code that looks up USDC addresses, and a mock USDC
+//! deployment for local nodes.
+
+#[cfg(feature = "onchain")]
+mod anvil;
+
+#[cfg(feature = "onchain")]
+pub use anvil::{deploy_mock_usdc, mock_fiat_token_source, ContractDeployer};
+
+use crate::{AsyncUsdc, Usdc, UsdcError, UsdcResolver};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+use core::{fmt, future::Future};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+type ErrorFactory = Arc<dyn Fn() -> UsdcError + Send + Sync>;
+
+#[derive(Clone)]
+enum Response {
+    Address(Address),
+    Error(ErrorFactory),
+}
+
+/// A programmable USDC resolver for unit tests, so chain-handling logic can be
+/// exercised without depending on the built-in table.
+///
+/// Each chain answers with a fixed address or a forced error; every other
+/// chain is unsupported. Lookups are counted per chain, to assert on caching
+/// and retry behaviour; clones share their counts.
+///
+/// `MockUsdc` implements [`UsdcResolver`] and [`AsyncUsdc`], and
+/// [`MockUsdc::chain`] binds it to one chain as a [`Usdc`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{MockUsdc, Usdc, UsdcError, UsdcResolver};
+/// use alloy_chains::{Chain, NamedChain};
+/// use alloy_primitives::address;
+///
+/// let token = address!("0x1111111111111111111111111111111111111111");
+/// let mock = MockUsdc::new()
+///     .with_address(NamedChain::Base, token)
+///     .with_error(NamedChain::Arbitrum, || UsdcError::UnsupportedChainId(42_161));
+///
+/// assert_eq!(mock.chain(NamedChain::Base).usdc_address().unwrap(), token);
+/// assert!(mock.usdc_address(NamedChain::Arbitrum.into()).is_err());
+/// assert!(mock.usdc_address(Chain::from_id(12_345)).is_err());
+///
+/// assert_eq!(mock.calls(NamedChain::Base), 1);
+/// assert_eq!(mock.total_calls(), 3);
+/// ```
+#[derive(Clone, Default)]
+pub struct MockUsdc {
+    responses: HashMap<u64, Response>,
+    calls: Arc<Mutex<HashMap<u64, usize>>>,
+}
+
+impl MockUsdc {
+    /// Creates a mock that knows no chains.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers lookups for `chain` with `address`.
+    pub fn with_address(mut self, chain: impl Into<Chain>, address: Address) -> Self {
+        self.responses
+            .insert(chain.into().id(), Response::Address(address));
+        self
+    }
+
+    /// Fails lookups for `chain` with the error `error` returns.
+    pub fn with_error(
+        mut self,
+        chain: impl Into<Chain>,
+        error: impl Fn() -> UsdcError + Send + Sync + 'static,
+    ) -> Self {
+        self.responses
+            .insert(chain.into().id(), Response::Error(Arc::new(error)));
+        self
+    }
+
+    /// Returns the number of lookups made for `chain`.
+    pub fn calls(&self, chain: impl Into<Chain>) -> usize {
+        let calls = self.calls.lock().unwrap_or_else(|err| err.into_inner());
+        calls.get(&chain.into().id()).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of lookups made for any chain.
+    pub fn total_calls(&self) -> usize {
+        let calls = self.calls.lock().unwrap_or_else(|err| err.into_inner());
+        calls.values().sum()
+    }
+
+    /// Resets every call count to zero.
+    pub fn reset_calls(&self) {
+        self.calls
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+
+    /// Returns a view of this mock bound to `chain`, which implements [`Usdc`].
+    pub fn chain(&self, chain: impl Into<Chain>) -> MockChain<'_> {
+        MockChain {
+            mock: self,
+            chain: chain.into(),
+        }
+    }
+
+    fn lookup(&self, chain: Chain) -> Result<Address, UsdcError> {
+        *self
+            .calls
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .entry(chain.id())
+            .or_default() += 1;
+        match self.responses.get(&chain.id()) {
+            Some(Response::Address(address)) => Ok(*address),
+            Some(Response::Error(error)) => Err(error()),
+            None => Err(UsdcError::unsupported(chain)),
+        }
+    }
+}
+
+impl fmt::Debug for MockUsdc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockUsdc")
+            .field("chains", &self.responses.keys().collect::<Vec<_>>())
+            .field("total_calls", &self.total_calls())
+            .finish()
+    }
+}
+
+impl UsdcResolver for MockUsdc {
+    fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        self.lookup(chain)
+    }
+}
+
+impl AsyncUsdc for MockUsdc {
+    fn usdc_address(
+        &self,
+        chain: Chain,
+    ) -> impl Future<Output = Result<Address, UsdcError>> + Send {
+        core::future::ready(self.lookup(chain))
+    }
+}
+
+/// A [`MockUsdc`] bound to a single chain, returned by [`MockUsdc::chain`].
+#[derive(Clone, Copy, Debug)]
+pub struct MockChain<'a> {
+    mock: &'a MockUsdc,
+    chain: Chain,
+}
+
+impl Usdc for MockChain<'_> {
+    fn usdc_address(&self) -> Result<Address, UsdcError> {
+        self.mock.lookup(self.chain)
+    }
+}
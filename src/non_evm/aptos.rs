@@ -0,0 +1,66 @@
+use super::{parse_move_address, MultiChainAddressError};
+use alloy_primitives::{b256, B256};
+use core::{fmt, str::FromStr};
+
+/// The metadata object of native USDC on Aptos, which identifies it as a
+/// fungible asset (FA). Circle issues USDC on Aptos as an FA only, with no
+/// legacy `Coin` type.
+pub const APTOS_USDC: AptosAddress = AptosAddress::new(b256!(
+    "0xbae207659db88bea0cbead6da0ed00aac12edcdda169e591cd41c94180b46f3b"
+));
+
+/// A 32-byte Aptos account or object address, written as `0x` and 64 hex
+/// digits.
+///
+/// Fungible assets on Aptos are identified by the address of their metadata
+/// object, e.g. [`APTOS_USDC`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{AptosAddress, APTOS_USDC};
+///
+/// let usdc: AptosAddress = "0xbae207659db88bea0cbead6da0ed00aac12edcdda169e591cd41c94180b46f3b"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(usdc, APTOS_USDC);
+///
+/// // Short forms are zero-padded, as in `0x1` for the framework account.
+/// let framework: AptosAddress = "0x1".parse().unwrap();
+/// assert_eq!(framework.as_word().0[31], 1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AptosAddress(B256);
+
+impl AptosAddress {
+    /// Wraps a 32-byte address.
+    pub const fn new(word: B256) -> Self {
+        Self(word)
+    }
+
+    /// Returns the address as a 32-byte word.
+    pub const fn as_word(&self) -> B256 {
+        self.0
+    }
+}
+
+/// Always the long form, as recommended by AIP-40.
+impl fmt::Display for AptosAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for AptosAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AptosAddress({self})")
+    }
+}
+
+impl FromStr for AptosAddress {
+    type Err = MultiChainAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_move_address(s).map(Self)
+    }
+}
@@ -0,0 +1,55 @@
+//! Base58 with the Bitcoin alphabet, as used by Solana, Tron and others.
+
+use alloc::{string::String, vec::Vec};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes `bytes`, keeping each leading zero byte as a leading `1`.
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|b| **b == 0).count();
+    // Little-endian base-58 digits of the remaining bytes.
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for byte in &bytes[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in &mut digits {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let mut encoded = String::with_capacity(zeros + digits.len());
+    encoded.extend(core::iter::repeat_n('1', zeros));
+    encoded.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|d| ALPHABET[usize::from(*d)] as char),
+    );
+    encoded
+}
+
+/// Decodes `input`, returning `None` on characters outside the alphabet.
+pub(crate) fn decode(input: &str) -> Option<Vec<u8>> {
+    let zeros = input.bytes().take_while(|c| *c == b'1').count();
+    // Little-endian base-256 bytes of the remaining digits.
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for c in input.bytes().skip(zeros) {
+        let mut carry = ALPHABET.iter().position(|a| *a == c)? as u32;
+        for byte in &mut bytes {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.resize(bytes.len() + zeros, 0);
+    bytes.reverse();
+    Some(bytes)
+}
@@ -0,0 +1,133 @@
+use alloc::{borrow::Cow, format, string::String};
+use alloy_primitives::hex;
+use core::fmt;
+use sha2::{Digest, Sha256};
+
+/// A Cosmos SDK bank denomination, such as Noble's `uusdc` or an
+/// `ibc/<hash>` voucher for it on another chain.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CosmosDenom(Cow<'static, str>);
+
+impl CosmosDenom {
+    /// Native USDC on Noble, where Circle issues it for the Cosmos ecosystem.
+    /// Amounts are in micro-USDC (6 decimals).
+    pub const NOBLE_USDC: Self = Self::from_static("uusdc");
+
+    /// Wraps a denomination known at compile time.
+    pub const fn from_static(denom: &'static str) -> Self {
+        Self(Cow::Borrowed(denom))
+    }
+
+    /// Wraps a denomination.
+    pub fn new(denom: impl Into<String>) -> Self {
+        Self(Cow::Owned(denom.into()))
+    }
+
+    /// Returns the denomination string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the IBC voucher denomination of this token after it is
+    /// transferred over `channel` (the channel ID on the receiving chain),
+    /// `ibc/` followed by the uppercase SHA-256 of `transfer/<channel>/<denom>`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use usdshe::CosmosDenom;
+    ///
+    /// assert_eq!(
+    ///     CosmosDenom::NOBLE_USDC.ibc_denom("channel-750").as_str(),
+    ///     "ibc/498A0751C798A0D9A389AA3691123DADA57DAA4FE165D5C75894505B876BA6E4"
+    /// );
+    /// ```
+    pub fn ibc_denom(&self, channel: &str) -> Self {
+        let hash = Sha256::digest(format!("transfer/{channel}/{}", self.0));
+        Self::new(format!("ibc/{}", hex::encode_upper(hash)))
+    }
+}
+
+impl fmt::Display for CosmosDenom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for CosmosDenom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CosmosDenom({})", self.0)
+    }
+}
+
+/// A Cosmos chain with a direct IBC channel to Noble for USDC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum CosmosChain {
+    /// The Cosmos Hub.
+    CosmosHub,
+    /// The dYdX chain.
+    Dydx,
+    /// Injective.
+    Injective,
+    /// Neutron.
+    Neutron,
+    /// Osmosis.
+    Osmosis,
+}
+
+impl CosmosChain {
+    /// Every chain with a recorded Noble channel.
+    pub const ALL: &'static [Self] = &[
+        Self::CosmosHub,
+        Self::Dydx,
+        Self::Injective,
+        Self::Neutron,
+        Self::Osmosis,
+    ];
+
+    /// Returns the ID of the channel on this chain that receives USDC from
+    /// Noble.
+    pub const fn noble_channel(&self) -> &'static str {
+        match self {
+            Self::CosmosHub => "channel-536",
+            Self::Dydx => "channel-0",
+            Self::Injective => "channel-148",
+            Self::Neutron => "channel-30",
+            Self::Osmosis => "channel-750",
+        }
+    }
+
+    /// Returns the denomination of Noble USDC on this chain.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use usdshe::CosmosChain;
+    ///
+    /// assert_eq!(
+    ///     CosmosChain::Neutron.usdc_denom().as_str(),
+    ///     "ibc/B559A80D62249C8AA07A380E2A2BEA6E5CA9A6F079C912C3A9E9B494105E4F81"
+    /// );
+    /// ```
+    pub fn usdc_denom(&self) -> CosmosDenom {
+        CosmosDenom::NOBLE_USDC.ibc_denom(self.noble_channel())
+    }
+}
+
+impl fmt::Display for CosmosChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::CosmosHub => "cosmoshub",
+            Self::Dydx => "dydx",
+            Self::Injective => "injective",
+            Self::Neutron => "neutron",
+            Self::Osmosis => "osmosis",
+        })
+    }
+}
@@ -0,0 +1,326 @@
+//! USDC deployments on chains outside the EVM.
+//!
+//! Circle issues USDC natively on several non-EVM chains, whose token
+//! identifiers are not 20-byte addresses. [`MultiChainAddress`] holds either
+//! kind, each in its own variant so that, e.g., a Solana mint can never be
+//! mistaken for an EVM contract.
+
+mod aptos;
+mod base58;
+mod cosmos;
+mod near;
+mod solana;
+mod stellar;
+mod sui;
+mod tron;
+
+pub use aptos::{AptosAddress, APTOS_USDC};
+pub use cosmos::{CosmosChain, CosmosDenom};
+pub use near::NearAccountId;
+pub use solana::SolanaAddress;
+pub use stellar::{StellarAccountId, StellarAsset, STELLAR_USDC_ISSUER};
+pub use sui::SuiCoinType;
+pub use tron::{TronAddress, TRON_USDC};
+
+use crate::ChainFamily;
+use alloy_primitives::{hex, Address, B256};
+use core::fmt;
+use thiserror::Error;
+
+/// The USDC mint on Solana, issued natively by Circle.
+pub const SOLANA_USDC: SolanaAddress = SolanaAddress::new(hex!(
+    "c6fa7af3bedbad3a3d65f36aabc97431b1bbe4c2d2f6e0e47ca60203452f5d61"
+));
+
+/// A non-EVM chain with a native USDC deployment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum NonEvmChain {
+    /// Aptos mainnet.
+    Aptos,
+    /// NEAR mainnet.
+    Near,
+    /// Noble, the Cosmos chain Circle issues USDC on. Other Cosmos chains
+    /// hold it as an IBC voucher; see [`CosmosChain`].
+    Noble,
+    /// Solana mainnet-beta.
+    Solana,
+    /// The Stellar public network.
+    Stellar,
+    /// Sui mainnet.
+    Sui,
+    /// Tron mainnet. Circle stopped minting USDC on Tron in 2024; the token
+    /// remains in circulation.
+    Tron,
+}
+
+impl NonEvmChain {
+    /// Every non-EVM chain with a recorded USDC deployment.
+    pub const ALL: &'static [Self] = &[
+        Self::Aptos,
+        Self::Near,
+        Self::Noble,
+        Self::Solana,
+        Self::Stellar,
+        Self::Sui,
+        Self::Tron,
+    ];
+
+    /// Returns the family the chain belongs to.
+    pub const fn family(&self) -> ChainFamily {
+        match self {
+            Self::Aptos | Self::Sui => ChainFamily::Move,
+            Self::Near => ChainFamily::Near,
+            Self::Noble => ChainFamily::Cosmos,
+            Self::Solana => ChainFamily::Solana,
+            Self::Stellar => ChainFamily::Stellar,
+            Self::Tron => ChainFamily::Tron,
+        }
+    }
+
+    /// Returns the number of decimals of USDC on this chain: 7 on Stellar,
+    /// whose assets all share that precision, and 6 everywhere else.
+    pub const fn usdc_decimals(&self) -> u8 {
+        match self {
+            Self::Stellar => 7,
+            _ => 6,
+        }
+    }
+
+    /// Returns the native USDC identifier on this chain.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use usdshe::{MultiChainAddress, NonEvmChain, SOLANA_USDC};
+    ///
+    /// assert_eq!(NonEvmChain::Solana.usdc(), MultiChainAddress::Solana(SOLANA_USDC));
+    /// ```
+    pub const fn usdc(&self) -> MultiChainAddress {
+        match self {
+            Self::Aptos => MultiChainAddress::Aptos(APTOS_USDC),
+            Self::Near => MultiChainAddress::Near(NearAccountId::USDC),
+            Self::Noble => MultiChainAddress::Cosmos(CosmosDenom::NOBLE_USDC),
+            Self::Solana => MultiChainAddress::Solana(SOLANA_USDC),
+            Self::Stellar => MultiChainAddress::Stellar(StellarAsset::USDC),
+            Self::Sui => MultiChainAddress::Sui(SuiCoinType::USDC),
+            Self::Tron => MultiChainAddress::Tron(TRON_USDC),
+        }
+    }
+}
+
+impl fmt::Display for NonEvmChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Aptos => "aptos",
+            Self::Near => "near",
+            Self::Noble => "noble",
+            Self::Solana => "solana",
+            Self::Stellar => "stellar",
+            Self::Sui => "sui",
+            Self::Tron => "tron",
+        })
+    }
+}
+
+/// A token or account identifier on any chain USDC is deployed to.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{MultiChainAddress, NonEvmChain, ETHEREUM_USDC};
+///
+/// let treasury = [MultiChainAddress::from(ETHEREUM_USDC), NonEvmChain::Solana.usdc()];
+/// assert_eq!(treasury[0].as_evm(), Some(ETHEREUM_USDC));
+/// assert_eq!(treasury[1].to_string(), "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MultiChainAddress {
+    /// A 20-byte EVM address.
+    Evm(Address),
+    /// A Solana public key.
+    Solana(SolanaAddress),
+    /// A Cosmos SDK bank denomination.
+    Cosmos(CosmosDenom),
+    /// A Sui coin type.
+    Sui(SuiCoinType),
+    /// An Aptos account or object address.
+    Aptos(AptosAddress),
+    /// A Stellar asset: code and issuer.
+    Stellar(StellarAsset),
+    /// A Tron address.
+    Tron(TronAddress),
+    /// A NEAR account ID.
+    Near(NearAccountId),
+}
+
+impl MultiChainAddress {
+    /// Returns the EVM address, or `None` for other kinds.
+    pub const fn as_evm(&self) -> Option<Address> {
+        match self {
+            Self::Evm(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    /// Returns the Cosmos denomination, or `None` for other kinds.
+    pub const fn as_cosmos(&self) -> Option<&CosmosDenom> {
+        match self {
+            Self::Cosmos(denom) => Some(denom),
+            _ => None,
+        }
+    }
+
+    /// Returns the Aptos address, or `None` for other kinds.
+    pub const fn as_aptos(&self) -> Option<AptosAddress> {
+        match self {
+            Self::Aptos(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    /// Returns the Stellar asset, or `None` for other kinds.
+    pub const fn as_stellar(&self) -> Option<&StellarAsset> {
+        match self {
+            Self::Stellar(asset) => Some(asset),
+            _ => None,
+        }
+    }
+
+    /// Returns the Sui coin type, or `None` for other kinds.
+    pub const fn as_sui(&self) -> Option<&SuiCoinType> {
+        match self {
+            Self::Sui(coin_type) => Some(coin_type),
+            _ => None,
+        }
+    }
+
+    /// Returns the Tron address, or `None` for other kinds.
+    pub const fn as_tron(&self) -> Option<TronAddress> {
+        match self {
+            Self::Tron(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    /// Returns the NEAR account ID, or `None` for other kinds.
+    pub const fn as_near(&self) -> Option<&NearAccountId> {
+        match self {
+            Self::Near(account_id) => Some(account_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the Solana address, or `None` for other kinds.
+    pub const fn as_solana(&self) -> Option<SolanaAddress> {
+        match self {
+            Self::Solana(address) => Some(*address),
+            _ => None,
+        }
+    }
+}
+
+impl From<Address> for MultiChainAddress {
+    fn from(address: Address) -> Self {
+        Self::Evm(address)
+    }
+}
+
+impl From<CosmosDenom> for MultiChainAddress {
+    fn from(denom: CosmosDenom) -> Self {
+        Self::Cosmos(denom)
+    }
+}
+
+impl From<AptosAddress> for MultiChainAddress {
+    fn from(address: AptosAddress) -> Self {
+        Self::Aptos(address)
+    }
+}
+
+impl From<StellarAsset> for MultiChainAddress {
+    fn from(asset: StellarAsset) -> Self {
+        Self::Stellar(asset)
+    }
+}
+
+impl From<SuiCoinType> for MultiChainAddress {
+    fn from(coin_type: SuiCoinType) -> Self {
+        Self::Sui(coin_type)
+    }
+}
+
+impl From<TronAddress> for MultiChainAddress {
+    fn from(address: TronAddress) -> Self {
+        Self::Tron(address)
+    }
+}
+
+impl From<NearAccountId> for MultiChainAddress {
+    fn from(account_id: NearAccountId) -> Self {
+        Self::Near(account_id)
+    }
+}
+
+impl From<SolanaAddress> for MultiChainAddress {
+    fn from(address: SolanaAddress) -> Self {
+        Self::Solana(address)
+    }
+}
+
+/// EVM addresses are written checksummed; other kinds in their chain's
+/// native encoding.
+impl fmt::Display for MultiChainAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Evm(address) => write!(f, "{}", address.to_checksum(None)),
+            Self::Solana(address) => address.fmt(f),
+            Self::Cosmos(denom) => denom.fmt(f),
+            Self::Sui(coin_type) => coin_type.fmt(f),
+            Self::Aptos(address) => address.fmt(f),
+            Self::Stellar(asset) => asset.fmt(f),
+            Self::Tron(address) => address.fmt(f),
+            Self::Near(account_id) => account_id.fmt(f),
+        }
+    }
+}
+
+/// Represents errors that can occur when parsing a non-EVM address.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MultiChainAddressError {
+    /// The input is not in the chain's address encoding.
+    #[error("Invalid address encoding")]
+    InvalidEncoding,
+
+    /// The input's checksum does not match, e.g. because of a typo.
+    #[error("Invalid address checksum")]
+    InvalidChecksum,
+
+    /// The input decodes to the wrong number of bytes.
+    #[error("Invalid address length: expected {expected} bytes, got {actual}")]
+    InvalidLength {
+        /// The number of bytes an address has.
+        expected: usize,
+        /// The number of bytes decoded.
+        actual: usize,
+    },
+}
+
+/// Parses a `0x`-prefixed Move account address, which may omit leading zeros.
+pub(crate) fn parse_move_address(s: &str) -> Result<B256, MultiChainAddressError> {
+    let digits = s
+        .strip_prefix("0x")
+        .filter(|digits| !digits.is_empty() && digits.len() <= 64)
+        .ok_or(MultiChainAddressError::InvalidEncoding)?;
+    let mut padded = [b'0'; 64];
+    padded[64 - digits.len()..].copy_from_slice(digits.as_bytes());
+    let mut word = [0; 32];
+    hex::decode_to_slice(padded, &mut word).map_err(|_| MultiChainAddressError::InvalidEncoding)?;
+    Ok(B256::from(word))
+}
@@ -0,0 +1,93 @@
+use super::MultiChainAddressError;
+use alloc::{borrow::Cow, string::String};
+use core::{fmt, str::FromStr};
+
+/// A NEAR account ID, e.g. `alice.near`, or a 64-hex-digit implicit account
+/// derived from a public key.
+///
+/// Account IDs are 2 to 64 characters of lowercase letters, digits and the
+/// separators `-`, `_` and `.`, with no separator at either end or next to
+/// another.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::NearAccountId;
+///
+/// let usdc: NearAccountId = "17208628f84f5d6ad33f0da3bbbeb27ffcb398eac501a31bd6ad2011e36133a1"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(usdc, NearAccountId::USDC);
+/// assert!(usdc.is_implicit());
+///
+/// let named: NearAccountId = "usdc.wallet.near".parse().unwrap();
+/// assert!(!named.is_implicit());
+///
+/// assert!("Alice.near".parse::<NearAccountId>().is_err());
+/// assert!("alice..near".parse::<NearAccountId>().is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NearAccountId(Cow<'static, str>);
+
+impl NearAccountId {
+    /// The contract of native USDC on NEAR, issued by Circle.
+    ///
+    /// It is an implicit account; the bridged `USDC.e` from Ethereum lives at
+    /// `a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48.factory.bridge.near` instead.
+    pub const USDC: Self =
+        Self::from_static("17208628f84f5d6ad33f0da3bbbeb27ffcb398eac501a31bd6ad2011e36133a1");
+
+    /// Wraps an account ID known at compile time, without validating it.
+    pub const fn from_static(account_id: &'static str) -> Self {
+        Self(Cow::Borrowed(account_id))
+    }
+
+    /// Returns the account ID.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` for an implicit account: 64 lowercase hex digits, the
+    /// hex encoding of an Ed25519 public key.
+    pub fn is_implicit(&self) -> bool {
+        self.0.len() == 64
+            && self
+                .0
+                .bytes()
+                .all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(&c))
+    }
+}
+
+impl fmt::Display for NearAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for NearAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NearAccountId({})", self.0)
+    }
+}
+
+impl FromStr for NearAccountId {
+    type Err = MultiChainAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_separator = |c: u8| matches!(c, b'-' | b'_' | b'.');
+        let bytes = s.as_bytes();
+        let valid = (2..=64).contains(&bytes.len())
+            && bytes
+                .iter()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || is_separator(*c))
+            && !is_separator(bytes[0])
+            && !is_separator(bytes[bytes.len() - 1])
+            && !bytes
+                .windows(2)
+                .any(|pair| is_separator(pair[0]) && is_separator(pair[1]));
+        if !valid {
+            return Err(MultiChainAddressError::InvalidEncoding);
+        }
+        Ok(Self(Cow::Owned(String::from(s))))
+    }
+}
@@ -0,0 +1,68 @@
+use super::{base58, MultiChainAddressError};
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+/// A Solana account address: a 32-byte Ed25519 public key, written in base58.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{SolanaAddress, SOLANA_USDC};
+///
+/// let mint: SolanaAddress = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".parse().unwrap();
+/// assert_eq!(mint, SOLANA_USDC);
+/// assert_eq!(mint.to_string(), "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+///
+/// // The system program, all zero bytes.
+/// let system: SolanaAddress = "11111111111111111111111111111111".parse().unwrap();
+/// assert_eq!(system, SolanaAddress::new([0; 32]));
+///
+/// // Hex EVM addresses are rejected.
+/// assert!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".parse::<SolanaAddress>().is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SolanaAddress([u8; 32]);
+
+impl SolanaAddress {
+    /// Wraps the raw public key bytes.
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw public key bytes.
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the base58 encoding.
+    pub fn to_base58(&self) -> String {
+        base58::encode(&self.0)
+    }
+}
+
+impl fmt::Display for SolanaAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_base58())
+    }
+}
+
+impl fmt::Debug for SolanaAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SolanaAddress({self})")
+    }
+}
+
+impl FromStr for SolanaAddress {
+    type Err = MultiChainAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base58::decode(s).ok_or(MultiChainAddressError::InvalidEncoding)?;
+        let bytes = <[u8; 32]>::try_from(bytes.as_slice()).map_err(|_| {
+            MultiChainAddressError::InvalidLength {
+                expected: 32,
+                actual: bytes.len(),
+            }
+        })?;
+        Ok(Self(bytes))
+    }
+}
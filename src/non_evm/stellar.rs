@@ -0,0 +1,212 @@
+use super::MultiChainAddressError;
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloy_primitives::hex;
+use core::{fmt, str::FromStr};
+
+/// The strkey version byte of an Ed25519 public key, which encodes as a
+/// leading `G`.
+const ACCOUNT_ID_VERSION: u8 = 6 << 3;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// The issuing account of USDC on Stellar, operated by Circle.
+pub const STELLAR_USDC_ISSUER: StellarAccountId = StellarAccountId::new(hex!(
+    "3b9911380efe988ba0a8900eb1cfe44f366f7dbe946bed077240f7f624df15c5"
+));
+
+/// A Stellar account ID: a 32-byte Ed25519 public key, written as a `G...`
+/// strkey with a CRC16 checksum.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{StellarAccountId, STELLAR_USDC_ISSUER};
+///
+/// let issuer: StellarAccountId = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(issuer, STELLAR_USDC_ISSUER);
+/// assert_eq!(issuer.to_string(), "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN");
+///
+/// // A single mistyped character fails the checksum.
+/// assert!("GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVM"
+///     .parse::<StellarAccountId>()
+///     .is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StellarAccountId([u8; 32]);
+
+impl StellarAccountId {
+    /// Wraps the raw public key bytes.
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw public key bytes.
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the `G...` strkey.
+    pub fn to_strkey(&self) -> String {
+        let mut payload = Vec::with_capacity(35);
+        payload.push(ACCOUNT_ID_VERSION);
+        payload.extend_from_slice(&self.0);
+        payload.extend_from_slice(&crc16(&payload).to_le_bytes());
+        base32_encode(&payload)
+    }
+}
+
+impl fmt::Display for StellarAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_strkey())
+    }
+}
+
+impl fmt::Debug for StellarAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "StellarAccountId({self})")
+    }
+}
+
+impl FromStr for StellarAccountId {
+    type Err = MultiChainAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let payload = base32_decode(s).ok_or(MultiChainAddressError::InvalidEncoding)?;
+        if payload.len() != 35 {
+            return Err(MultiChainAddressError::InvalidLength {
+                expected: 35,
+                actual: payload.len(),
+            });
+        }
+        if payload[0] != ACCOUNT_ID_VERSION {
+            return Err(MultiChainAddressError::InvalidEncoding);
+        }
+        if crc16(&payload[..33]).to_le_bytes() != payload[33..] {
+            return Err(MultiChainAddressError::InvalidChecksum);
+        }
+        let mut key = [0; 32];
+        key.copy_from_slice(&payload[1..33]);
+        Ok(Self(key))
+    }
+}
+
+/// A Stellar asset: an asset code and the account that issues it.
+///
+/// Written as `CODE:ISSUER`, the SEP-11 form used by Horizon and most wallets.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::StellarAsset;
+///
+/// let usdc: StellarAsset = "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(usdc, StellarAsset::USDC);
+/// assert_eq!(usdc.code(), "USDC");
+///
+/// // The same code from another issuer is a different asset.
+/// assert!("USDC".parse::<StellarAsset>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StellarAsset {
+    code: Cow<'static, str>,
+    issuer: StellarAccountId,
+}
+
+impl StellarAsset {
+    /// Native USDC on Stellar, issued by Circle.
+    pub const USDC: Self = Self::from_static("USDC", STELLAR_USDC_ISSUER);
+
+    /// Creates an asset known at compile time.
+    pub const fn from_static(code: &'static str, issuer: StellarAccountId) -> Self {
+        Self {
+            code: Cow::Borrowed(code),
+            issuer,
+        }
+    }
+
+    /// Returns the asset code, e.g. `USDC`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the issuing account.
+    pub const fn issuer(&self) -> StellarAccountId {
+        self.issuer
+    }
+}
+
+impl fmt::Display for StellarAsset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.code, self.issuer)
+    }
+}
+
+impl FromStr for StellarAsset {
+    type Err = MultiChainAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (code, issuer) = s
+            .split_once(':')
+            .ok_or(MultiChainAddressError::InvalidEncoding)?;
+        if !(1..=12).contains(&code.len()) || !code.bytes().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(MultiChainAddressError::InvalidEncoding);
+        }
+        Ok(Self {
+            code: Cow::Owned(String::from(code)),
+            issuer: issuer.parse()?,
+        })
+    }
+}
+
+/// CRC16-XModem, the strkey checksum.
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= u16::from(*byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// RFC 4648 base32 without padding.
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    let (mut buffer, mut bits) = (0u16, 0);
+    for byte in bytes {
+        buffer = (buffer << 8) | u16::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(BASE32_ALPHABET[usize::from((buffer >> bits) & 31)] as char);
+        }
+    }
+    if bits > 0 {
+        encoded.push(BASE32_ALPHABET[usize::from((buffer << (5 - bits)) & 31)] as char);
+    }
+    encoded
+}
+
+fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u16, 0);
+    for c in input.bytes() {
+        let value = BASE32_ALPHABET.iter().position(|a| *a == c)? as u16;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
@@ -0,0 +1,103 @@
+use super::{parse_move_address, MultiChainAddressError};
+use alloc::{borrow::Cow, string::String};
+use alloy_primitives::{b256, B256};
+use core::{fmt, str::FromStr};
+
+/// A Sui coin type, `<package>::<module>::<name>`, which identifies a token
+/// on Sui the way a contract address does on an EVM chain.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::SuiCoinType;
+///
+/// let usdc: SuiCoinType =
+///     "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC"
+///         .parse()
+///         .unwrap();
+/// assert_eq!(usdc, SuiCoinType::USDC);
+///
+/// // Short package addresses are zero-padded, as in `0x2::sui::SUI`.
+/// let sui: SuiCoinType = "0x2::sui::SUI".parse().unwrap();
+/// assert_eq!(sui.module(), "sui");
+/// assert!(sui.to_string().starts_with("0x0000"));
+///
+/// // A bare EVM address is not a coin type.
+/// assert!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".parse::<SuiCoinType>().is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SuiCoinType {
+    package: B256,
+    module: Cow<'static, str>,
+    name: Cow<'static, str>,
+}
+
+impl SuiCoinType {
+    /// Native USDC on Sui, issued by Circle.
+    pub const USDC: Self = Self::from_static(
+        b256!("0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7"),
+        "usdc",
+        "USDC",
+    );
+
+    /// Creates a coin type known at compile time.
+    pub const fn from_static(package: B256, module: &'static str, name: &'static str) -> Self {
+        Self {
+            package,
+            module: Cow::Borrowed(module),
+            name: Cow::Borrowed(name),
+        }
+    }
+
+    /// Returns the address of the package defining the coin.
+    pub const fn package(&self) -> B256 {
+        self.package
+    }
+
+    /// Returns the module defining the coin.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Returns the name of the coin's struct.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for SuiCoinType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}::{}", self.package, self.module, self.name)
+    }
+}
+
+impl fmt::Debug for SuiCoinType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SuiCoinType({self})")
+    }
+}
+
+impl FromStr for SuiCoinType {
+    type Err = MultiChainAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split("::");
+        let (Some(package), Some(module), Some(name), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(MultiChainAddressError::InvalidEncoding);
+        };
+        let is_identifier = |part: &str| {
+            part.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+                && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        };
+        if !is_identifier(module) || !is_identifier(name) {
+            return Err(MultiChainAddressError::InvalidEncoding);
+        }
+        Ok(Self {
+            package: parse_move_address(package)?,
+            module: Cow::Owned(String::from(module)),
+            name: Cow::Owned(String::from(name)),
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use super::{base58, MultiChainAddressError};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{address, hex, Address};
+use core::{fmt, str::FromStr};
+use sha2::{Digest, Sha256};
+
+/// The prefix byte of mainnet Tron addresses, which base58-encodes as a
+/// leading `T`.
+const MAINNET_PREFIX: u8 = 0x41;
+
+/// USDC on Tron, a TRC-20 token. Circle stopped minting it in 2024, but
+/// existing balances remain transferable.
+pub const TRON_USDC: TronAddress =
+    TronAddress::new(address!("0x3487b63d30b5b2c87fb7ffa8bcfade38eaac1abe"));
+
+/// A Tron account or contract address.
+///
+/// Tron addresses are EVM-style 20-byte addresses behind a `0x41` prefix.
+/// They are usually written in base58check (`T...`), and sometimes as the
+/// 21-byte hex form (`41...`) used by node APIs.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{TronAddress, TRON_USDC};
+///
+/// let usdc: TronAddress = "TEkxiTehnzSmSe2XqrBj4w32RUN966rdz8".parse().unwrap();
+/// assert_eq!(usdc, TRON_USDC);
+/// assert_eq!(usdc.to_hex(), "413487b63d30b5b2c87fb7ffa8bcfade38eaac1abe");
+/// assert_eq!(TronAddress::from_hex("413487b63d30b5b2c87fb7ffa8bcfade38eaac1abe").unwrap(), usdc);
+///
+/// // A mistyped character fails the checksum.
+/// assert!("TEkxiTehnzSmSe2XqrBj4w32RUN966rdz9".parse::<TronAddress>().is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TronAddress(Address);
+
+impl TronAddress {
+    /// Wraps the 20 bytes following the `0x41` prefix.
+    pub const fn new(address: Address) -> Self {
+        Self(address)
+    }
+
+    /// Returns the address without its prefix, as seen by the TVM, e.g. in
+    /// calldata and event topics.
+    pub const fn evm_address(&self) -> Address {
+        self.0
+    }
+
+    /// Parses the 21-byte hex form, with or without a `0x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MultiChainAddressError`] if the input is not 21 bytes of hex
+    /// starting with `41`.
+    pub fn from_hex(s: &str) -> Result<Self, MultiChainAddressError> {
+        let bytes = hex::decode(s).map_err(|_| MultiChainAddressError::InvalidEncoding)?;
+        Self::from_prefixed(&bytes)
+    }
+
+    /// Returns the 21-byte hex form, `41` followed by the address.
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.prefixed())
+    }
+
+    /// Returns the base58check form, `T...`.
+    pub fn to_base58(&self) -> String {
+        let mut payload = self.prefixed();
+        let checksum = double_sha256(&payload);
+        payload.extend_from_slice(&checksum[..4]);
+        base58::encode(&payload)
+    }
+
+    fn prefixed(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(25);
+        bytes.push(MAINNET_PREFIX);
+        bytes.extend_from_slice(self.0.as_slice());
+        bytes
+    }
+
+    fn from_prefixed(bytes: &[u8]) -> Result<Self, MultiChainAddressError> {
+        if bytes.len() != 21 {
+            return Err(MultiChainAddressError::InvalidLength {
+                expected: 21,
+                actual: bytes.len(),
+            });
+        }
+        if bytes[0] != MAINNET_PREFIX {
+            return Err(MultiChainAddressError::InvalidEncoding);
+        }
+        Ok(Self(Address::from_slice(&bytes[1..])))
+    }
+}
+
+impl fmt::Display for TronAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_base58())
+    }
+}
+
+impl fmt::Debug for TronAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TronAddress({self})")
+    }
+}
+
+/// Parses the base58check form.
+impl FromStr for TronAddress {
+    type Err = MultiChainAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base58::decode(s).ok_or(MultiChainAddressError::InvalidEncoding)?;
+        if bytes.len() != 25 {
+            return Err(MultiChainAddressError::InvalidLength {
+                expected: 25,
+                actual: bytes.len(),
+            });
+        }
+        let (payload, checksum) = bytes.split_at(21);
+        if double_sha256(payload)[..4] != *checksum {
+            return Err(MultiChainAddressError::InvalidChecksum);
+        }
+        Self::from_prefixed(payload)
+    }
+}
+
+impl From<TronAddress> for Address {
+    fn from(address: TronAddress) -> Self {
+        address.0
+    }
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
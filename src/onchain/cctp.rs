@@ -0,0 +1,68 @@
+use super::{call, EthProvider, OnchainError};
+use crate::{abi, cctp_contracts_for, CctpVersion, Usdc};
+use alloy_chains::NamedChain;
+use alloy_primitives::U256;
+
+/// Reads the most USDC a single CCTP burn on `chain` may move, from the
+/// `burnLimitsPerMessage` of the chain's `TokenMinter` for `version`, in raw
+/// token units.
+///
+/// A `depositForBurn` above the limit reverts, so check amounts before
+/// submitting a burn and split larger transfers. CCTP has no per-domain limit;
+/// the per-message limit applies to every destination.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{cctp_burn_limit, CctpVersion, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{Address, Bytes, B256, U256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(8453) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(B256::from(U256::from(1_000_000_000_000u64).to_be_bytes::<32>()).into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let provider = Node;
+///
+/// let amount = U256::from(25_000_000);
+/// let limit = block_on(cctp_burn_limit(&provider, NamedChain::Base, CctpVersion::V1)).unwrap();
+/// assert!(amount <= limit, "split the transfer into burns of at most {limit}");
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If the chain lacks `version` or has no known USDC
+///   address.
+/// - [`OnchainError::Provider`] or [`OnchainError::Decode`]: If the call fails.
+pub async fn cctp_burn_limit<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+    version: CctpVersion,
+) -> Result<U256, OnchainError> {
+    let minter = cctp_contracts_for(chain, version)?.token_minter;
+    let token = chain.usdc_address()?;
+    call(
+        &provider,
+        minter,
+        "burnLimitsPerMessage(address)",
+        abi::encode_call(abi::BURN_LIMITS_PER_MESSAGE, &[abi::address_word(token)]),
+        abi::decode_uint,
+    )
+    .await
+}
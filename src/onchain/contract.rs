@@ -0,0 +1,258 @@
+use super::{call, EthProvider, OnchainError, UsdcRoles};
+use crate::{abi, Usdc, UsdcError};
+use alloc::string::String;
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, Bytes, B256, U256};
+
+/// The USDC contract on one chain, bound to a provider.
+///
+/// Created with [`UsdcProviderExt::usdc`], or [`UsdcContract::new`] for a
+/// custom address.
+#[derive(Clone, Copy, Debug)]
+pub struct UsdcContract<P> {
+    provider: P,
+    chain: NamedChain,
+    address: Address,
+}
+
+impl<P: EthProvider> UsdcContract<P> {
+    /// Binds `provider` to the token at `address` on `chain`, e.g. for a fork
+    /// or an address from a [`UsdcRegistry`](crate::UsdcRegistry).
+    pub fn new(provider: P, chain: NamedChain, address: Address) -> Self {
+        Self {
+            provider,
+            chain,
+            address,
+        }
+    }
+
+    /// Returns the chain the contract is deployed on.
+    pub fn chain(&self) -> NamedChain {
+        self.chain
+    }
+
+    /// Returns the token address.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns the underlying provider.
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Calls `name()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn name(&self) -> Result<String, OnchainError> {
+        self.call(
+            "name()",
+            abi::encode_call(abi::NAME, &[]),
+            abi::decode_string,
+        )
+        .await
+    }
+
+    /// Calls `symbol()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn symbol(&self) -> Result<String, OnchainError> {
+        self.call(
+            "symbol()",
+            abi::encode_call(abi::SYMBOL, &[]),
+            abi::decode_string,
+        )
+        .await
+    }
+
+    /// Calls `decimals()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn decimals(&self) -> Result<u8, OnchainError> {
+        self.call(
+            "decimals()",
+            abi::encode_call(abi::DECIMALS, &[]),
+            abi::decode_u8,
+        )
+        .await
+    }
+
+    /// Calls `DOMAIN_SEPARATOR()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn domain_separator(&self) -> Result<B256, OnchainError> {
+        self.call(
+            "DOMAIN_SEPARATOR()",
+            abi::encode_call(abi::DOMAIN_SEPARATOR, &[]),
+            abi::decode_word,
+        )
+        .await
+    }
+
+    /// Calls `totalSupply()` and returns the supply in raw token units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn total_supply(&self) -> Result<U256, OnchainError> {
+        self.call(
+            "totalSupply()",
+            abi::encode_call(abi::TOTAL_SUPPLY, &[]),
+            abi::decode_uint,
+        )
+        .await
+    }
+
+    /// Calls `balanceOf(owner)` and returns the balance in raw token units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn balance_of(&self, owner: Address) -> Result<U256, OnchainError> {
+        self.call(
+            "balanceOf(address)",
+            abi::encode_call(abi::BALANCE_OF, &[abi::address_word(owner)]),
+            abi::decode_uint,
+        )
+        .await
+    }
+
+    /// Calls `allowance(owner, spender)` and returns the amount `spender` may
+    /// transfer on behalf of `owner`, in raw token units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn allowance(&self, owner: Address, spender: Address) -> Result<U256, OnchainError> {
+        self.call(
+            "allowance(address,address)",
+            abi::encode_call(
+                abi::ALLOWANCE,
+                &[abi::address_word(owner), abi::address_word(spender)],
+            ),
+            abi::decode_uint,
+        )
+        .await
+    }
+
+    /// Calls `isBlacklisted(account)`, FiatToken's compliance blocklist. A
+    /// blacklisted account can neither send nor receive USDC.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn is_blacklisted(&self, account: Address) -> Result<bool, OnchainError> {
+        self.call(
+            "isBlacklisted(address)",
+            abi::encode_call(abi::IS_BLACKLISTED, &[abi::address_word(account)]),
+            abi::decode_bool,
+        )
+        .await
+    }
+
+    /// Calls `paused()`. While the token is paused, every transfer, approval
+    /// and mint reverts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if the call fails or returns malformed data.
+    pub async fn paused(&self) -> Result<bool, OnchainError> {
+        self.call(
+            "paused()",
+            abi::encode_call(abi::PAUSED, &[]),
+            abi::decode_bool,
+        )
+        .await
+    }
+
+    /// Reads the FiatToken admin roles with `owner()`, `masterMinter()`,
+    /// `pauser()`, `blacklister()` and `rescuer()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OnchainError`] if a call fails or returns malformed data.
+    pub async fn roles(&self) -> Result<UsdcRoles, OnchainError> {
+        let role = |function, selector| {
+            self.call(
+                function,
+                abi::encode_call(selector, &[]),
+                abi::decode_address,
+            )
+        };
+        Ok(UsdcRoles {
+            owner: role("owner()", abi::OWNER).await?,
+            master_minter: role("masterMinter()", abi::MASTER_MINTER).await?,
+            pauser: role("pauser()", abi::PAUSER).await?,
+            blacklister: role("blacklister()", abi::BLACKLISTER).await?,
+            rescuer: role("rescuer()", abi::RESCUER).await?,
+        })
+    }
+
+    pub(crate) async fn call<T>(
+        &self,
+        function: &'static str,
+        data: Bytes,
+        decode: impl FnOnce(&[u8]) -> Option<T>,
+    ) -> Result<T, OnchainError> {
+        call(&self.provider, self.address, function, data, decode).await
+    }
+}
+
+/// Binds a provider to the USDC contract of a chain, so callers do not have to
+/// look up the address and wire up a contract themselves.
+///
+/// Implemented for every [`EthProvider`].
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{EthProvider, UsdcProviderExt, ARBITRUM_USDC};
+/// use alloy_chains::NamedChain;
+/// # use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(42161) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(B256::with_last_byte(6).into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let provider = Node;
+///
+/// let usdc = provider.usdc(NamedChain::Arbitrum).unwrap();
+/// assert_eq!(usdc.address(), ARBITRUM_USDC);
+/// assert_eq!(block_on(usdc.decimals()).unwrap(), 6);
+/// ```
+pub trait UsdcProviderExt: EthProvider + Sized {
+    /// Returns the USDC contract on `chain`, bound to this provider.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+    fn usdc(&self, chain: NamedChain) -> Result<UsdcContract<&Self>, UsdcError> {
+        Ok(UsdcContract::new(self, chain, chain.usdc_address()?))
+    }
+}
+
+impl<P: EthProvider> UsdcProviderExt for P {}
@@ -0,0 +1,194 @@
+use super::{EthProvider, OnchainError, UsdcProviderExt};
+use crate::{format_usdc, usdc_decimals};
+use alloc::string::String;
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, U256};
+
+/// Returns the USDC balance of `owner` on `chain`, in raw token units.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{usdc_balance_of, usdc_balance_of_formatted, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, U256};
+/// # use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(8453) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(B256::from(U256::from(1_250_000).to_be_bytes::<32>()).into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let provider = Node;
+///
+/// let owner = address!("0x1111111111111111111111111111111111111111");
+/// let raw = block_on(usdc_balance_of(&provider, NamedChain::Base, owner)).unwrap();
+/// assert_eq!(raw, U256::from(1_250_000));
+///
+/// let formatted = block_on(usdc_balance_of_formatted(&provider, NamedChain::Base, owner)).unwrap();
+/// assert_eq!(formatted, "1.25");
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If the chain has no known USDC address.
+/// - [`OnchainError::Provider`] or [`OnchainError::Decode`]: If the call fails.
+pub async fn usdc_balance_of<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+    owner: Address,
+) -> Result<U256, OnchainError> {
+    provider.usdc(chain)?.balance_of(owner).await
+}
+
+/// Returns the USDC balance of `owner` on `chain` as a decimal string, scaled
+/// by the deployment's decimals, e.g. `"1.25"`.
+///
+/// Scaling uses [`usdc_decimals`], so the 18-decimal Binance-Peg USDC on BSC is
+/// handled like every 6-decimal deployment.
+///
+/// # Errors
+///
+/// See [`usdc_balance_of`].
+pub async fn usdc_balance_of_formatted<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+    owner: Address,
+) -> Result<String, OnchainError> {
+    let decimals = usdc_decimals(chain)?;
+    let balance = usdc_balance_of(provider, chain, owner).await?;
+    Ok(format_usdc(balance, decimals))
+}
+
+/// Returns the amount of USDC `spender` may transfer on behalf of `owner` on
+/// `chain`, in raw token units.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{usdc_allowance, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, U256};
+/// # use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(1) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(B256::from(U256::from(5_000_000).to_be_bytes::<32>()).into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let provider = Node;
+///
+/// let owner = address!("0x1111111111111111111111111111111111111111");
+/// let router = address!("0x2222222222222222222222222222222222222222");
+/// let amount = U256::from(5_000_000);
+///
+/// let allowance = block_on(usdc_allowance(&provider, NamedChain::Mainnet, owner, router)).unwrap();
+/// assert!(allowance >= amount, "approval required");
+/// ```
+///
+/// # Errors
+///
+/// See [`usdc_balance_of`].
+pub async fn usdc_allowance<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+    owner: Address,
+    spender: Address,
+) -> Result<U256, OnchainError> {
+    provider.usdc(chain)?.allowance(owner, spender).await
+}
+
+/// The circulating USDC supply on a chain, as returned by
+/// [`usdc_total_supply`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsdcSupply {
+    /// The supply in raw token units.
+    pub raw: U256,
+    /// The supply as a decimal string scaled by the deployment's decimals, e.g.
+    /// `"55512345678.9"`.
+    pub formatted: String,
+}
+
+/// Returns the total USDC supply on `chain`, both raw and scaled by the
+/// deployment's decimals, e.g. for dashboards tracking supply per chain.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{usdc_total_supply, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::U256;
+/// # use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(42161) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(B256::from(U256::from(7_250_000_000_000_000u64).to_be_bytes::<32>()).into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let provider = Node;
+///
+/// let supply = block_on(usdc_total_supply(&provider, NamedChain::Arbitrum)).unwrap();
+/// assert_eq!(supply.raw, U256::from(7_250_000_000_000_000u64));
+/// assert_eq!(supply.formatted, "7250000000");
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If the chain has no known USDC address.
+/// - [`OnchainError::Provider`] or [`OnchainError::Decode`]: If the call fails.
+pub async fn usdc_total_supply<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+) -> Result<UsdcSupply, OnchainError> {
+    let decimals = usdc_decimals(chain)?;
+    let raw = provider.usdc(chain)?.total_supply().await?;
+    Ok(UsdcSupply {
+        raw,
+        formatted: format_usdc(raw, decimals),
+    })
+}
@@ -0,0 +1,215 @@
+use super::{EthProvider, OnchainError, UsdcProviderExt};
+use alloc::vec::Vec;
+use alloy_chains::NamedChain;
+use alloy_primitives::Address;
+
+/// The privileged accounts of a FiatToken contract.
+///
+/// Risk monitoring can snapshot these with [`usdc_roles`], persist them (with
+/// the `serde` feature, addresses are written checksummed) and alert when a
+/// later read [`changes`](Self::changes).
+///
+/// The crate ships no per-chain baseline: role holders are not published with
+/// the token addresses and cannot be verified offline, so the baseline is the
+/// first snapshot an application stores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsdcRoles {
+    /// Can reassign the other roles. Upgrades are controlled separately, by the
+    /// proxy admin.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_helpers::checksummed")
+    )]
+    pub owner: Address,
+    /// Configures minters and their allowances.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_helpers::checksummed")
+    )]
+    pub master_minter: Address,
+    /// Can pause and unpause the token.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_helpers::checksummed")
+    )]
+    pub pauser: Address,
+    /// Can add accounts to and remove them from the blacklist.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_helpers::checksummed")
+    )]
+    pub blacklister: Address,
+    /// Can recover other tokens sent to the contract.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_helpers::checksummed")
+    )]
+    pub rescuer: Address,
+}
+
+impl UsdcRoles {
+    /// Returns the names of the roles whose holder differs between `self` and
+    /// `other`, in declaration order.
+    pub fn changes(&self, other: &Self) -> Vec<&'static str> {
+        [
+            ("owner", self.owner == other.owner),
+            ("master_minter", self.master_minter == other.master_minter),
+            ("pauser", self.pauser == other.pauser),
+            ("blacklister", self.blacklister == other.blacklister),
+            ("rescuer", self.rescuer == other.rescuer),
+        ]
+        .into_iter()
+        .filter(|(_, unchanged)| !unchanged)
+        .map(|(role, _)| role)
+        .collect()
+    }
+}
+
+/// Reads the current [`UsdcRoles`] of the USDC contract on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{usdc_roles, EthProvider};
+/// use alloy_chains::NamedChain;
+/// # use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(1) }
+/// #     async fn call(&self, _: Address, data: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(Address::repeat_byte(data[0]).into_word().into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let provider = Node;
+///
+/// let baseline = block_on(usdc_roles(&provider, NamedChain::Mainnet)).unwrap();
+/// // ... later
+/// let current = block_on(usdc_roles(&provider, NamedChain::Mainnet)).unwrap();
+/// assert!(baseline.changes(&current).is_empty());
+/// ```
+///
+/// # Errors
+///
+/// See [`usdc_is_blacklisted`].
+pub async fn usdc_roles<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+) -> Result<UsdcRoles, OnchainError> {
+    provider.usdc(chain)?.roles().await
+}
+
+/// Returns `true` if `account` is on the USDC blacklist on `chain`, so
+/// counterparties can be screened before a transfer is built. Transfers to or
+/// from a blacklisted account revert.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{usdc_is_blacklisted, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::address;
+/// # use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(1) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(B256::ZERO.into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let provider = Node;
+///
+/// let recipient = address!("0x1111111111111111111111111111111111111111");
+/// if block_on(usdc_is_blacklisted(&provider, NamedChain::Mainnet, recipient)).unwrap() {
+///     panic!("refusing to pay a blacklisted address");
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If the chain has no known USDC address.
+/// - [`OnchainError::Provider`] or [`OnchainError::Decode`]: If the call fails,
+///   e.g. because the token is not a FiatToken.
+pub async fn usdc_is_blacklisted<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+    account: Address,
+) -> Result<bool, OnchainError> {
+    provider.usdc(chain)?.is_blacklisted(account).await
+}
+
+/// Returns `true` if the USDC contract on `chain` is paused, so payment
+/// pipelines can back off during a pause instead of submitting transactions
+/// that revert without a useful reason.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{usdc_paused, EthProvider};
+/// use alloy_chains::NamedChain;
+/// # use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(8453) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(B256::with_last_byte(1).into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let provider = Node;
+///
+/// if block_on(usdc_paused(&provider, NamedChain::Base)).unwrap() {
+///     // Queue the payout and retry later.
+/// }
+/// ```
+///
+/// # Errors
+///
+/// See [`usdc_is_blacklisted`].
+pub async fn usdc_paused<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+) -> Result<bool, OnchainError> {
+    provider.usdc(chain)?.paused().await
+}
@@ -0,0 +1,228 @@
+//! Reading USDC contract state from a node.
+//!
+//! Like the remote resolvers, this crate does not ship an RPC stack. The
+//! helpers are generic over an [`EthProvider`], a small trait that applications
+//! implement on top of whichever client they already use (alloy, ethers, a
+//! hand-rolled JSON-RPC client, ...).
+
+mod cctp;
+mod contract;
+mod erc20;
+mod fiat_token;
+mod oracle;
+mod subscribe;
+mod verify;
+
+pub use cctp::cctp_burn_limit;
+pub use contract::{UsdcContract, UsdcProviderExt};
+pub use erc20::{
+    usdc_allowance, usdc_balance_of, usdc_balance_of_formatted, usdc_total_supply, UsdcSupply,
+};
+pub use fiat_token::{usdc_is_blacklisted, usdc_paused, usdc_roles, UsdcRoles};
+pub use oracle::{chainlink_latest_price, pyth_latest_price, ChainlinkPrice, PythPrice};
+pub use subscribe::{subscribe_usdc_transfers, LogStream, LogSubscriber, UsdcTransfers};
+pub use verify::{verify_domain_separator, verify_onchain, FieldCheck, VerificationReport};
+
+use crate::{abi, Usdc, UsdcError};
+use alloc::boxed::Box;
+use alloy_chains::NamedChain;
+use alloy_primitives::{b256, Address, Bytes, B256};
+use core::future::Future;
+use std::error::Error as StdError;
+use thiserror::Error;
+
+/// The subset of the Ethereum JSON-RPC API used by the on-chain helpers.
+///
+/// An adapter over an alloy provider is a few lines:
+///
+/// ```rust,ignore
+/// impl<P: alloy_provider::Provider> EthProvider for MyProvider<P> {
+///     type Error = alloy_transport::TransportError;
+///
+///     async fn chain_id(&self) -> Result<u64, Self::Error> {
+///         self.0.get_chain_id().await
+///     }
+///
+///     async fn call(&self, to: Address, data: Bytes) -> Result<Bytes, Self::Error> {
+///         let request = TransactionRequest::default().to(to).input(data.into());
+///         self.0.call(request).await
+///     }
+///
+///     async fn storage_at(&self, address: Address, slot: B256) -> Result<B256, Self::Error> {
+///         self.0.get_storage_at(address, slot.into()).await.map(B256::from)
+///     }
+/// }
+/// ```
+pub trait EthProvider {
+    /// The error returned when a request cannot be completed.
+    type Error: StdError + Send + Sync + 'static;
+
+    /// Returns the chain ID the node serves (`eth_chainId`).
+    fn chain_id(&self) -> impl Future<Output = Result<u64, Self::Error>> + Send;
+
+    /// Executes a read-only call against the latest block (`eth_call`) and
+    /// returns the raw return data.
+    fn call(
+        &self,
+        to: Address,
+        data: Bytes,
+    ) -> impl Future<Output = Result<Bytes, Self::Error>> + Send;
+
+    /// Reads a storage slot at the latest block (`eth_getStorageAt`).
+    fn storage_at(
+        &self,
+        address: Address,
+        slot: B256,
+    ) -> impl Future<Output = Result<B256, Self::Error>> + Send;
+}
+
+impl<P: EthProvider + ?Sized> EthProvider for &P {
+    type Error = P::Error;
+
+    fn chain_id(&self) -> impl Future<Output = Result<u64, Self::Error>> + Send {
+        (**self).chain_id()
+    }
+
+    fn call(
+        &self,
+        to: Address,
+        data: Bytes,
+    ) -> impl Future<Output = Result<Bytes, Self::Error>> + Send {
+        (**self).call(to, data)
+    }
+
+    fn storage_at(
+        &self,
+        address: Address,
+        slot: B256,
+    ) -> impl Future<Output = Result<B256, Self::Error>> + Send {
+        (**self).storage_at(address, slot)
+    }
+}
+
+/// Represents errors that can occur when reading contract state.
+#[derive(Error, Debug)]
+pub enum OnchainError {
+    /// The provider could not complete a request.
+    #[error("Provider request failed: {0}")]
+    Provider(#[source] Box<dyn StdError + Send + Sync>),
+
+    /// A call returned data that does not decode as the expected type, e.g.
+    /// because the address holds no contract.
+    #[error("Failed to decode the return data of '{function}'")]
+    Decode {
+        /// The signature of the function called.
+        function: &'static str,
+    },
+
+    /// The chain has no known USDC deployment.
+    #[error(transparent)]
+    Usdc(#[from] UsdcError),
+}
+
+impl OnchainError {
+    pub(crate) fn provider(err: impl StdError + Send + Sync + 'static) -> Self {
+        Self::Provider(Box::new(err))
+    }
+}
+
+/// Calls `function` on `to` and decodes the result with `decode`.
+pub(crate) async fn call<P: EthProvider, T>(
+    provider: &P,
+    to: Address,
+    function: &'static str,
+    data: Bytes,
+    decode: impl FnOnce(&[u8]) -> Option<T>,
+) -> Result<T, OnchainError> {
+    let output = provider
+        .call(to, data)
+        .await
+        .map_err(OnchainError::provider)?;
+    decode(&output).ok_or(OnchainError::Decode { function })
+}
+
+/// The EIP-1967 implementation slot, `keccak256("eip1967.proxy.implementation") - 1`.
+const EIP1967_IMPLEMENTATION_SLOT: B256 =
+    b256!("0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// The implementation slot of the ZeppelinOS `AdminUpgradeabilityProxy`,
+/// `keccak256("org.zeppelinos.proxy.implementation")`. Circle's `FiatTokenProxy`
+/// predates EIP-1967 and uses this slot instead.
+const ZEPPELINOS_IMPLEMENTATION_SLOT: B256 =
+    b256!("0x7050c9e0f4ca769c69bd3a8ef740bc37934f8e2c036e5a723fd8ee048ed3f8c3");
+
+/// Reads the implementation address behind the proxy at `proxy`, checking the
+/// EIP-1967 slot first and then the ZeppelinOS slot used by `FiatTokenProxy`.
+/// Returns `None` if neither slot is set.
+pub(crate) async fn proxy_implementation<P: EthProvider>(
+    provider: &P,
+    proxy: Address,
+) -> Result<Option<Address>, OnchainError> {
+    for slot in [EIP1967_IMPLEMENTATION_SLOT, ZEPPELINOS_IMPLEMENTATION_SLOT] {
+        let word = provider
+            .storage_at(proxy, slot)
+            .await
+            .map_err(OnchainError::provider)?;
+        if word != B256::ZERO {
+            return abi::word_to_address(word)
+                .map(Some)
+                .ok_or(OnchainError::Decode {
+                    function: "implementation slot",
+                });
+        }
+    }
+    Ok(None)
+}
+
+/// Reads the implementation address from the live proxy slot of the USDC
+/// contract on `chain`, for comparison with the recorded
+/// [`usdc_implementation`](crate::usdc_implementation).
+///
+/// Both the EIP-1967 slot and the ZeppelinOS slot used by `FiatTokenProxy` are
+/// checked. Returns `None` if neither is set.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{usdc_implementation, usdc_live_implementation, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(1) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(Bytes::new())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(usdc_implementation(NamedChain::Mainnet).unwrap().unwrap().into_word())
+/// #     }
+/// # }
+///
+/// let live = block_on(usdc_live_implementation(&Node, NamedChain::Mainnet)).unwrap();
+/// if live != usdc_implementation(NamedChain::Mainnet).unwrap() {
+///     panic!("USDC was upgraded to {live:?}");
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If the chain has no known USDC address.
+/// - [`OnchainError::Provider`]: If a request fails.
+/// - [`OnchainError::Decode`]: If a slot does not hold an address.
+pub async fn usdc_live_implementation<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+) -> Result<Option<Address>, OnchainError> {
+    proxy_implementation(&provider, chain.usdc_address()?).await
+}
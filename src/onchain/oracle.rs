@@ -0,0 +1,237 @@
+use super::{call, EthProvider, OnchainError};
+use crate::{abi, chainlink_usdc_usd_feed, pyth_contract, ChainlinkFeed, PYTH_USDC_USD_FEED_ID};
+use alloy_chains::NamedChain;
+use alloy_primitives::{I256, U256};
+use core::time::Duration;
+
+/// The latest answer of a Chainlink feed, returned by [`chainlink_latest_price`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChainlinkPrice {
+    /// The feed that was read.
+    pub feed: ChainlinkFeed,
+    /// The round the answer belongs to.
+    pub round_id: u128,
+    /// The price with [`ChainlinkFeed::decimals`] decimals, e.g. `99_985_000`
+    /// for $0.99985.
+    pub answer: I256,
+    /// The Unix timestamp, in seconds, at which the answer was last updated.
+    pub updated_at: u64,
+}
+
+impl ChainlinkPrice {
+    /// Returns the age of the answer at the Unix timestamp `now`, in seconds.
+    pub fn age(&self, now: u64) -> Duration {
+        Duration::from_secs(now.saturating_sub(self.updated_at))
+    }
+
+    /// Returns `true` if the answer is older than the feed's heartbeat at the
+    /// Unix timestamp `now`, i.e. the feed has stopped updating and the price
+    /// should not be trusted.
+    pub fn is_stale(&self, now: u64) -> bool {
+        self.age(now) > self.feed.heartbeat
+    }
+
+    /// Returns how far the price is from $1.00, in basis points: negative
+    /// below the peg, positive above it.
+    pub fn peg_deviation_bps(&self) -> I256 {
+        let one = I256::exp10(usize::from(self.feed.decimals));
+        self.answer
+            .saturating_sub(one)
+            .saturating_mul(I256::exp10(4))
+            / one
+    }
+}
+
+/// Reads the latest answer of the Chainlink USDC/USD feed on `chain` with
+/// `latestRoundData()`, e.g. for depeg alerts.
+///
+/// The answer is returned as-is: check [`ChainlinkPrice::is_stale`] against the
+/// current time before acting on it.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{chainlink_latest_price, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::I256;
+/// # use alloy_primitives::{Address, Bytes, B256, U256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(1) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         let words = [7u64, 99_950_000, 1_700_000_000, 1_700_000_000, 7];
+/// #         Ok(words.iter().flat_map(|w| U256::from(*w).to_be_bytes::<32>()).collect())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let now = 1_700_000_600;
+///
+/// let price = block_on(chainlink_latest_price(&Node, NamedChain::Mainnet)).unwrap();
+/// assert!(!price.is_stale(now));
+/// // $0.9995, 5 bps below the peg.
+/// assert_eq!(price.peg_deviation_bps(), I256::try_from(-5).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If no USDC/USD feed is recorded for the chain.
+/// - [`OnchainError::Provider`] or [`OnchainError::Decode`]: If the call fails.
+pub async fn chainlink_latest_price<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+) -> Result<ChainlinkPrice, OnchainError> {
+    let feed = chainlink_usdc_usd_feed(chain)?;
+    let (round_id, answer, updated_at) = call(
+        &provider,
+        feed.address,
+        "latestRoundData()",
+        abi::encode_call(abi::LATEST_ROUND_DATA, &[]),
+        decode_round,
+    )
+    .await?;
+    Ok(ChainlinkPrice {
+        feed,
+        round_id,
+        answer,
+        updated_at,
+    })
+}
+
+/// Decodes `(uint80 roundId, int256 answer, uint256 startedAt, uint256
+/// updatedAt, uint80 answeredInRound)` into the round ID, answer and update
+/// time.
+fn decode_round(data: &[u8]) -> Option<(u128, I256, u64)> {
+    let word = |index: usize| {
+        data.get(32 * index..32 * (index + 1))
+            .map(U256::from_be_slice)
+    };
+    let round_id = word(0)?.try_into().ok()?;
+    let answer = I256::from_raw(word(1)?);
+    let updated_at = word(3)?.try_into().ok()?;
+    word(4)?;
+    Some((round_id, answer, updated_at))
+}
+
+/// A Pyth price, returned by [`pyth_latest_price`].
+///
+/// The price is `price * 10^expo` dollars, give or take `conf * 10^expo`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PythPrice {
+    /// The price, scaled by [`expo`](Self::expo).
+    pub price: i64,
+    /// The confidence interval around the price, scaled by
+    /// [`expo`](Self::expo).
+    pub conf: u64,
+    /// The power of ten both values are scaled by, e.g. `-8`.
+    pub expo: i32,
+    /// The Unix timestamp, in seconds, at which the price was published.
+    pub publish_time: u64,
+}
+
+impl PythPrice {
+    /// Returns the age of the price at the Unix timestamp `now`, in seconds.
+    pub fn age(&self, now: u64) -> Duration {
+        Duration::from_secs(now.saturating_sub(self.publish_time))
+    }
+
+    /// Returns `true` if the price is older than `max_age` at the Unix
+    /// timestamp `now`. Pyth prices are only updated when someone pays for an
+    /// update, so pick `max_age` for the use case.
+    pub fn is_stale(&self, now: u64, max_age: Duration) -> bool {
+        self.age(now) > max_age
+    }
+}
+
+/// Reads the stored USDC/USD price from the Pyth contract on `chain` with
+/// `getPriceUnsafe(PYTH_USDC_USD_FEED_ID)`, as an alternative oracle to
+/// [`chainlink_latest_price`].
+///
+/// The price is whatever was last pushed to the contract, however old;
+/// check [`PythPrice::is_stale`] before acting on it.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{pyth_latest_price, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use core::time::Duration;
+/// # use alloy_primitives::{Address, Bytes, B256, I256, U256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(8453) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         let words = [
+/// #             I256::try_from(99_990_000).unwrap(),
+/// #             I256::try_from(45_000).unwrap(),
+/// #             I256::try_from(-8).unwrap(),
+/// #             I256::try_from(1_700_000_000).unwrap(),
+/// #         ];
+/// #         Ok(words.iter().flat_map(|w| w.to_be_bytes::<32>()).collect())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+/// # let now = 1_700_000_030;
+///
+/// let price = block_on(pyth_latest_price(&Node, NamedChain::Base)).unwrap();
+/// assert_eq!((price.price, price.expo), (99_990_000, -8));
+/// assert!(!price.is_stale(now, Duration::from_secs(60)));
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If no Pyth contract is recorded for the chain.
+/// - [`OnchainError::Provider`] or [`OnchainError::Decode`]: If the call fails,
+///   e.g. because the contract has never received a USDC/USD update.
+pub async fn pyth_latest_price<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+) -> Result<PythPrice, OnchainError> {
+    call(
+        &provider,
+        pyth_contract(chain)?,
+        "getPriceUnsafe(bytes32)",
+        abi::encode_call(abi::GET_PRICE_UNSAFE, &[PYTH_USDC_USD_FEED_ID]),
+        decode_pyth_price,
+    )
+    .await
+}
+
+/// Decodes `(int64 price, uint64 conf, int32 expo, uint256 publishTime)`.
+fn decode_pyth_price(data: &[u8]) -> Option<PythPrice> {
+    let word = |index: usize| {
+        data.get(32 * index..32 * (index + 1))
+            .map(U256::from_be_slice)
+    };
+    Some(PythPrice {
+        price: I256::from_raw(word(0)?).try_into().ok()?,
+        conf: word(1)?.try_into().ok()?,
+        expo: I256::from_raw(word(2)?).try_into().ok()?,
+        publish_time: word(3)?.try_into().ok()?,
+    })
+}
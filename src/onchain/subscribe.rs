@@ -0,0 +1,283 @@
+use super::OnchainError;
+use crate::{TransferEvent, TransferFilter};
+use alloy_primitives::Log;
+use core::{
+    future::{self, Future},
+    task::{Context, Poll},
+};
+use std::error::Error as StdError;
+
+/// A node connection that can push new logs, e.g. over a WebSocket with
+/// `eth_subscribe("logs", filter)`.
+///
+/// An adapter over an alloy pub-sub provider is a few lines:
+///
+/// ```rust,ignore
+/// impl<P: alloy_provider::Provider> LogSubscriber for MyProvider<P> {
+///     type Error = alloy_transport::TransportError;
+///     type Logs = MyLogs;
+///
+///     async fn subscribe_logs(&self, filter: &TransferFilter) -> Result<MyLogs, Self::Error> {
+///         let mut rpc = Filter::new().address(filter.address).event_signature(TRANSFER_TOPIC);
+///         if let Some(from) = filter.from {
+///             rpc = rpc.topic1(from.into_word());
+///         }
+///         if let Some(to) = filter.to {
+///             rpc = rpc.topic2(to.into_word());
+///         }
+///         let stream = self.0.subscribe_logs(&rpc).await?.into_stream();
+///         Ok(MyLogs(Box::pin(stream)))
+///     }
+/// }
+///
+/// impl LogStream for MyLogs {
+///     type Error = alloy_transport::TransportError;
+///
+///     fn poll_next_log(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Log, Self::Error>>> {
+///         self.0.poll_next_unpin(cx).map(|log| log.map(|log| Ok(log.inner)))
+///     }
+/// }
+/// ```
+pub trait LogSubscriber {
+    /// The error returned when the subscription cannot be opened or breaks.
+    type Error: StdError + Send + Sync + 'static;
+
+    /// The stream of logs delivered by an open subscription.
+    type Logs: LogStream<Error = Self::Error>;
+
+    /// Subscribes to new logs matching `filter`'s address and topics, as in
+    /// [`TransferFilter::to_json`].
+    fn subscribe_logs(
+        &self,
+        filter: &TransferFilter,
+    ) -> impl Future<Output = Result<Self::Logs, Self::Error>> + Send;
+}
+
+impl<P: LogSubscriber + ?Sized> LogSubscriber for &P {
+    type Error = P::Error;
+    type Logs = P::Logs;
+
+    fn subscribe_logs(
+        &self,
+        filter: &TransferFilter,
+    ) -> impl Future<Output = Result<Self::Logs, Self::Error>> + Send {
+        (**self).subscribe_logs(filter)
+    }
+}
+
+/// The logs of an open subscription, in the shape of a
+/// `Stream<Item = Result<Log, Error>>`, so any such stream adapts with a
+/// one-line `poll_next_unpin`.
+pub trait LogStream {
+    /// The error returned when the subscription breaks.
+    type Error: StdError + Send + Sync + 'static;
+
+    /// Polls for the next log, returning `Ready(None)` once the subscription
+    /// has ended.
+    fn poll_next_log(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Log, Self::Error>>>;
+}
+
+/// A live stream of USDC transfers, created with [`subscribe_usdc_transfers`].
+///
+/// [`poll_next`](Self::poll_next) has the signature of `Stream::poll_next`, so
+/// wrapping it with `futures::stream::poll_fn` gives a `Stream` of transfers.
+#[derive(Debug)]
+pub struct UsdcTransfers<S> {
+    logs: S,
+    filter: TransferFilter,
+}
+
+impl<S: LogStream> UsdcTransfers<S> {
+    /// Returns the filter transfers are matched against.
+    pub fn filter(&self) -> &TransferFilter {
+        &self.filter
+    }
+
+    /// Polls for the next matching transfer, returning `Ready(None)` once the
+    /// subscription has ended.
+    ///
+    /// Logs that do not match the filter are skipped, whether the node sent
+    /// them anyway or they fall below [`TransferFilter::min_value`], which
+    /// nodes cannot filter on.
+    pub fn poll_next(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<TransferEvent, OnchainError>>> {
+        loop {
+            match self.logs.poll_next_log(cx) {
+                Poll::Ready(Some(Ok(log))) => {
+                    if let Some(transfer) = self.filter.decode(&log) {
+                        return Poll::Ready(Some(Ok(transfer)));
+                    }
+                }
+                Poll::Ready(Some(Err(err))) => {
+                    return Poll::Ready(Some(Err(OnchainError::provider(err))))
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    /// Waits for the next matching transfer, returning `None` once the
+    /// subscription has ended.
+    pub async fn next(&mut self) -> Option<Result<TransferEvent, OnchainError>> {
+        future::poll_fn(|cx| self.poll_next(cx)).await
+    }
+}
+
+/// Subscribes to USDC transfers matching `filter` through `provider`, e.g. a
+/// WebSocket connection, and decodes them as they arrive.
+///
+/// Narrow the subscription with the [`TransferFilter`] builder methods: by
+/// sender or recipient, which the node filters, or by
+/// [`min_value`](TransferFilter::min_value), which is applied to each log as it
+/// arrives.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{subscribe_usdc_transfers, usdc_transfer_filter, LogStream, LogSubscriber};
+/// use usdshe::{TransferFilter, BASE_USDC, TRANSFER_TOPIC};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, Address, Log, U256};
+/// use std::{collections::VecDeque, task::{Context, Poll}};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # fn transfer(to: Address, value: u64) -> Log {
+/// #     let topics = vec![TRANSFER_TOPIC, Address::ZERO.into_word(), to.into_word()];
+/// #     let data = U256::from(value).to_be_bytes_vec().into();
+/// #     Log::new(BASE_USDC, topics, data).unwrap()
+/// # }
+///
+/// // A node that replays two transfers; real applications wrap a WebSocket
+/// // subscription instead.
+/// struct Node(Vec<Log>);
+/// struct Logs(VecDeque<Log>);
+///
+/// impl LogSubscriber for Node {
+///     type Error = std::io::Error;
+///     type Logs = Logs;
+///
+///     async fn subscribe_logs(&self, _filter: &TransferFilter) -> Result<Logs, Self::Error> {
+///         Ok(Logs(self.0.clone().into()))
+///     }
+/// }
+///
+/// impl LogStream for Logs {
+///     type Error = std::io::Error;
+///
+///     fn poll_next_log(&mut self, _cx: &mut Context<'_>) -> Poll<Option<Result<Log, Self::Error>>> {
+///         Poll::Ready(self.0.pop_front().map(Ok))
+///     }
+/// }
+///
+/// let treasury = address!("0x1111111111111111111111111111111111111111");
+/// let provider = Node(vec![transfer(treasury, 5_000_000), transfer(treasury, 25_000_000_000)]);
+/// let filter = usdc_transfer_filter(NamedChain::Base)
+///     .unwrap()
+///     .to(treasury)
+///     .min_value(U256::from(10_000_000_000u64));
+///
+/// block_on(async {
+///     let mut transfers = subscribe_usdc_transfers(&provider, filter).await.unwrap();
+///     while let Some(transfer) = transfers.next().await {
+///         let transfer = transfer.unwrap();
+///         assert_eq!(transfer.to, treasury);
+///         assert_eq!(transfer.value, U256::from(25_000_000_000u64));
+///     }
+/// });
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Provider`]: If the subscription cannot be opened.
+pub async fn subscribe_usdc_transfers<P: LogSubscriber>(
+    provider: P,
+    filter: TransferFilter,
+) -> Result<UsdcTransfers<P::Logs>, OnchainError> {
+    let logs = provider
+        .subscribe_logs(&filter)
+        .await
+        .map_err(OnchainError::provider)?;
+    Ok(UsdcTransfers { logs, filter })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_support::{block_on, transfer_log, Subscriber},
+        usdc_transfer_filter,
+    };
+    use alloc::vec::Vec;
+    use alloy_chains::NamedChain;
+    use alloy_primitives::{Address, U256};
+
+    /// A chain from whichever chain groups are enabled, and its USDC address.
+    fn any_usdc() -> (NamedChain, Address) {
+        crate::all()
+            .next()
+            .expect("at least one chain group is enabled")
+    }
+
+    fn collect<S: LogStream>(mut transfers: UsdcTransfers<S>) -> Vec<TransferEvent> {
+        block_on(async {
+            let mut out = Vec::new();
+            while let Some(transfer) = transfers.next().await {
+                out.push(transfer.unwrap());
+            }
+            out
+        })
+    }
+
+    #[test]
+    fn skips_logs_outside_the_filter() {
+        let (chain, usdc) = any_usdc();
+        let holder = Address::repeat_byte(1);
+        let provider = Subscriber::new([
+            transfer_log(Address::repeat_byte(0x42), holder, U256::from(1)),
+            transfer_log(usdc, Address::repeat_byte(2), U256::from(2)),
+            transfer_log(usdc, holder, U256::from(3)),
+        ]);
+        let filter = usdc_transfer_filter(chain).unwrap().to(holder);
+        let transfers = block_on(subscribe_usdc_transfers(&provider, filter)).unwrap();
+        let values: Vec<_> = collect(transfers).iter().map(|t| t.value).collect();
+        assert_eq!(values, [U256::from(3)]);
+    }
+
+    #[test]
+    fn applies_the_minimum_value() {
+        let (chain, usdc) = any_usdc();
+        let holder = Address::repeat_byte(1);
+        let provider = Subscriber::new([
+            transfer_log(usdc, holder, U256::from(999)),
+            transfer_log(usdc, holder, U256::from(1_000)),
+        ]);
+        let filter = usdc_transfer_filter(chain)
+            .unwrap()
+            .min_value(U256::from(1_000));
+        let transfers = block_on(subscribe_usdc_transfers(&provider, filter)).unwrap();
+        assert_eq!(collect(transfers).len(), 1);
+    }
+
+    #[test]
+    fn surfaces_subscription_errors() {
+        let provider = Subscriber::failing();
+        let filter = usdc_transfer_filter(any_usdc().0).unwrap();
+        let mut transfers = block_on(subscribe_usdc_transfers(&provider, filter)).unwrap();
+        assert!(matches!(
+            block_on(transfers.next()),
+            Some(Err(OnchainError::Provider(_)))
+        ));
+        assert!(block_on(transfers.next()).is_none());
+    }
+}
@@ -0,0 +1,280 @@
+use super::{proxy_implementation, EthProvider, OnchainError, UsdcContract, UsdcProviderExt};
+use crate::{usdc_domain_separator, usdc_eip712_domain, usdc_implementation, usdc_token_info};
+use alloc::{string::String, vec::Vec};
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, B256};
+
+/// A value from the built-in data next to the value read from chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldCheck<T> {
+    /// The value this crate records.
+    pub expected: T,
+    /// The value the node returned.
+    pub actual: T,
+}
+
+impl<T: PartialEq> FieldCheck<T> {
+    /// Returns `true` if the live value matches the recorded one.
+    pub fn is_match(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+impl<T: PartialEq> FieldCheck<Option<T>> {
+    /// Returns `true` if the live value matches the recorded one, or if nothing
+    /// is recorded.
+    pub fn is_match_or_unrecorded(&self) -> bool {
+        self.expected.is_none() || self.is_match()
+    }
+}
+
+/// The result of [`verify_onchain`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// The chain that was checked.
+    pub chain: NamedChain,
+    /// The registered USDC address that was queried.
+    pub address: Address,
+    /// The chain ID reported by the provider, which catches a provider pointed
+    /// at the wrong network.
+    pub chain_id: FieldCheck<u64>,
+    /// The token symbol.
+    pub symbol: FieldCheck<String>,
+    /// The token name.
+    pub name: FieldCheck<String>,
+    /// The token decimals.
+    pub decimals: FieldCheck<u8>,
+    /// The implementation behind the proxy at [`address`](Self::address):
+    /// the recorded [`usdc_implementation`] next to the EIP-1967 implementation
+    /// slot, or the equivalent slot of Circle's `FiatTokenProxy`. `actual` is
+    /// `None` if the token is not behind a proxy.
+    ///
+    /// This only counts as a mismatch where an implementation is recorded, so
+    /// an upgraded proxy is reported without flagging every chain whose
+    /// implementation the crate does not track.
+    pub implementation: FieldCheck<Option<Address>>,
+}
+
+impl VerificationReport {
+    /// Returns `true` if every field matches the built-in data.
+    pub fn is_ok(&self) -> bool {
+        self.mismatches().is_empty()
+    }
+
+    /// Returns the names of the fields that do not match, in declaration order.
+    pub fn mismatches(&self) -> Vec<&'static str> {
+        [
+            ("chain_id", self.chain_id.is_match()),
+            ("symbol", self.symbol.is_match()),
+            ("name", self.name.is_match()),
+            ("decimals", self.decimals.is_match()),
+            (
+                "implementation",
+                self.implementation.is_match_or_unrecorded(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, matches)| !matches)
+        .map(|(field, _)| field)
+        .collect()
+    }
+}
+
+/// Compares the registered USDC deployment on `chain` with live contract state,
+/// so applications can check that the built-in data has not gone stale.
+///
+/// `symbol()`, `name()` and `decimals()` are compared with
+/// [`usdc_token_info`], and the proxy's implementation slot with
+/// [`usdc_implementation`].
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{verify_onchain, EthProvider, BASE_USDC};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # fn abi_string(s: &str) -> Bytes {
+/// #     let mut out = vec![0u8; 64];
+/// #     out[31] = 32;
+/// #     out[63] = s.len() as u8;
+/// #     out.extend(s.as_bytes());
+/// #     out.resize(96, 0);
+/// #     out.into()
+/// # }
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(8453) }
+/// #     async fn call(&self, _: Address, data: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(match &data[..4] {
+/// #             [0x95, 0xd8, 0x9b, 0x41] => abi_string("USDC"),
+/// #             [0x06, 0xfd, 0xde, 0x03] => abi_string("USD Coin"),
+/// #             _ => B256::with_last_byte(6).into(),
+/// #         })
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+///
+/// // `Node` implements `EthProvider` on top of an RPC client.
+/// let report = block_on(verify_onchain(&Node, NamedChain::Base)).unwrap();
+/// assert_eq!(report.address, BASE_USDC);
+/// assert!(report.is_ok(), "stale fields: {:?}", report.mismatches());
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If the chain has no known USDC address.
+/// - [`OnchainError::Provider`]: If a request fails.
+/// - [`OnchainError::Decode`]: If a getter returns malformed data, e.g. because
+///   no contract is deployed at the address.
+pub async fn verify_onchain<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+) -> Result<VerificationReport, OnchainError> {
+    let info = usdc_token_info(chain)?;
+    let address = info.address;
+
+    let chain_id = provider.chain_id().await.map_err(OnchainError::provider)?;
+    let usdc = UsdcContract::new(&provider, chain, address);
+    let symbol = usdc.symbol().await?;
+    let name = usdc.name().await?;
+    let decimals = usdc.decimals().await?;
+    let implementation = FieldCheck {
+        expected: usdc_implementation(chain)?,
+        actual: proxy_implementation(&provider, address).await?,
+    };
+
+    Ok(VerificationReport {
+        chain,
+        address,
+        chain_id: FieldCheck {
+            expected: chain as u64,
+            actual: chain_id,
+        },
+        symbol: FieldCheck {
+            expected: info.symbol,
+            actual: symbol,
+        },
+        name: FieldCheck {
+            expected: info.name,
+            actual: name,
+        },
+        decimals: FieldCheck {
+            expected: info.decimals,
+            actual: decimals,
+        },
+        implementation,
+    })
+}
+
+/// Compares the expected domain separator of `chain` with the contract's live
+/// `DOMAIN_SEPARATOR()`, e.g. in a startup check before trusting offline
+/// signatures.
+///
+/// The expected value is the recorded [`usdc_domain_separator`] where there is
+/// one, and otherwise the separator of [`usdc_eip712_domain`].
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{usdc_domain_separator, verify_domain_separator, EthProvider};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{Address, Bytes, B256};
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+/// # struct Node;
+/// # impl EthProvider for Node {
+/// #     type Error = std::io::Error;
+/// #     async fn chain_id(&self) -> Result<u64, Self::Error> { Ok(10) }
+/// #     async fn call(&self, _: Address, _: Bytes) -> Result<Bytes, Self::Error> {
+/// #         Ok(usdc_domain_separator(NamedChain::Optimism).unwrap().into())
+/// #     }
+/// #     async fn storage_at(&self, _: Address, _: B256) -> Result<B256, Self::Error> {
+/// #         Ok(B256::ZERO)
+/// #     }
+/// # }
+///
+/// let check = block_on(verify_domain_separator(&Node, NamedChain::Optimism)).unwrap();
+/// assert!(check.is_match(), "live separator: {}", check.actual);
+/// ```
+///
+/// # Errors
+///
+/// - [`OnchainError::Usdc`]: If the chain has no known USDC address or no
+///   EIP-712 domain.
+/// - [`OnchainError::Provider`] or [`OnchainError::Decode`]: If the call fails.
+pub async fn verify_domain_separator<P: EthProvider>(
+    provider: P,
+    chain: NamedChain,
+) -> Result<FieldCheck<B256>, OnchainError> {
+    let expected = match usdc_domain_separator(chain) {
+        Some(separator) => separator,
+        None => usdc_eip712_domain(chain)?.separator(),
+    };
+    let actual = provider.usdc(chain)?.domain_separator().await?;
+    Ok(FieldCheck { expected, actual })
+}
+
+#[cfg(all(test, feature = "ethereum"))]
+mod tests {
+    use super::*;
+    use crate::test_support::{block_on, Node};
+    use alloy_primitives::{Bytes, U256};
+
+    /// ABI-encodes `value` as the return data of a `string` getter.
+    fn abi_string(value: &str) -> Bytes {
+        let mut out = B256::with_last_byte(32).to_vec();
+        out.extend(B256::from(U256::from(value.len())));
+        out.extend(value.as_bytes());
+        out.resize(64 + value.len().div_ceil(32) * 32, 0);
+        out.into()
+    }
+
+    fn mainnet(implementation: Address) -> VerificationReport {
+        let provider = Node::new(1)
+            .on_call(|_, data| match data[..4] {
+                [0x95, 0xd8, 0x9b, 0x41] => abi_string("USDC"),
+                [0x06, 0xfd, 0xde, 0x03] => abi_string("USD Coin"),
+                _ => B256::with_last_byte(6).into(),
+            })
+            .with_storage(implementation.into_word());
+        block_on(verify_onchain(&provider, NamedChain::Mainnet)).unwrap()
+    }
+
+    #[test]
+    fn recorded_implementation_verifies() {
+        let recorded = usdc_implementation(NamedChain::Mainnet).unwrap().unwrap();
+        let report = mainnet(recorded);
+        assert!(report.is_ok(), "{:?}", report.mismatches());
+    }
+
+    #[test]
+    fn upgraded_proxy_is_a_mismatch() {
+        let report = mainnet(Address::repeat_byte(0x42));
+        assert_eq!(
+            report.implementation.actual,
+            Some(Address::repeat_byte(0x42))
+        );
+        assert_eq!(report.mismatches(), ["implementation"]);
+    }
+}
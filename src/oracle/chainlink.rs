@@ -0,0 +1,100 @@
+use crate::UsdcError;
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+use core::time::Duration;
+
+/// The Chainlink USDC/USD aggregator proxy on Ethereum mainnet.
+pub const ETHEREUM_CHAINLINK_USDC_USD: Address =
+    address!("0x8fFfFfd4AfB6115b954Bd326cbe7B4BA576818f6");
+/// The Chainlink USDC/USD aggregator proxy on Arbitrum One.
+pub const ARBITRUM_CHAINLINK_USDC_USD: Address =
+    address!("0x50834F3163758fcC1Df9973b6e91f0F0F0434aD3");
+/// The Chainlink USDC/USD aggregator proxy on Avalanche C-Chain.
+pub const AVALANCHE_CHAINLINK_USDC_USD: Address =
+    address!("0xF096872672F44d6EBA71458D74fe67F9a77a23B9");
+/// The Chainlink USDC/USD aggregator proxy on Base.
+pub const BASE_CHAINLINK_USDC_USD: Address = address!("0x7e860098F58bBFC8648a4311b374B1D669a2bc6B");
+/// The Chainlink USDC/USD aggregator proxy on BNB Smart Chain.
+pub const BSC_CHAINLINK_USDC_USD: Address = address!("0x51597f405303C4377E36123cBc172b13269EA163");
+/// The Chainlink USDC/USD aggregator proxy on Optimism.
+pub const OPTIMISM_CHAINLINK_USDC_USD: Address =
+    address!("0x16a9FA2FDa030272Ce99B29CF780dFA30361E0f3");
+/// The Chainlink USDC/USD aggregator proxy on Polygon PoS.
+pub const POLYGON_CHAINLINK_USDC_USD: Address =
+    address!("0xfE4A8cc5b5B2366C1B58Bea3858e81843581b2F7");
+
+/// The decimals of every Chainlink USD-denominated feed.
+const USD_FEED_DECIMALS: u8 = 8;
+
+/// A Chainlink price feed, returned by [`chainlink_usdc_usd_feed`].
+///
+/// A feed publishes a new answer when the price moves by more than
+/// [`deviation_bps`](Self::deviation_bps), and at least once per
+/// [`heartbeat`](Self::heartbeat) otherwise, so an answer older than the
+/// heartbeat means the feed has stalled. The thresholds are those Chainlink
+/// published when this crate was released and can be changed by Chainlink.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChainlinkFeed {
+    /// The aggregator proxy to read, which stays fixed across aggregator
+    /// upgrades.
+    pub address: Address,
+    /// The decimals of the answer: `100_000_000` is $1.00.
+    pub decimals: u8,
+    /// The longest interval between two answers.
+    pub heartbeat: Duration,
+    /// The price movement that triggers a new answer, in basis points.
+    pub deviation_bps: u16,
+}
+
+/// Returns the Chainlink USDC/USD feed on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{chainlink_usdc_usd_feed, BASE_CHAINLINK_USDC_USD};
+/// use alloy_chains::NamedChain;
+/// use core::time::Duration;
+///
+/// let feed = chainlink_usdc_usd_feed(NamedChain::Base).unwrap();
+/// assert_eq!(feed.address, BASE_CHAINLINK_USDC_USD);
+/// assert_eq!(feed.decimals, 8);
+/// assert_eq!(feed.heartbeat, Duration::from_secs(24 * 60 * 60));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If no USDC/USD feed is recorded for the
+///   chain.
+pub fn chainlink_usdc_usd_feed(chain: NamedChain) -> Result<ChainlinkFeed, UsdcError> {
+    feed_on(chain).ok_or(UsdcError::UnsupportedChain(chain))
+}
+
+const fn feed_on(chain: NamedChain) -> Option<ChainlinkFeed> {
+    let feed = match chain {
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some((ETHEREUM_CHAINLINK_USDC_USD, 86_400, 25)),
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some((ARBITRUM_CHAINLINK_USDC_USD, 86_400, 10)),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some((AVALANCHE_CHAINLINK_USDC_USD, 86_400, 10)),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some((BASE_CHAINLINK_USDC_USD, 86_400, 30)),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::BinanceSmartChain => Some((BSC_CHAINLINK_USDC_USD, 86_400, 10)),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some((OPTIMISM_CHAINLINK_USDC_USD, 86_400, 10)),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some((POLYGON_CHAINLINK_USDC_USD, 86_400, 25)),
+        _ => None,
+    };
+    let Some((address, heartbeat, deviation_bps)) = feed else {
+        return None;
+    };
+    Some(ChainlinkFeed {
+        address,
+        decimals: USD_FEED_DECIMALS,
+        heartbeat: Duration::from_secs(heartbeat),
+        deviation_bps,
+    })
+}
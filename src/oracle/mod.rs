@@ -0,0 +1,14 @@
+//! Price oracles quoting USDC against the US dollar, for depeg monitoring.
+//!
+//! Chainlink pushes USDC/USD answers to a feed on every chain; Pyth serves a
+//! single feed ID from one contract per chain, updated on demand. Only the feed
+//! metadata is recorded here; with the `onchain` feature,
+//! [`chainlink_latest_price`](crate::chainlink_latest_price) and
+//! [`pyth_latest_price`](crate::pyth_latest_price) read the latest price
+//! through an [`EthProvider`](crate::EthProvider).
+
+mod chainlink;
+mod pyth;
+
+pub use chainlink::*;
+pub use pyth::*;
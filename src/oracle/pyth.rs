@@ -0,0 +1,79 @@
+use crate::UsdcError;
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, b256, Address, B256};
+
+/// The Pyth price feed ID of USDC/USD, the same on every chain.
+pub const PYTH_USDC_USD_FEED_ID: B256 =
+    b256!("0xeaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a");
+
+/// The Pyth contract on Ethereum mainnet.
+pub const ETHEREUM_PYTH: Address = address!("0x4305FB66699C3B2702D4d05CF36551390A4c69C6");
+/// The Pyth contract on Arbitrum One.
+pub const ARBITRUM_PYTH: Address = address!("0xff1a0f4744e8582DF1aE09D5611b887B6a12925C");
+/// The Pyth contract on Avalanche C-Chain.
+pub const AVALANCHE_PYTH: Address = address!("0x4305FB66699C3B2702D4d05CF36551390A4c69C6");
+/// The Pyth contract on Base.
+pub const BASE_PYTH: Address = address!("0x8250f4aF4B972684F7b336503E2D6dFeDeB1487a");
+/// The Pyth contract on BNB Smart Chain.
+pub const BSC_PYTH: Address = address!("0x4D7E825f80bDf85e913E0DD2A2D54927e9dE1594");
+/// The Pyth contract on Linea.
+pub const LINEA_PYTH: Address = address!("0xA2aa501b19aff244D90cc15a4Cf739D2725B5729");
+/// The Pyth contract on Mantle.
+pub const MANTLE_PYTH: Address = address!("0xA2aa501b19aff244D90cc15a4Cf739D2725B5729");
+/// The Pyth contract on Optimism.
+pub const OPTIMISM_PYTH: Address = address!("0xff1a0f4744e8582DF1aE09D5611b887B6a12925C");
+/// The Pyth contract on Polygon PoS.
+pub const POLYGON_PYTH: Address = address!("0xff1a0f4744e8582DF1aE09D5611b887B6a12925C");
+/// The Pyth contract on Scroll.
+pub const SCROLL_PYTH: Address = address!("0xA2aa501b19aff244D90cc15a4Cf739D2725B5729");
+
+/// Returns the Pyth price feed contract on `chain`, which serves
+/// [`PYTH_USDC_USD_FEED_ID`] among every other Pyth feed.
+///
+/// Pyth is a pull oracle: the contract only holds the prices someone has
+/// submitted an update for, so on quiet chains the stored USDC/USD price can be
+/// old. Check its publish time before relying on it.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{pyth_contract, BASE_PYTH};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(pyth_contract(NamedChain::Base).unwrap(), BASE_PYTH);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If no Pyth contract is recorded for the
+///   chain.
+pub fn pyth_contract(chain: NamedChain) -> Result<Address, UsdcError> {
+    pyth_on(chain).ok_or(UsdcError::UnsupportedChain(chain))
+}
+
+const fn pyth_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_PYTH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_PYTH),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_PYTH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_PYTH),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::BinanceSmartChain => Some(BSC_PYTH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Linea => Some(LINEA_PYTH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Mantle => Some(MANTLE_PYTH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_PYTH),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_PYTH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Scroll => Some(SCROLL_PYTH),
+        _ => None,
+    }
+}
@@ -0,0 +1,105 @@
+//! EIP-2612 `permit` approvals.
+
+use crate::{abi, usdc_eip712_domain, Eip712Message, TypedData, UsdcError};
+use alloc::{vec, vec::Vec};
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, B256, U256};
+
+/// An EIP-2612 `Permit`, approving `spender` to transfer `value` of `owner`'s
+/// tokens without an on-chain `approve`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Permit {
+    /// The token holder signing the approval.
+    pub owner: Address,
+    /// The account allowed to spend the tokens.
+    pub spender: Address,
+    /// The allowance, in raw token units.
+    pub value: U256,
+    /// The owner's current `nonces(owner)`.
+    pub nonce: U256,
+    /// The Unix timestamp after which the signature is rejected.
+    pub deadline: U256,
+}
+
+impl Eip712Message for Permit {
+    const PRIMARY_TYPE: &'static str = "Permit";
+
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("owner", "address"),
+        ("spender", "address"),
+        ("value", "uint256"),
+        ("nonce", "uint256"),
+        ("deadline", "uint256"),
+    ];
+
+    fn encode_values(&self) -> Vec<B256> {
+        vec![
+            abi::address_word(self.owner),
+            abi::address_word(self.spender),
+            abi::uint_word(self.value),
+            abi::uint_word(self.nonce),
+            abi::uint_word(self.deadline),
+        ]
+    }
+}
+
+/// Builds the EIP-712 typed data of a `permit` for the USDC contract on
+/// `chain`, ready to be signed by `owner`.
+///
+/// `value` is in the deployment's raw units; read `nonce` from the token's
+/// `nonces(owner)`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::build_permit;
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, U256};
+///
+/// let owner = address!("0x1111111111111111111111111111111111111111");
+/// let spender = address!("0x2222222222222222222222222222222222222222");
+///
+/// let permit = build_permit(
+///     NamedChain::Base,
+///     owner,
+///     spender,
+///     U256::from(25_000_000),
+///     U256::ZERO,
+///     U256::from(1_800_000_000),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(permit.domain.name, "USD Coin");
+/// assert_eq!(permit.domain.chain_id, 8453);
+/// let digest = permit.signing_hash(); // sign this with the owner's key
+/// let json = permit.to_json(); // or hand this to `eth_signTypedData_v4`
+/// # assert!(json.contains(r#""primaryType":"Permit""#));
+/// # assert!(json.contains(r#""value":"25000000""#));
+/// # let _ = digest;
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+/// - [`UsdcError::SignaturesUnsupported`]: If the token on the chain does not
+///   implement `permit`.
+pub fn build_permit(
+    chain: NamedChain,
+    owner: Address,
+    spender: Address,
+    value: U256,
+    nonce: U256,
+    deadline: U256,
+) -> Result<TypedData<Permit>, UsdcError> {
+    Ok(TypedData {
+        domain: usdc_eip712_domain(chain)?,
+        message: Permit {
+            owner,
+            spender,
+            value,
+            nonce,
+            deadline,
+        },
+    })
+}
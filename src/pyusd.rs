@@ -0,0 +1,79 @@
+//! PayPal USD (PYUSD) addresses.
+
+use crate::{TokenKind, Tokens, UsdcError};
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::{address, Address};
+
+/// PayPal USD on Ethereum mainnet, issued by Paxos.
+pub const ETHEREUM_PYUSD: Address = address!("0x6c3ea9036406852006290770BEdFcAbA0e23A0e8");
+/// PayPal USD on Arbitrum One.
+pub const ARBITRUM_PYUSD: Address = address!("0x46850aD61C2B7d64d08c9C754F45254596696984");
+/// PayPal USD on Ethereum Sepolia.
+pub const ETHEREUM_SEPOLIA_PYUSD: Address = address!("0xCaC524BcA292aaade2DF8A05cC58F0a65B1B3bB9");
+
+/// A trait for types that can provide a PayPal USD (PYUSD) contract address, the
+/// counterpart to [`Usdc`](crate::Usdc).
+///
+/// PYUSD uses 6 decimals.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{Pyusd, UsdcError, ETHEREUM_PYUSD};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(NamedChain::Mainnet.pyusd_address().unwrap(), ETHEREUM_PYUSD);
+/// assert!(1u64.is_pyusd(ETHEREUM_PYUSD));
+/// assert!(matches!(
+///     NamedChain::Base.pyusd_address(),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Base))
+/// ));
+/// ```
+pub trait Pyusd {
+    /// Returns the PYUSD contract address for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but has no known
+    ///   PYUSD address.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn pyusd_address(&self) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical PYUSD contract for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_pyusd(&self, address: Address) -> bool {
+        self.pyusd_address().is_ok_and(|pyusd| pyusd == address)
+    }
+}
+
+pub(crate) const fn pyusd_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_PYUSD),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_PYUSD),
+        #[cfg(feature = "testnets")]
+        NamedChain::Sepolia => Some(ETHEREUM_SEPOLIA_PYUSD),
+        _ => None,
+    }
+}
+
+impl Pyusd for NamedChain {
+    fn pyusd_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Pyusd)
+    }
+}
+
+impl Pyusd for Chain {
+    fn pyusd_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Pyusd)
+    }
+}
+
+impl Pyusd for u64 {
+    fn pyusd_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Pyusd)
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{SupportedUsdcChain, Usdc, UsdcError};
+use alloc::{collections::BTreeMap, vec::Vec};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+
+/// A runtime-editable table of USDC addresses, keyed by chain ID.
+///
+/// [`UsdcRegistry::new`] seeds the table with the built-in data; entries can then
+/// be added, overridden or removed, e.g. for private forks and appchains whose
+/// addresses this crate cannot know. Any chain ID is accepted as a key, named or
+/// not.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{Usdc, UsdcError, UsdcRegistry, BASE_USDC};
+/// use alloy_chains::{Chain, NamedChain};
+/// use alloy_primitives::address;
+///
+/// let mut registry = UsdcRegistry::new();
+/// assert_eq!(registry.usdc_address(NamedChain::Base).unwrap(), BASE_USDC);
+///
+/// // Register USDC on a private appchain.
+/// let appchain = Chain::from_id(12_345);
+/// let token = address!("0x1111111111111111111111111111111111111111");
+/// registry.insert(appchain, token);
+/// assert_eq!(registry.usdc_address(appchain).unwrap(), token);
+///
+/// // A registry entry can be used wherever a `Usdc` implementation is expected.
+/// assert!(registry.chain(appchain).is_usdc(token));
+///
+/// registry.remove(NamedChain::Base);
+/// assert!(matches!(
+///     registry.usdc_address(NamedChain::Base),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Base))
+/// ));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsdcRegistry {
+    entries: BTreeMap<u64, Address>,
+}
+
+impl UsdcRegistry {
+    /// Creates a registry seeded with every built-in USDC address.
+    pub fn new() -> Self {
+        let entries = SupportedUsdcChain::ALL
+            .iter()
+            .map(|chain| (chain.chain_id(), chain.usdc_address()))
+            .collect();
+        Self { entries }
+    }
+
+    /// Creates a registry with no entries.
+    pub fn empty() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the USDC address for `chain`, returning the address it replaces.
+    pub fn insert(&mut self, chain: impl Into<Chain>, address: Address) -> Option<Address> {
+        self.entries.insert(chain.into().id(), address)
+    }
+
+    /// Removes the entry for `chain`, returning its address.
+    pub fn remove(&mut self, chain: impl Into<Chain>) -> Option<Address> {
+        self.entries.remove(&chain.into().id())
+    }
+
+    /// Returns the USDC address for `chain`, if registered.
+    pub fn get(&self, chain: impl Into<Chain>) -> Option<Address> {
+        self.entries.get(&chain.into().id()).copied()
+    }
+
+    /// Returns the USDC address for `chain`.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If a named chain has no entry.
+    /// - [`UsdcError::UnsupportedChainId`]: If an unnamed chain ID has no entry.
+    pub fn usdc_address(&self, chain: impl Into<Chain>) -> Result<Address, UsdcError> {
+        let chain = chain.into();
+        self.get(chain).ok_or_else(|| UsdcError::unsupported(chain))
+    }
+
+    /// Returns `true` if `address` is the registered USDC contract on `chain`.
+    pub fn is_usdc(&self, chain: impl Into<Chain>, address: Address) -> bool {
+        self.get(chain) == Some(address)
+    }
+
+    /// Returns every chain on which `address` is the registered USDC contract.
+    pub fn chains_for_address(&self, address: Address) -> Vec<Chain> {
+        self.iter()
+            .filter(|(_, usdc)| *usdc == address)
+            .map(|(chain, _)| chain)
+            .collect()
+    }
+
+    /// Returns an iterator over the registered `(chain, address)` pairs, ordered by
+    /// chain ID.
+    pub fn iter(&self) -> impl Iterator<Item = (Chain, Address)> + '_ {
+        self.entries
+            .iter()
+            .map(|(id, address)| (Chain::from_id(*id), *address))
+    }
+
+    /// Returns the number of registered chains.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no chains are registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns a view of this registry bound to `chain`, which implements [`Usdc`].
+    pub fn chain(&self, chain: impl Into<Chain>) -> RegistryChain<'_> {
+        RegistryChain {
+            registry: self,
+            chain: chain.into(),
+        }
+    }
+}
+
+impl Default for UsdcRegistry {
+    /// Equivalent to [`UsdcRegistry::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`UsdcRegistry`] bound to a single chain, returned by [`UsdcRegistry::chain`].
+#[derive(Clone, Copy, Debug)]
+pub struct RegistryChain<'a> {
+    registry: &'a UsdcRegistry,
+    chain: Chain,
+}
+
+impl Usdc for RegistryChain<'_> {
+    fn usdc_address(&self) -> Result<Address, UsdcError> {
+        self.registry.usdc_address(self.chain)
+    }
+}
@@ -0,0 +1,136 @@
+use super::{find_deployment, get_json, HttpClient, HttpRequest, RemoteError, RetryPolicy};
+use crate::{parse_address, AsyncUsdc, UsdcError};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+
+/// A resolver backed by a JSON feed of Circle's published USDC deployments.
+///
+/// Circle publishes its contract addresses as documentation rather than as an
+/// API, so there is no default endpoint: pass the URL of a service that mirrors
+/// them, e.g. an internal registry. A `GET` on the endpoint must return a JSON
+/// object whose `data` array lists one entry per deployment, with the EIP-155
+/// chain ID as a number and the token address as a hex string:
+///
+/// ```json
+/// { "data": [{ "chainId": 8453, "address": "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913" }] }
+/// ```
+///
+/// Entries may carry other fields, which are ignored. Use it as a
+/// [`CompositeResolver`](crate::CompositeResolver) fallback to cover chains
+/// missing from the built-in table.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{AsyncUsdc, CircleResolver, HttpClient, HttpRequest, HttpResponse};
+/// use alloy_chains::Chain;
+/// use alloy_primitives::address;
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+///
+/// // A canned client; real applications wrap their HTTP stack instead.
+/// struct Canned;
+///
+/// impl HttpClient for Canned {
+///     type Error = std::io::Error;
+///
+///     async fn get(&self, request: HttpRequest) -> Result<HttpResponse, Self::Error> {
+///         assert!(request.url.starts_with("https://"));
+///         Ok(HttpResponse {
+///             status: 200,
+///             body: r#"{"data": [{"chainId": 12345, "address": "0x1111111111111111111111111111111111111111"}]}"#
+///                 .to_string(),
+///         })
+///     }
+///
+///     async fn sleep(&self, _duration: std::time::Duration) {}
+/// }
+///
+/// let circle = CircleResolver::new(Canned, "https://registry.example.com/usdc/deployments");
+/// let address = block_on(circle.usdc_address(Chain::from_id(12_345))).unwrap();
+/// assert_eq!(address, address!("0x1111111111111111111111111111111111111111"));
+/// assert!(block_on(circle.usdc_address(Chain::from_id(54_321))).is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct CircleResolver<C> {
+    client: C,
+    endpoint: String,
+    api_key: Option<String>,
+    retry: RetryPolicy,
+}
+
+impl<C> CircleResolver<C> {
+    /// Creates a resolver that queries `endpoint` through `client`. The
+    /// endpoint must serve the schema described on [`CircleResolver`].
+    pub fn new(client: C, endpoint: impl Into<String>) -> Self {
+        Self {
+            client,
+            endpoint: endpoint.into(),
+            api_key: None,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Sends `api_key` as a bearer token with every request.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the retry policy; the default is [`RetryPolicy::default`].
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+}
+
+impl<C: HttpClient> CircleResolver<C> {
+    /// Fetches every `(chain, address)` pair published by the endpoint.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::Remote`]: If the request fails or the response is malformed.
+    /// - [`UsdcError::AddressParseError`]: If an entry holds an invalid address.
+    pub async fn deployments(&self) -> Result<Vec<(Chain, Address)>, UsdcError> {
+        let mut request = HttpRequest::get(&self.endpoint);
+        if let Some(api_key) = &self.api_key {
+            request = request.with_header("Authorization", format!("Bearer {api_key}"));
+        }
+        let document = get_json(&self.client, request, &self.retry).await?;
+        let invalid = |reason| RemoteError::InvalidResponse {
+            url: self.endpoint.clone(),
+            reason,
+        };
+
+        let entries = document
+            .get("data")
+            .and_then(|data| data.as_array())
+            .ok_or_else(|| invalid("expected a `data` array"))?;
+        let mut deployments = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let chain_id = entry
+                .get("chainId")
+                .and_then(|id| id.as_u64())
+                .ok_or_else(|| invalid("expected a numeric `chainId` in every entry"))?;
+            let address = entry
+                .get("address")
+                .and_then(|address| address.as_str())
+                .ok_or_else(|| invalid("expected an `address` string in every entry"))?;
+            deployments.push((Chain::from_id(chain_id), parse_address(address)?));
+        }
+        Ok(deployments)
+    }
+}
+
+impl<C: HttpClient + Sync> AsyncUsdc for CircleResolver<C> {
+    async fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        find_deployment(self.deployments().await?, chain)
+    }
+}
@@ -0,0 +1,172 @@
+use super::{find_deployment, get_json, HttpClient, HttpRequest, RemoteError, RetryPolicy};
+use crate::{parse_address, AsyncUsdc, UsdcError};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+use std::collections::BTreeMap;
+
+/// A resolver backed by CoinGecko's `usd-coin` platform mapping.
+///
+/// CoinGecko keys contract addresses by its own platform IDs (`ethereum`,
+/// `arbitrum-one`, ...), so each lookup fetches both the coin's `platforms` map and
+/// the `/asset_platforms` list that ties those IDs to EVM chain IDs. Platforms
+/// without a chain ID (e.g. Solana) and empty or invalid addresses are skipped.
+///
+/// Useful as a secondary [`CompositeResolver`](crate::CompositeResolver) fallback
+/// when Circle's API is unavailable, or for chains Circle does not list.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{AsyncUsdc, CoinGeckoResolver, HttpClient, HttpRequest, HttpResponse};
+/// use alloy_chains::Chain;
+/// use alloy_primitives::address;
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+///
+/// struct Canned;
+///
+/// impl HttpClient for Canned {
+///     type Error = std::io::Error;
+///
+///     async fn get(&self, request: HttpRequest) -> Result<HttpResponse, Self::Error> {
+///         let body = if request.url.contains("/asset_platforms") {
+///             r#"[{"id": "appchain", "chain_identifier": 12345}, {"id": "solana", "chain_identifier": null}, {"id": "brokenchain", "chain_identifier": 54321}]"#
+///         } else {
+///             r#"{"platforms": {"appchain": "0x1111111111111111111111111111111111111111", "solana": "EPjFW...", "brokenchain": "0xbad"}}"#
+///         };
+///         Ok(HttpResponse { status: 200, body: body.to_string() })
+///     }
+///
+///     async fn sleep(&self, _duration: std::time::Duration) {}
+/// }
+///
+/// let coingecko = CoinGeckoResolver::new(Canned);
+/// let address = block_on(coingecko.usdc_address(Chain::from_id(12_345))).unwrap();
+/// assert_eq!(address, address!("0x1111111111111111111111111111111111111111"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CoinGeckoResolver<C> {
+    client: C,
+    base_url: String,
+    api_key: Option<(&'static str, String)>,
+    retry: RetryPolicy,
+}
+
+impl<C> CoinGeckoResolver<C> {
+    /// The public API base URL.
+    pub const PUBLIC_API_URL: &'static str = "https://api.coingecko.com/api/v3";
+
+    /// The Pro API base URL, used by [`CoinGeckoResolver::with_pro_api_key`].
+    pub const PRO_API_URL: &'static str = "https://pro-api.coingecko.com/api/v3";
+
+    /// Creates a resolver that queries the public API through `client`.
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            base_url: Self::PUBLIC_API_URL.to_string(),
+            api_key: None,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Queries `base_url` instead of the public API, e.g. a caching proxy.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sends a Demo plan API key with every request.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(("x-cg-demo-api-key", api_key.into()));
+        self
+    }
+
+    /// Switches to the Pro API and sends `api_key` with every request.
+    pub fn with_pro_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.base_url = Self::PRO_API_URL.to_string();
+        self.api_key = Some(("x-cg-pro-api-key", api_key.into()));
+        self
+    }
+
+    fn request(&self, path: &str) -> HttpRequest {
+        let request = HttpRequest::get(format!("{}{path}", self.base_url.trim_end_matches('/')));
+        match &self.api_key {
+            Some((header, api_key)) => request.with_header(*header, api_key),
+            None => request,
+        }
+    }
+
+    /// Sets the retry policy; the default is [`RetryPolicy::default`].
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+}
+
+impl<C: HttpClient> CoinGeckoResolver<C> {
+    /// Fetches every `(chain, address)` pair CoinGecko lists for USDC on an EVM
+    /// chain. Platforms whose entry is not a valid EVM address are skipped.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::Remote`]: If a request fails or a response is malformed.
+    pub async fn deployments(&self) -> Result<Vec<(Chain, Address)>, UsdcError> {
+        let platforms_request = self.request("/asset_platforms");
+        let platforms_url = platforms_request.url.clone();
+        let platforms = get_json(&self.client, platforms_request, &self.retry).await?;
+        let chain_ids: BTreeMap<&str, u64> = platforms
+            .as_array()
+            .ok_or(RemoteError::InvalidResponse {
+                url: platforms_url,
+                reason: "expected an array of asset platforms",
+            })?
+            .iter()
+            .filter_map(|platform| {
+                let id = platform.get("id")?.as_str()?;
+                let chain_id = platform.get("chain_identifier")?.as_u64()?;
+                Some((id, chain_id))
+            })
+            .collect();
+
+        let coin_request = self.request(
+            "/coins/usd-coin?localization=false&tickers=false&market_data=false\
+             &community_data=false&developer_data=false",
+        );
+        let coin_url = coin_request.url.clone();
+        let coin = get_json(&self.client, coin_request, &self.retry).await?;
+        let addresses = coin
+            .get("platforms")
+            .and_then(|platforms| platforms.as_object())
+            .ok_or(RemoteError::InvalidResponse {
+                url: coin_url,
+                reason: "expected a `platforms` object",
+            })?;
+
+        let mut deployments = Vec::new();
+        for (platform, address) in addresses {
+            let (Some(&chain_id), Some(address)) =
+                (chain_ids.get(platform.as_str()), address.as_str())
+            else {
+                continue;
+            };
+            let Ok(address) = parse_address(address) else {
+                continue;
+            };
+            deployments.push((Chain::from_id(chain_id), address));
+        }
+        Ok(deployments)
+    }
+}
+
+impl<C: HttpClient + Sync> AsyncUsdc for CoinGeckoResolver<C> {
+    async fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        find_deployment(self.deployments().await?, chain)
+    }
+}
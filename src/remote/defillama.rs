@@ -0,0 +1,186 @@
+use super::{get_json, HttpClient, HttpRequest, RemoteError, RetryPolicy};
+use crate::{chain::parse_chain_key, parse_address, AsyncUsdc, UsdcError, UsdcVariant};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+
+/// A USDC contract listed in DefiLlama's token mappings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DefiLlamaDeployment {
+    /// The chain the contract is deployed on.
+    pub chain: Chain,
+    /// The contract address.
+    pub address: Address,
+    /// The symbol DefiLlama lists for the contract, e.g. `USDC` or `USDC.e`.
+    pub symbol: String,
+    /// Whether the contract is native or bridged, when the symbol tells; `None`
+    /// if DefiLlama does not distinguish it.
+    pub variant: Option<UsdcVariant>,
+}
+
+/// A resolver backed by DefiLlama's token mappings.
+///
+/// The mapping document is an object keyed by chain name (or numeric chain ID),
+/// each holding an object of contract address to token metadata. Contracts whose
+/// `to` field is `coingecko#usd-coin` are USDC:
+///
+/// ```json
+/// { "arbitrum": { "0xff970a61a04b1ca14834a43f5de4533ebddb5cc8": { "to": "coingecko#usd-coin", "symbol": "USDC.e", "decimals": 6 } } }
+/// ```
+///
+/// Bridged representations are recognized by their symbol (`USDC.e`, `USDbC`,
+/// `axlUSDC`, ...), so [`DefiLlamaDeployment::variant`] is only set where the
+/// classification is visible. When a chain has several USDC contracts,
+/// [`AsyncUsdc::usdc_address`] prefers one that is not known to be bridged.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::{AsyncUsdc, DefiLlamaResolver, HttpClient, HttpRequest, HttpResponse, UsdcVariant};
+/// use alloy_chains::{Chain, NamedChain};
+/// use alloy_primitives::address;
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+///
+/// struct Canned;
+///
+/// impl HttpClient for Canned {
+///     type Error = std::io::Error;
+///
+///     async fn get(&self, _request: HttpRequest) -> Result<HttpResponse, Self::Error> {
+///         let body = r#"{"gnosis": {
+///             "0x2a22f9c3b484c3629090feed35f17ff8f88f76f0": {"to": "coingecko#usd-coin", "symbol": "USDC.e"},
+///             "0xddafbb505ad214d7b80b1f830fccc89b60fb7a83": {"to": "coingecko#usd-coin", "symbol": "USDC"},
+///             "0xe91d153e0b41518a2ce8dd3d7944fa863463a97d": {"to": "coingecko#xdai", "symbol": "WXDAI"},
+///             "0xbad": {"to": "coingecko#usd-coin", "symbol": "USDC"}
+///         }}"#;
+///         Ok(HttpResponse { status: 200, body: body.to_string() })
+///     }
+///
+///     async fn sleep(&self, _duration: std::time::Duration) {}
+/// }
+///
+/// let llama = DefiLlamaResolver::new(Canned);
+/// let deployments = block_on(llama.deployments()).unwrap();
+/// assert_eq!(deployments.len(), 2);
+/// assert_eq!(deployments[0].variant, Some(UsdcVariant::BridgedUsdcE));
+///
+/// let address = block_on(llama.usdc_address(NamedChain::Gnosis.into())).unwrap();
+/// assert_eq!(address, address!("0xDDAfbb505ad214D7b80b1f830fcCc89B60fb7A83"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DefiLlamaResolver<C> {
+    client: C,
+    url: String,
+    retry: RetryPolicy,
+}
+
+impl<C> DefiLlamaResolver<C> {
+    /// The default token mapping document.
+    pub const DEFAULT_URL: &'static str = "https://raw.githubusercontent.com/DefiLlama/defillama-server/master/coins/src/adapters/tokenMapping.json";
+
+    /// The `to` value that marks a contract as USDC.
+    const USDC_ID: &'static str = "coingecko#usd-coin";
+
+    /// Creates a resolver that fetches [`DefiLlamaResolver::DEFAULT_URL`] through
+    /// `client`.
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            url: Self::DEFAULT_URL.to_string(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Fetches the mapping from `url` instead of the default, e.g. a pinned
+    /// revision.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Sets the retry policy; the default is [`RetryPolicy::default`].
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+}
+
+impl<C: HttpClient> DefiLlamaResolver<C> {
+    /// Fetches every USDC contract in the mapping. Chains whose names cannot be
+    /// resolved to a chain ID, and entries not keyed by an EVM address, are
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::Remote`]: If the request fails or the response is malformed.
+    pub async fn deployments(&self) -> Result<Vec<DefiLlamaDeployment>, UsdcError> {
+        let document = get_json(&self.client, HttpRequest::get(&self.url), &self.retry).await?;
+        let chains = document
+            .as_object()
+            .ok_or_else(|| RemoteError::InvalidResponse {
+                url: self.url.clone(),
+                reason: "expected an object keyed by chain",
+            })?;
+
+        let mut deployments = Vec::new();
+        for (key, tokens) in chains {
+            let (Ok(chain), Some(tokens)) = (parse_chain_key(key), tokens.as_object()) else {
+                continue;
+            };
+            for (address, token) in tokens {
+                if token.get("to").and_then(|to| to.as_str()) != Some(Self::USDC_ID) {
+                    continue;
+                }
+                let Ok(address) = parse_address(address) else {
+                    continue;
+                };
+                let symbol = token
+                    .get("symbol")
+                    .and_then(|symbol| symbol.as_str())
+                    .unwrap_or("USDC");
+                deployments.push(DefiLlamaDeployment {
+                    chain,
+                    address,
+                    symbol: symbol.to_string(),
+                    variant: classify(symbol),
+                });
+            }
+        }
+        Ok(deployments)
+    }
+}
+
+impl<C: HttpClient + Sync> AsyncUsdc for DefiLlamaResolver<C> {
+    async fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        let candidates: Vec<_> = self
+            .deployments()
+            .await?
+            .into_iter()
+            .filter(|deployment| deployment.chain.id() == chain.id())
+            .collect();
+        candidates
+            .iter()
+            .find(|deployment| deployment.variant.is_none_or(|variant| variant.is_native()))
+            .or_else(|| candidates.first())
+            .map(|deployment| deployment.address)
+            .ok_or_else(|| UsdcError::unsupported(chain))
+    }
+}
+
+/// Infers the variant from the symbols bridges conventionally use.
+fn classify(symbol: &str) -> Option<UsdcVariant> {
+    match symbol {
+        "USDC.e" | "USDbC" | "USDC.E" => Some(UsdcVariant::BridgedUsdcE),
+        "axlUSDC" => Some(UsdcVariant::AxelarWrapped),
+        "USDCet" | "USDCso" | "USDCbs" | "USDCpo" | "USDCav" => Some(UsdcVariant::WormholeWrapped),
+        "anyUSDC" | "multiUSDC" => Some(UsdcVariant::MultichainBridged),
+        _ => None,
+    }
+}
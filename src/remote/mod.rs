@@ -0,0 +1,255 @@
+//! Resolvers backed by remote data sources, and the HTTP abstraction they share.
+//!
+//! This crate does not ship an HTTP stack. Remote resolvers are generic over an
+//! [`HttpClient`], a small trait that applications implement on top of whichever
+//! client they already use (`reqwest`, `hyper`, a WASI host call, ...).
+
+// The shared helpers are used by different resolver features.
+#![cfg_attr(
+    not(all(
+        feature = "remote-circle",
+        feature = "remote-coingecko",
+        feature = "remote-defillama"
+    )),
+    allow(dead_code)
+)]
+
+#[cfg(feature = "remote-circle")]
+mod circle;
+#[cfg(feature = "remote-coingecko")]
+mod coingecko;
+#[cfg(feature = "remote-defillama")]
+mod defillama;
+mod retry;
+
+#[cfg(feature = "remote-circle")]
+pub use circle::CircleResolver;
+#[cfg(feature = "remote-coingecko")]
+pub use coingecko::CoinGeckoResolver;
+#[cfg(feature = "remote-defillama")]
+pub use defillama::{DefiLlamaDeployment, DefiLlamaResolver};
+pub use retry::RetryPolicy;
+
+use crate::{json, JsonError, UsdcError};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+use core::future::Future;
+use std::{error::Error as StdError, time::Duration};
+use thiserror::Error;
+
+/// An HTTP GET request issued by a remote resolver.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// The absolute URL to fetch.
+    pub url: String,
+    /// Additional request headers, e.g. `Authorization`.
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    /// Creates a request for `url` with no extra headers.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+        }
+    }
+
+    /// Adds a request header.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// The status and body of an HTTP response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The response body, decoded as UTF-8.
+    pub body: String,
+}
+
+/// A minimal async HTTP client used by the remote resolvers.
+///
+/// Implementations perform the request and provide a timer; status handling,
+/// retries and decoding are done by the resolvers. An adapter over `reqwest` on
+/// tokio is a few lines:
+///
+/// ```rust,ignore
+/// impl HttpClient for MyClient {
+///     type Error = reqwest::Error;
+///
+///     async fn get(&self, request: HttpRequest) -> Result<HttpResponse, Self::Error> {
+///         let mut builder = self.0.get(&request.url);
+///         for (name, value) in &request.headers {
+///             builder = builder.header(name, value);
+///         }
+///         let response = builder.send().await?;
+///         let status = response.status().as_u16();
+///         Ok(HttpResponse { status, body: response.text().await? })
+///     }
+///
+///     async fn sleep(&self, duration: Duration) {
+///         tokio::time::sleep(duration).await
+///     }
+/// }
+/// ```
+pub trait HttpClient {
+    /// The error returned when a request cannot be completed, e.g. on DNS, TLS or
+    /// connection failures.
+    type Error: StdError + Send + Sync + 'static;
+
+    /// Performs a GET request.
+    fn get(
+        &self,
+        request: HttpRequest,
+    ) -> impl Future<Output = Result<HttpResponse, Self::Error>> + Send;
+
+    /// Waits for `duration` between retries and polls.
+    ///
+    /// Implement this with the timer of the runtime the client runs on, e.g.
+    /// `tokio::time::sleep`, so that waiting never blocks an executor thread.
+    /// Blocking clients can call `std::thread::sleep` and return a ready future.
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send;
+}
+
+impl<C: HttpClient + ?Sized> HttpClient for &C {
+    type Error = C::Error;
+
+    fn get(
+        &self,
+        request: HttpRequest,
+    ) -> impl Future<Output = Result<HttpResponse, Self::Error>> + Send {
+        (**self).get(request)
+    }
+
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
+        (**self).sleep(duration)
+    }
+}
+
+/// Represents errors that can occur when querying a remote data source.
+#[derive(Error, Debug)]
+pub enum RemoteError {
+    /// The request could not be completed.
+    #[error("Request to '{url}' failed: {source}")]
+    Transport {
+        /// The requested URL.
+        url: String,
+        /// The error reported by the [`HttpClient`].
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+
+    /// The server answered with a non-success status code.
+    #[error("Request to '{url}' returned HTTP status {status}")]
+    Status {
+        /// The requested URL.
+        url: String,
+        /// The HTTP status code.
+        status: u16,
+    },
+
+    /// The response body is not valid JSON.
+    #[error("Invalid JSON from '{url}': {source}")]
+    Json {
+        /// The requested URL.
+        url: String,
+        /// The underlying parse error.
+        #[source]
+        source: JsonError,
+    },
+
+    /// The response is valid JSON but does not have the expected shape.
+    #[error("Unexpected response from '{url}': {reason}")]
+    InvalidResponse {
+        /// The requested URL.
+        url: String,
+        /// What was missing or malformed.
+        reason: &'static str,
+    },
+
+    /// Every attempt allowed by the [`RetryPolicy`] failed.
+    #[error("Giving up after {attempts} attempts: {last}")]
+    RetriesExhausted {
+        /// The number of attempts made.
+        attempts: u32,
+        /// The error from the final attempt.
+        #[source]
+        last: Box<RemoteError>,
+    },
+}
+
+impl RemoteError {
+    /// Returns `true` if the failure may be transient: a transport error, or an
+    /// HTTP `429` or `5xx` status.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Transport { .. } => true,
+            Self::Status { status, .. } => *status == 429 || (500..600).contains(status),
+            Self::Json { .. } | Self::InvalidResponse { .. } | Self::RetriesExhausted { .. } => {
+                false
+            }
+        }
+    }
+}
+
+/// Performs `request` under `policy` and parses a successful response as JSON.
+pub(crate) async fn get_json<C: HttpClient>(
+    client: &C,
+    request: HttpRequest,
+    policy: &RetryPolicy,
+) -> Result<json::Value, RemoteError> {
+    let mut attempt = 1;
+    loop {
+        match try_get_json(client, request.clone()).await {
+            Ok(document) => return Ok(document),
+            Err(err) if err.is_retryable() && attempt < policy.max_attempts() => {
+                client.sleep(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+            Err(err) if err.is_retryable() && attempt > 1 => {
+                return Err(RemoteError::RetriesExhausted {
+                    attempts: attempt,
+                    last: Box::new(err),
+                })
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+async fn try_get_json<C: HttpClient>(
+    client: &C,
+    request: HttpRequest,
+) -> Result<json::Value, RemoteError> {
+    let url = request.url.clone();
+    let response = client
+        .get(request)
+        .await
+        .map_err(|source| RemoteError::Transport {
+            url: url.clone(),
+            source: Box::new(source),
+        })?;
+    if !(200..300).contains(&response.status) {
+        return Err(RemoteError::Status {
+            url,
+            status: response.status,
+        });
+    }
+    json::parse(&response.body).map_err(|source| RemoteError::Json { url, source })
+}
+
+/// Picks the address for `chain` out of a resolver's full deployment list.
+pub(crate) fn find_deployment(
+    deployments: Vec<(Chain, Address)>,
+    chain: Chain,
+) -> Result<Address, UsdcError> {
+    deployments
+        .into_iter()
+        .find(|(deployed, _)| deployed.id() == chain.id())
+        .map(|(_, address)| address)
+        .ok_or_else(|| UsdcError::unsupported(chain))
+}
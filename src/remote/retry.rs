@@ -0,0 +1,106 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// How remote resolvers retry failed requests.
+///
+/// Transport failures, `429 Too Many Requests` and `5xx` responses are retried
+/// with exponential backoff: the n-th retry waits `initial_backoff * 2^(n-1)`,
+/// capped at `max_backoff`. With jitter enabled (the default), each wait is drawn
+/// uniformly from zero to that bound, which spreads out clients that failed at
+/// the same moment. Other errors are returned immediately.
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(4)
+///     .with_initial_backoff(Duration::from_millis(100))
+///     .with_max_backoff(Duration::from_millis(250))
+///     .with_jitter(false);
+/// assert_eq!(policy.backoff(1), Duration::from_millis(100));
+/// assert_eq!(policy.backoff(2), Duration::from_millis(200));
+/// assert_eq!(policy.backoff(3), Duration::from_millis(250));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that makes up to `max_attempts` attempts in total, with a
+    /// 200 ms initial backoff, a 5 s cap and jitter.
+    pub const fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+
+    /// A policy that never retries.
+    pub const fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the wait before the first retry.
+    pub const fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the longest wait between two attempts.
+    pub const fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Enables or disables jitter.
+    pub const fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns the total number of attempts, including the first.
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the upper bound of the wait before the `retry`-th retry, counting
+    /// from 1, before jitter is applied.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Returns the wait before the `retry`-th retry, with jitter applied.
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let backoff = self.backoff(retry);
+        if !self.jitter {
+            return backoff;
+        }
+        // `RandomState` is randomly keyed per instance, which is all the
+        // randomness jitter needs.
+        let random = RandomState::new().build_hasher().finish();
+        backoff.mul_f64(random as f64 / u64::MAX as f64)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts with the backoff described on [`RetryPolicy::new`].
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
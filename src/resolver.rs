@@ -0,0 +1,225 @@
+use crate::{UsdcError, UsdcRegistry};
+use alloc::{boxed::Box, vec::Vec};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+use core::future::{self, Future};
+
+/// A source of USDC addresses that can be queried for any chain.
+///
+/// Where [`Usdc`](crate::Usdc) is implemented by chain identifiers, a resolver is
+/// a lookup service: the chain is an argument. [`UsdcRegistry`] is the built-in
+/// resolver; remote or custom sources implement this trait to plug into a
+/// [`CompositeResolver`].
+pub trait UsdcResolver {
+    /// Returns the USDC address for `chain`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UsdcError::UnsupportedChain`] or [`UsdcError::UnsupportedChainId`]
+    /// if the resolver does not know the chain, or another [`UsdcError`] if the
+    /// lookup itself failed.
+    fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError>;
+}
+
+impl UsdcResolver for UsdcRegistry {
+    fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        UsdcRegistry::usdc_address(self, chain)
+    }
+}
+
+impl<R: UsdcResolver + ?Sized> UsdcResolver for &R {
+    fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        (**self).usdc_address(chain)
+    }
+}
+
+impl<R: UsdcResolver + ?Sized> UsdcResolver for Box<R> {
+    fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        (**self).usdc_address(chain)
+    }
+}
+
+/// A resolver that consults a [`UsdcRegistry`] first and falls back to other
+/// resolvers for chains the registry does not know.
+///
+/// Fallbacks are tried in the order they were added. The first address found is
+/// returned; if every fallback fails, the error from the last one is returned.
+/// Errors other than an unsupported chain from the registry are returned without
+/// consulting the fallbacks.
+///
+/// The resolver implements [`UsdcResolver`] when its fallbacks do, and
+/// [`AsyncUsdc`] when its fallbacks are async.
+///
+/// Use `Box<dyn UsdcResolver>` as the fallback type to mix different resolvers.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{CompositeResolver, UsdcRegistry, UsdcResolver, BASE_USDC};
+/// use alloy_chains::{Chain, NamedChain};
+/// use alloy_primitives::address;
+///
+/// let token = address!("0x1111111111111111111111111111111111111111");
+/// let mut appchains = UsdcRegistry::empty();
+/// appchains.insert(Chain::from_id(12_345), token);
+///
+/// let resolver = CompositeResolver::new().with_fallback(appchains);
+/// assert_eq!(resolver.usdc_address(NamedChain::Base.into()).unwrap(), BASE_USDC);
+/// assert_eq!(resolver.usdc_address(Chain::from_id(12_345)).unwrap(), token);
+/// assert!(resolver.usdc_address(Chain::from_id(54_321)).is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompositeResolver<R> {
+    registry: UsdcRegistry,
+    fallbacks: Vec<R>,
+}
+
+impl<R> CompositeResolver<R> {
+    /// Creates a resolver backed by the built-in registry, with no fallbacks.
+    pub fn new() -> Self {
+        Self::with_registry(UsdcRegistry::new())
+    }
+
+    /// Creates a resolver backed by `registry`, with no fallbacks.
+    pub fn with_registry(registry: UsdcRegistry) -> Self {
+        Self {
+            registry,
+            fallbacks: Vec::new(),
+        }
+    }
+
+    /// Appends a fallback resolver.
+    pub fn with_fallback(mut self, fallback: R) -> Self {
+        self.fallbacks.push(fallback);
+        self
+    }
+
+    /// Returns the primary registry.
+    pub fn registry(&self) -> &UsdcRegistry {
+        &self.registry
+    }
+
+    /// Returns the fallback resolvers, in the order they are consulted.
+    pub fn fallbacks(&self) -> &[R] {
+        &self.fallbacks
+    }
+}
+
+impl<R> Default for CompositeResolver<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: UsdcResolver> UsdcResolver for CompositeResolver<R> {
+    fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        let mut result = self.registry.usdc_address(chain);
+        if !is_unsupported(&result) {
+            return result;
+        }
+        for fallback in &self.fallbacks {
+            result = UsdcResolver::usdc_address(fallback, chain);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+}
+
+/// The async counterpart to [`UsdcResolver`], for resolvers backed by HTTP APIs
+/// or RPC nodes.
+///
+/// The returned future is `Send` so resolvers can be used from multi-threaded
+/// runtimes. In-memory resolvers such as [`UsdcRegistry`] return an
+/// already-completed future.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{AsyncUsdc, UsdcError, UsdcRegistry, BASE_USDC};
+/// use alloy_chains::{Chain, NamedChain};
+/// use alloy_primitives::Address;
+/// # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     let mut future = std::pin::pin!(future);
+/// #     loop {
+/// #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+/// #             return output;
+/// #         }
+/// #     }
+/// # }
+///
+/// async fn lookup(resolver: &impl AsyncUsdc, chain: Chain) -> Result<Address, UsdcError> {
+///     resolver.usdc_address(chain).await
+/// }
+///
+/// let registry = UsdcRegistry::new();
+/// let address = block_on(lookup(&registry, NamedChain::Base.into())).unwrap();
+/// assert_eq!(address, BASE_USDC);
+/// ```
+pub trait AsyncUsdc {
+    /// Resolves the USDC address for `chain`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UsdcError::UnsupportedChain`] or [`UsdcError::UnsupportedChainId`]
+    /// if the resolver does not know the chain, or another [`UsdcError`] if the
+    /// lookup itself failed.
+    fn usdc_address(&self, chain: Chain)
+        -> impl Future<Output = Result<Address, UsdcError>> + Send;
+}
+
+impl AsyncUsdc for UsdcRegistry {
+    fn usdc_address(
+        &self,
+        chain: Chain,
+    ) -> impl Future<Output = Result<Address, UsdcError>> + Send {
+        future::ready(UsdcRegistry::usdc_address(self, chain))
+    }
+}
+
+impl<R: AsyncUsdc + ?Sized> AsyncUsdc for &R {
+    fn usdc_address(
+        &self,
+        chain: Chain,
+    ) -> impl Future<Output = Result<Address, UsdcError>> + Send {
+        (**self).usdc_address(chain)
+    }
+}
+
+impl<R: AsyncUsdc + ?Sized> AsyncUsdc for Box<R> {
+    fn usdc_address(
+        &self,
+        chain: Chain,
+    ) -> impl Future<Output = Result<Address, UsdcError>> + Send {
+        (**self).usdc_address(chain)
+    }
+}
+
+impl<R: AsyncUsdc + Sync> AsyncUsdc for CompositeResolver<R> {
+    async fn usdc_address(&self, chain: Chain) -> Result<Address, UsdcError> {
+        let mut result = self.registry.usdc_address(chain);
+        if !is_unsupported(&result) {
+            return result;
+        }
+        for fallback in &self.fallbacks {
+            result = AsyncUsdc::usdc_address(fallback, chain).await;
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+}
+
+/// Returns `true` if `result` means the resolver does not know the chain, which
+/// is the only failure that [`CompositeResolver`] falls back on.
+fn is_unsupported(result: &Result<Address, UsdcError>) -> bool {
+    matches!(
+        result,
+        Err(UsdcError::UnsupportedChain(_) | UsdcError::UnsupportedChainId(_))
+    )
+}
@@ -0,0 +1,19 @@
+use alloy_primitives::Address;
+use core::fmt::Display;
+use serde::Serializer;
+
+/// Serializes an address as an EIP-55 checksummed hex string.
+pub(crate) fn checksummed<S: Serializer>(
+    address: &Address,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(address.to_checksum_buffer(None).as_str())
+}
+
+/// Serializes a value through its [`Display`] implementation.
+pub(crate) fn display<T: Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
@@ -0,0 +1,378 @@
+//! Serving a [`UsdcRegistry`] over HTTP as JSON.
+
+use crate::{chain::parse_chain_key, json::Value, parse_address, UsdcRegistry};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+use std::{
+    io::{self, BufRead, BufReader, Read, Take, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        mpsc::{self, SyncSender, TrySendError},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A JSON response produced by [`UsdcService::handle`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The JSON body.
+    pub body: String,
+}
+
+impl ServiceResponse {
+    fn ok(body: Value) -> Self {
+        Self {
+            status: 200,
+            body: body.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: Value::object([("error", Value::from(message.into()))]).to_string(),
+        }
+    }
+}
+
+/// A read-only HTTP API over a [`UsdcRegistry`], so services outside Rust can
+/// consume the same data.
+///
+/// | Route | Response |
+/// | --- | --- |
+/// | `GET /usdc/{chain}` | The entry for a chain, given by ID or name |
+/// | `GET /tokens` | Every entry, ordered by chain ID |
+/// | `GET /reverse/{address}` | The entries whose address is `address` |
+///
+/// Entries are objects with `chain_id`, `chain` (the chain name, or `null` for
+/// unnamed chains) and `address` fields. Errors are objects with an `error`
+/// message: 400 for malformed input, 404 for unknown chains and routes.
+///
+/// [`handle`](Self::handle) is independent of any HTTP stack, so the service can
+/// be mounted in an existing server; [`serve`](Self::serve) runs a small
+/// standalone one.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::UsdcService;
+///
+/// let service = UsdcService::new();
+///
+/// let response = service.handle("GET", "/usdc/base");
+/// assert_eq!(response.status, 200);
+/// assert_eq!(
+///     response.body,
+///     r#"{"chain_id":8453,"chain":"base","address":"0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"}"#
+/// );
+///
+/// assert_eq!(service.handle("GET", "/usdc/gnosis").status, 404);
+/// assert_eq!(service.handle("POST", "/tokens").status, 405);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct UsdcService {
+    registry: UsdcRegistry,
+}
+
+impl UsdcService {
+    /// Creates a service backed by the built-in registry.
+    pub fn new() -> Self {
+        Self::with_registry(UsdcRegistry::new())
+    }
+
+    /// Creates a service backed by `registry`.
+    pub fn with_registry(registry: UsdcRegistry) -> Self {
+        Self { registry }
+    }
+
+    /// Returns the registry being served.
+    pub fn registry(&self) -> &UsdcRegistry {
+        &self.registry
+    }
+
+    /// Routes a request. Any query string on `path` is ignored.
+    pub fn handle(&self, method: &str, path: &str) -> ServiceResponse {
+        if method != "GET" {
+            return ServiceResponse::error(405, "only GET is supported");
+        }
+        let path = path.split_once('?').map_or(path, |(path, _)| path);
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match segments[..] {
+            ["usdc", chain] => self.usdc(chain),
+            ["tokens"] => ServiceResponse::ok(Value::Array(
+                self.registry
+                    .iter()
+                    .map(|(chain, address)| entry(chain, address))
+                    .collect(),
+            )),
+            ["reverse", address] => self.reverse(address),
+            _ => ServiceResponse::error(404, "not found"),
+        }
+    }
+
+    fn usdc(&self, key: &str) -> ServiceResponse {
+        let chain = match parse_chain_key(key) {
+            Ok(chain) => chain,
+            Err(err) => return ServiceResponse::error(400, err.to_string()),
+        };
+        match self.registry.usdc_address(chain) {
+            Ok(address) => ServiceResponse::ok(entry(chain, address)),
+            Err(err) => ServiceResponse::error(404, err.to_string()),
+        }
+    }
+
+    fn reverse(&self, address: &str) -> ServiceResponse {
+        let address = match parse_address(address) {
+            Ok(address) => address,
+            Err(err) => return ServiceResponse::error(400, err.to_string()),
+        };
+        ServiceResponse::ok(Value::Array(
+            self.registry
+                .chains_for_address(address)
+                .into_iter()
+                .map(|chain| entry(chain, address))
+                .collect(),
+        ))
+    }
+
+    /// Serves HTTP/1.1 requests on `listener`. Connections are handled by a
+    /// fixed pool of 16 threads and closed after one response.
+    ///
+    /// Up to 16 further connections wait for a free worker; beyond
+    /// that, new connections are answered with 503 and closed. Each request
+    /// must send its request line and headers within 10 seconds and 8 KiB, or
+    /// it is answered with 408 or 431.
+    ///
+    /// ```no_run
+    /// use std::net::TcpListener;
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:8080")?;
+    /// usdshe::UsdcService::new().serve(listener)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`TcpListener::accept`] if the listener itself is
+    /// unusable. Transient accept errors, such as a connection reset before it
+    /// was accepted or running out of file descriptors, are logged to stderr
+    /// and retried. Errors on individual connections are ignored.
+    pub fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        let (sender, receiver) = mpsc::sync_channel::<TcpStream>(WORKERS);
+        let receiver = Mutex::new(receiver);
+        thread::scope(|scope| {
+            for _ in 0..WORKERS {
+                scope.spawn(|| loop {
+                    // The lock is released as soon as a connection is taken.
+                    let next = receiver.lock().map(|receiver| receiver.recv());
+                    match next {
+                        Ok(Ok(stream)) => {
+                            let _ = self.respond(stream);
+                        }
+                        _ => return,
+                    }
+                });
+            }
+            let result = accept_loop(&listener, &sender);
+            // Dropping the sender lets idle workers exit.
+            drop(sender);
+            result
+        })
+    }
+
+    fn respond(&self, mut stream: TcpStream) -> io::Result<()> {
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        let response = match read_request_line(&stream) {
+            Ok(request_line) => {
+                let mut parts = request_line.split_whitespace();
+                match (parts.next(), parts.next()) {
+                    (Some(method), Some(path)) => self.handle(method, path),
+                    _ => ServiceResponse::error(400, "malformed request line"),
+                }
+            }
+            Err(HeadError::Io(err)) => return Err(err),
+            Err(HeadError::Rejected(response)) => response,
+        };
+        write_response(&mut stream, &response)
+    }
+}
+
+/// The number of threads [`UsdcService::serve`] handles connections on, and
+/// the number of accepted connections that may wait for one.
+const WORKERS: usize = 16;
+
+/// How long a connection may take to send its request line and headers.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a client may take to read a response.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The most bytes a request line and its headers may take together.
+const MAX_HEAD_BYTES: u64 = 8 * 1024;
+
+/// The most header lines a request may send.
+const MAX_HEADERS: usize = 64;
+
+/// How long to wait before accepting again after a transient error, so that
+/// e.g. running out of file descriptors does not spin the accept loop.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Accepts connections and hands them to the workers until the listener fails
+/// with a non-transient error.
+fn accept_loop(listener: &TcpListener, workers: &SyncSender<TcpStream>) -> io::Result<()> {
+    loop {
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(err) if is_fatal_accept_error(&err) => return Err(err),
+            Err(err) => {
+                eprintln!("usdshe: failed to accept a connection: {err}");
+                thread::sleep(ACCEPT_BACKOFF);
+                continue;
+            }
+        };
+        match workers.try_send(stream) {
+            Ok(()) => {}
+            Err(TrySendError::Full(mut stream)) => {
+                // Never block the accept loop on a client: the short response
+                // fits in the socket buffer, and is dropped if it does not.
+                let busy = ServiceResponse::error(503, "server busy");
+                let _ = stream.set_nonblocking(true);
+                let _ = write_response(&mut stream, &busy);
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                return Err(io::Error::other("every server worker has stopped"));
+            }
+        }
+    }
+}
+
+/// Returns `true` if `err` means the listener cannot accept any further
+/// connections, rather than that one connection failed.
+fn is_fatal_accept_error(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::InvalidInput | io::ErrorKind::NotConnected | io::ErrorKind::Unsupported
+    )
+}
+
+/// Why a request head could not be read.
+enum HeadError {
+    /// The connection failed or closed early.
+    Io(io::Error),
+    /// The request broke a limit and is answered with this response.
+    Rejected(ServiceResponse),
+}
+
+impl From<io::Error> for HeadError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => {
+                Self::Rejected(ServiceResponse::error(408, "request timed out"))
+            }
+            _ => Self::Io(err),
+        }
+    }
+}
+
+/// Reads the request line and drains the headers, which no route reads,
+/// within [`READ_TIMEOUT`], [`MAX_HEAD_BYTES`] and [`MAX_HEADERS`].
+fn read_request_line(stream: &TcpStream) -> Result<String, HeadError> {
+    let deadline = Instant::now() + READ_TIMEOUT;
+    let mut reader = BufReader::new(stream.take(MAX_HEAD_BYTES));
+    let request_line = read_line(&mut reader, stream, deadline)?;
+    for _ in 0..MAX_HEADERS {
+        if read_line(&mut reader, stream, deadline)?
+            .trim_ascii_end()
+            .is_empty()
+        {
+            return String::from_utf8(request_line).map_err(|_| {
+                HeadError::Rejected(ServiceResponse::error(400, "malformed request line"))
+            });
+        }
+    }
+    Err(HeadError::Rejected(ServiceResponse::error(
+        431,
+        "too many request headers",
+    )))
+}
+
+/// Reads one line, including its `\n`. Each read waits only for the time left
+/// until `deadline`, so a client trickling bytes is still cut off.
+fn read_line(
+    reader: &mut BufReader<Take<&TcpStream>>,
+    stream: &TcpStream,
+    deadline: Instant,
+) -> Result<Vec<u8>, HeadError> {
+    let mut line = Vec::new();
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::Error::from(io::ErrorKind::TimedOut).into());
+        }
+        stream.set_read_timeout(Some(remaining))?;
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            if reader.get_ref().limit() == 0 {
+                return Err(HeadError::Rejected(ServiceResponse::error(
+                    431,
+                    "request head too large",
+                )));
+            }
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        match buffer.iter().position(|b| *b == b'\n') {
+            Some(end) => {
+                line.extend_from_slice(&buffer[..=end]);
+                reader.consume(end + 1);
+                return Ok(line);
+            }
+            None => {
+                let len = buffer.len();
+                line.extend_from_slice(buffer);
+                reader.consume(len);
+            }
+        }
+    }
+}
+
+fn write_response(stream: &mut TcpStream, response: &ServiceResponse) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+fn entry(chain: Chain, address: Address) -> Value {
+    let name = match chain.named() {
+        Some(named) => Value::from(named.to_string()),
+        None => Value::Null,
+    };
+    Value::object([
+        ("chain_id", Value::from(chain.id())),
+        ("chain", name),
+        ("address", Value::from(address.to_checksum(None))),
+    ])
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
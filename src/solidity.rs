@@ -0,0 +1,83 @@
+//! Rendering a registry as a Solidity library.
+
+use crate::UsdcRegistry;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use alloy_chains::Chain;
+use core::fmt::Write;
+
+impl UsdcRegistry {
+    /// Renders the registry as `UsdcAddresses.sol`, a Solidity library with one
+    /// constant per entry and a lookup by chain ID, so contracts can embed the
+    /// same data as Rust code.
+    ///
+    /// `usdc(uint256 chainId)` reverts with `UnsupportedChain(chainId)` for
+    /// chains without an entry, and `usdc()` looks up `block.chainid`.
+    /// Constants are named after the chain, e.g. `ARBITRUM_SEPOLIA`, or
+    /// `CHAIN_<id>` for unnamed chain IDs.
+    ///
+    /// ## Examples
+    ///
+    #[cfg_attr(feature = "ethereum", doc = "```rust")]
+    #[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+    /// use usdshe::UsdcRegistry;
+    /// use alloy_chains::Chain;
+    /// use alloy_primitives::address;
+    ///
+    /// let mut registry = UsdcRegistry::new();
+    /// registry.insert(
+    ///     Chain::from_id(12_345),
+    ///     address!("0x1111111111111111111111111111111111111111"),
+    /// );
+    ///
+    /// let source = registry.to_solidity_library();
+    /// assert!(source.contains("library UsdcAddresses {"));
+    /// assert!(source.contains("function usdc(uint256 chainId) internal pure returns (address)"));
+    /// assert!(source.contains(
+    ///     "address internal constant MAINNET = 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48;"
+    /// ));
+    /// assert!(source.contains("if (chainId == 12345) return CHAIN_12345;"));
+    /// ```
+    pub fn to_solidity_library(&self) -> String {
+        let mut out = format!(
+            "// SPDX-License-Identifier: MIT\n// Generated by usdshe {}. Do not edit by hand.\n\
+             pragma solidity ^0.8.4;\n\n",
+            env!("CARGO_PKG_VERSION")
+        );
+        out.push_str("/// @notice USDC contract addresses keyed by EIP-155 chain ID.\n");
+        out.push_str("library UsdcAddresses {\n");
+        out.push_str("    error UnsupportedChain(uint256 chainId);\n\n");
+        for (chain, address) in self.iter() {
+            let _ = writeln!(
+                out,
+                "    address internal constant {} = {address};",
+                constant_name(chain)
+            );
+        }
+        out.push_str("\n    /// @notice Returns the USDC address on `chainId`.\n");
+        out.push_str("    function usdc(uint256 chainId) internal pure returns (address) {\n");
+        for (chain, _) in self.iter() {
+            let _ = writeln!(
+                out,
+                "        if (chainId == {}) return {};",
+                chain.id(),
+                constant_name(chain)
+            );
+        }
+        out.push_str("        revert UnsupportedChain(chainId);\n    }\n\n");
+        out.push_str("    /// @notice Returns the USDC address on the current chain.\n");
+        out.push_str("    function usdc() internal view returns (address) {\n");
+        out.push_str("        return usdc(block.chainid);\n    }\n}\n");
+        out
+    }
+}
+
+/// The constant name for a chain, e.g. `ARBITRUM_SEPOLIA` or `CHAIN_12345`.
+fn constant_name(chain: Chain) -> String {
+    match chain.named() {
+        Some(named) => named.to_string().to_uppercase().replace('-', "_"),
+        None => format!("CHAIN_{}", chain.id()),
+    }
+}
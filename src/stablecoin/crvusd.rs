@@ -0,0 +1,27 @@
+//! Curve's crvUSD addresses. L2 deployments are bridged from Ethereum.
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+
+/// crvUSD on Ethereum mainnet.
+pub const ETHEREUM_CRVUSD: Address = address!("0xf939E0A03FB07F59A73314E73794Be0E57ac1b4E");
+/// crvUSD on Arbitrum One.
+pub const ARBITRUM_CRVUSD: Address = address!("0x498Bf2B1e120FeD3ad3D42EA2165E9b73f99C1e5");
+/// crvUSD on Base.
+pub const BASE_CRVUSD: Address = address!("0x417Ac0e078398C154EdFadD9Ef675d30Be60Af93");
+/// crvUSD on OP Mainnet.
+pub const OPTIMISM_CRVUSD: Address = address!("0xC52D7F23a2e460248Db6eE192Cb23dD12bDDCbf6");
+
+pub(super) const fn address(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_CRVUSD),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_CRVUSD),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_CRVUSD),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_CRVUSD),
+        _ => None,
+    }
+}
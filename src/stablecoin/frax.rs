@@ -0,0 +1,39 @@
+//! Frax (FRAX) addresses. On Fraxtal, FRAX is a predeploy at a fixed address.
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+
+/// FRAX on Ethereum mainnet.
+pub const ETHEREUM_FRAX: Address = address!("0x853d955aCEf822Db058eb8505911ED77F175b99e");
+/// FRAX on Arbitrum One.
+pub const ARBITRUM_FRAX: Address = address!("0x17FC002b466eEc40DaE837Fc4bE5c67993ddBd6F");
+/// FRAX on Avalanche C-Chain.
+pub const AVALANCHE_FRAX: Address = address!("0xD24C2Ad096400B6FBcd2ad8B24E7acBc21A1da64");
+/// FRAX on BNB Smart Chain.
+pub const BSC_FRAX: Address = address!("0x90C97F71E18723b0Cf0dfa30ee176Ab653E89F40");
+/// FRAX on Fraxtal.
+pub const FRAXTAL_FRAX: Address = address!("0xFc00000000000000000000000000000000000001");
+/// FRAX on OP Mainnet.
+pub const OPTIMISM_FRAX: Address = address!("0x2E3D870790dC77A83DD1d18184Acc7439A53f475");
+/// FRAX on Polygon PoS.
+pub const POLYGON_FRAX: Address = address!("0x45c32fA6DF82ead1e2EF74d17b76547EDdFaFF89");
+
+pub(super) const fn address(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_FRAX),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_FRAX),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::BinanceSmartChain => Some(BSC_FRAX),
+        #[cfg(feature = "l2s")]
+        NamedChain::Fraxtal => Some(FRAXTAL_FRAX),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_FRAX),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_FRAX),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_FRAX),
+        _ => None,
+    }
+}
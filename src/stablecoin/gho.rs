@@ -0,0 +1,23 @@
+//! Aave's GHO addresses. L2 deployments are bridged through Chainlink CCIP.
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+
+/// GHO on Ethereum mainnet.
+pub const ETHEREUM_GHO: Address = address!("0x40D16FC0246aD3160Ccc09B8D0D3A2cD28aE6C2f");
+/// GHO on Arbitrum One.
+pub const ARBITRUM_GHO: Address = address!("0x7dfF72693f6A4149b17e7C6314655f6A9F7c8B33");
+/// GHO on Base.
+pub const BASE_GHO: Address = address!("0x6Bb7a212910682DCFdbd5BCBb3e28FB4E8da10Ee");
+
+pub(super) const fn address(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_GHO),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_GHO),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_GHO),
+        _ => None,
+    }
+}
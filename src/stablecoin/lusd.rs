@@ -0,0 +1,23 @@
+//! Liquity USD (LUSD) addresses. L2 deployments are bridged from Ethereum.
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+
+/// LUSD on Ethereum mainnet.
+pub const ETHEREUM_LUSD: Address = address!("0x5f98805A4E8be255a32880FDeC7F6728C6568bA0");
+/// LUSD on Arbitrum One.
+pub const ARBITRUM_LUSD: Address = address!("0x93b346b6BC2548dA6A1E7d98E9a421B42541425b");
+/// LUSD on OP Mainnet.
+pub const OPTIMISM_LUSD: Address = address!("0xc40F949F8a4e094D1b49a23ea9241D289B7b2819");
+
+pub(super) const fn address(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_LUSD),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_LUSD),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_LUSD),
+        _ => None,
+    }
+}
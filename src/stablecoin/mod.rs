@@ -0,0 +1,128 @@
+//! DeFi-native stablecoins behind a single [`Stablecoin`] enum.
+
+mod crvusd;
+mod frax;
+mod gho;
+mod lusd;
+mod usde;
+
+pub use crvusd::{ARBITRUM_CRVUSD, BASE_CRVUSD, ETHEREUM_CRVUSD, OPTIMISM_CRVUSD};
+pub use frax::{
+    ARBITRUM_FRAX, AVALANCHE_FRAX, BSC_FRAX, ETHEREUM_FRAX, FRAXTAL_FRAX, OPTIMISM_FRAX,
+    POLYGON_FRAX,
+};
+pub use gho::{ARBITRUM_GHO, BASE_GHO, ETHEREUM_GHO};
+pub use lusd::{ARBITRUM_LUSD, ETHEREUM_LUSD, OPTIMISM_LUSD};
+pub use usde::{ARBITRUM_USDE, BASE_USDE, ETHEREUM_USDE, MANTLE_USDE, OPTIMISM_USDE};
+
+use crate::{Tokens, UsdcError};
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::Address;
+
+/// A DeFi-native stablecoin whose addresses this crate knows.
+///
+/// Each token's data lives in its own module; [`Stablecoins`] looks them up by
+/// chain, as does [`Tokens`] through [`TokenKind::Stablecoin`](crate::TokenKind::Stablecoin).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum Stablecoin {
+    /// Frax Finance's FRAX.
+    Frax,
+    /// Aave's GHO.
+    Gho,
+    /// Curve's crvUSD.
+    CrvUsd,
+    /// Liquity USD.
+    Lusd,
+    /// Ethena's synthetic dollar, USDe.
+    Usde,
+}
+
+impl Stablecoin {
+    /// Every stablecoin, in declaration order.
+    pub const ALL: &'static [Self] = &[Self::Frax, Self::Gho, Self::CrvUsd, Self::Lusd, Self::Usde];
+
+    /// Returns the token symbol, e.g. `crvUSD`.
+    pub const fn symbol(&self) -> &'static str {
+        match self {
+            Self::Frax => "FRAX",
+            Self::Gho => "GHO",
+            Self::CrvUsd => "crvUSD",
+            Self::Lusd => "LUSD",
+            Self::Usde => "USDe",
+        }
+    }
+
+    /// Returns the number of decimals, which is 18 for every listed token.
+    pub const fn decimals(&self) -> u8 {
+        18
+    }
+
+    /// Returns the token address on `chain`, if known.
+    pub const fn address_on(&self, chain: NamedChain) -> Option<Address> {
+        match self {
+            Self::Frax => frax::address(chain),
+            Self::Gho => gho::address(chain),
+            Self::CrvUsd => crvusd::address(chain),
+            Self::Lusd => lusd::address(chain),
+            Self::Usde => usde::address(chain),
+        }
+    }
+}
+
+/// A trait for chain identifiers that can look up any [`Stablecoin`].
+///
+/// One method covers every token, so adding a stablecoin does not add a trait.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "ethereum", doc = "```rust")]
+#[cfg_attr(not(feature = "ethereum"), doc = "```ignore")]
+/// use usdshe::{Stablecoin, Stablecoins, UsdcError, ETHEREUM_GHO};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(NamedChain::Mainnet.stablecoin_address(Stablecoin::Gho).unwrap(), ETHEREUM_GHO);
+/// assert!(1u64.is_stablecoin(Stablecoin::Gho, ETHEREUM_GHO));
+/// assert!(matches!(
+///     NamedChain::Mantle.stablecoin_address(Stablecoin::Lusd),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Mantle))
+/// ));
+/// ```
+pub trait Stablecoins {
+    /// Returns the address of `coin` for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but `coin` has no
+    ///   known address on it.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn stablecoin_address(&self, coin: Stablecoin) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical `coin` contract for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_stablecoin(&self, coin: Stablecoin, address: Address) -> bool {
+        self.stablecoin_address(coin)
+            .is_ok_and(|known| known == address)
+    }
+}
+
+impl Stablecoins for NamedChain {
+    fn stablecoin_address(&self, coin: Stablecoin) -> Result<Address, UsdcError> {
+        self.token_address(coin.into())
+    }
+}
+
+impl Stablecoins for Chain {
+    fn stablecoin_address(&self, coin: Stablecoin) -> Result<Address, UsdcError> {
+        self.token_address(coin.into())
+    }
+}
+
+impl Stablecoins for u64 {
+    fn stablecoin_address(&self, coin: Stablecoin) -> Result<Address, UsdcError> {
+        self.token_address(coin.into())
+    }
+}
@@ -0,0 +1,31 @@
+//! Ethena USDe addresses. L2 deployments are LayerZero OFTs sharing one address.
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+
+/// USDe on Ethereum mainnet.
+pub const ETHEREUM_USDE: Address = address!("0x4c9EDD5852cd905f086C759E8383e09bff1E68B3");
+/// USDe on Arbitrum One.
+pub const ARBITRUM_USDE: Address = address!("0x5d3a1Ff2b6BAb83b63cd9AD0787074081a52ef34");
+/// USDe on Base.
+pub const BASE_USDE: Address = address!("0x5d3a1Ff2b6BAb83b63cd9AD0787074081a52ef34");
+/// USDe on Mantle.
+pub const MANTLE_USDE: Address = address!("0x5d3a1Ff2b6BAb83b63cd9AD0787074081a52ef34");
+/// USDe on OP Mainnet.
+pub const OPTIMISM_USDE: Address = address!("0x5d3a1Ff2b6BAb83b63cd9AD0787074081a52ef34");
+
+pub(super) const fn address(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_USDE),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_USDE),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_USDE),
+        #[cfg(feature = "l2s")]
+        NamedChain::Mantle => Some(MANTLE_USDE),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_USDE),
+        _ => None,
+    }
+}
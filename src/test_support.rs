@@ -0,0 +1,221 @@
+//! Fixtures shared by the unit tests: a thread-parking executor, a canned
+//! [`EthProvider`] and a canned [`LogSubscriber`].
+
+#[cfg(feature = "onchain")]
+use crate::{EthProvider, LogStream, LogSubscriber, TransferFilter, TRANSFER_TOPIC};
+#[cfg(feature = "onchain")]
+use alloy_primitives::{Address, Bytes, Log, B256, U256};
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+#[cfg(feature = "onchain")]
+use std::{
+    collections::VecDeque,
+    io::{self, ErrorKind},
+};
+use std::{
+    sync::Arc,
+    task::Wake,
+    thread::{self, Thread},
+};
+
+/// Wakes a thread parked in [`block_on`].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs `future` to completion on the current thread, parking it while the
+/// future is pending.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+#[cfg(feature = "onchain")]
+type CallHandler = Box<dyn Fn(Address, &[u8]) -> Bytes + Send + Sync>;
+
+/// An [`EthProvider`] with canned answers: every `eth_call` is answered by a
+/// handler, and every storage slot holds the same word.
+#[cfg(feature = "onchain")]
+pub struct Node {
+    chain_id: u64,
+    call: CallHandler,
+    storage: B256,
+}
+
+#[cfg(feature = "onchain")]
+impl Node {
+    /// A node for `chain_id` whose calls return no data and whose storage is
+    /// zero.
+    pub fn new(chain_id: u64) -> Self {
+        Self {
+            chain_id,
+            call: Box::new(|_, _| Bytes::new()),
+            storage: B256::ZERO,
+        }
+    }
+
+    /// Answers every call with `handler(to, calldata)`.
+    pub fn on_call(
+        mut self,
+        handler: impl Fn(Address, &[u8]) -> Bytes + Send + Sync + 'static,
+    ) -> Self {
+        self.call = Box::new(handler);
+        self
+    }
+
+    /// Makes every storage slot hold `word`.
+    pub fn with_storage(mut self, word: B256) -> Self {
+        self.storage = word;
+        self
+    }
+}
+
+#[cfg(feature = "onchain")]
+impl EthProvider for Node {
+    type Error = std::io::Error;
+
+    async fn chain_id(&self) -> Result<u64, Self::Error> {
+        Ok(self.chain_id)
+    }
+
+    async fn call(&self, to: Address, data: Bytes) -> Result<Bytes, Self::Error> {
+        Ok((self.call)(to, &data))
+    }
+
+    async fn storage_at(&self, _address: Address, _slot: B256) -> Result<B256, Self::Error> {
+        Ok(self.storage)
+    }
+}
+
+/// A [`LogSubscriber`] whose subscriptions deliver a fixed list of logs, or a
+/// single error, and then end.
+#[cfg(feature = "onchain")]
+pub struct Subscriber(Vec<Result<Log, ErrorKind>>);
+
+#[cfg(feature = "onchain")]
+impl Subscriber {
+    /// Delivers `logs` to every subscription.
+    pub fn new(logs: impl IntoIterator<Item = Log>) -> Self {
+        Self(logs.into_iter().map(Ok).collect())
+    }
+
+    /// Breaks every subscription with a connection error.
+    pub fn failing() -> Self {
+        Self(vec![Err(ErrorKind::ConnectionReset)])
+    }
+}
+
+#[cfg(feature = "onchain")]
+impl LogSubscriber for Subscriber {
+    type Error = io::Error;
+    type Logs = Logs;
+
+    async fn subscribe_logs(&self, _filter: &TransferFilter) -> Result<Logs, Self::Error> {
+        Ok(Logs(self.0.clone().into()))
+    }
+}
+
+/// The subscription opened by a [`Subscriber`].
+#[cfg(feature = "onchain")]
+pub struct Logs(VecDeque<Result<Log, ErrorKind>>);
+
+#[cfg(feature = "onchain")]
+impl LogStream for Logs {
+    type Error = io::Error;
+
+    fn poll_next_log(&mut self, _cx: &mut Context<'_>) -> Poll<Option<Result<Log, io::Error>>> {
+        Poll::Ready(self.0.pop_front().map(|log| log.map_err(io::Error::from)))
+    }
+}
+
+/// A `Transfer` of `value` raw units from a fixed sender to `to`, emitted by
+/// `token`.
+#[cfg(feature = "onchain")]
+pub fn transfer_log(token: Address, to: Address, value: U256) -> Log {
+    let topics = vec![
+        TRANSFER_TOPIC,
+        Address::repeat_byte(0xee).into_word(),
+        to.into_word(),
+    ];
+    Log::new(token, topics, value.to_be_bytes_vec().into()).expect("three topics")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        time::Duration,
+    };
+
+    /// Pending until another thread sets the flag and wakes the task.
+    struct WokenLater(Arc<AtomicBool>, bool);
+
+    impl Future for WokenLater {
+        type Output = ();
+
+        fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0.load(Ordering::Acquire) {
+                return Poll::Ready(());
+            }
+            if !self.1 {
+                self.1 = true;
+                let (done, waker) = (self.0.clone(), cx.waker().clone());
+                thread::spawn(move || {
+                    thread::sleep(Duration::from_millis(10));
+                    done.store(true, Ordering::Release);
+                    waker.wake();
+                });
+            }
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn block_on_returns_ready_output() {
+        assert_eq!(block_on(async { 42 }), 42);
+    }
+
+    #[test]
+    fn block_on_parks_until_woken() {
+        block_on(WokenLater(Arc::default(), false));
+    }
+
+    #[cfg(feature = "onchain")]
+    #[test]
+    fn node_answers_with_canned_data() {
+        let node = Node::new(8453)
+            .on_call(|to, data| Bytes::from([to.as_slice(), data].concat()))
+            .with_storage(B256::repeat_byte(1));
+        let to = Address::repeat_byte(2);
+        assert_eq!(block_on(node.chain_id()).unwrap(), 8453);
+        assert_eq!(
+            block_on(node.call(to, Bytes::from_static(&[3])))
+                .unwrap()
+                .len(),
+            21
+        );
+        assert_eq!(
+            block_on(node.storage_at(to, B256::ZERO)).unwrap(),
+            B256::repeat_byte(1)
+        );
+    }
+}
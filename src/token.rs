@@ -0,0 +1,153 @@
+//! A single entry point over every token this crate knows.
+
+use crate::{
+    btc, dai, eurc, pyusd, usdt, wrapped_native, Stablecoin, SupportedUsdcChain, UsdcError,
+};
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::Address;
+
+/// A token whose per-chain addresses this crate knows.
+///
+/// The per-token traits ([`Usdc`](crate::Usdc), [`Usdt`](crate::Usdt),
+/// [`WrappedNative`](crate::WrappedNative), ...) are thin wrappers over
+/// [`Tokens::token_address`] with the matching kind, so code can be written
+/// generically over kinds without losing the dedicated APIs. Every lookup,
+/// whatever the token, fails with the same [`UsdcError`] variants as the USDC
+/// API, so errors can be handled in one place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum TokenKind {
+    /// USDC, as returned by [`Usdc`](crate::Usdc).
+    Usdc,
+    /// The legacy bridged USDC.e, as returned by [`usdc_e_address`](crate::usdc_e_address).
+    UsdcE,
+    /// Tether USD.
+    Usdt,
+    /// DAI, bridged outside Ethereum.
+    Dai,
+    /// Circle's Euro Coin.
+    Eurc,
+    /// PayPal USD.
+    Pyusd,
+    /// The chain's wrapped native gas token, e.g. WETH or WAVAX.
+    WrappedNative,
+    /// Wrapped BTC.
+    Wbtc,
+    /// Threshold's tBTC.
+    Tbtc,
+    /// One of the DeFi-native [`Stablecoin`]s.
+    Stablecoin(Stablecoin),
+}
+
+impl TokenKind {
+    /// Every token kind, including each [`Stablecoin`].
+    pub const ALL: &'static [Self] = &[
+        Self::Usdc,
+        Self::UsdcE,
+        Self::Usdt,
+        Self::Dai,
+        Self::Eurc,
+        Self::Pyusd,
+        Self::WrappedNative,
+        Self::Wbtc,
+        Self::Tbtc,
+        Self::Stablecoin(Stablecoin::Frax),
+        Self::Stablecoin(Stablecoin::Gho),
+        Self::Stablecoin(Stablecoin::CrvUsd),
+        Self::Stablecoin(Stablecoin::Lusd),
+        Self::Stablecoin(Stablecoin::Usde),
+    ];
+
+    /// Returns the token address on `chain`, if known.
+    pub fn address_on(&self, chain: NamedChain) -> Option<Address> {
+        match *self {
+            Self::Usdc => SupportedUsdcChain::try_from(chain)
+                .ok()
+                .map(|chain| chain.usdc_address()),
+            Self::UsdcE => SupportedUsdcChain::try_from(chain)
+                .ok()
+                .and_then(|chain| chain.usdc_e_address()),
+            Self::Usdt => usdt::usdt_on(chain),
+            Self::Dai => dai::dai_on(chain),
+            Self::Eurc => eurc::eurc_on(chain),
+            Self::Pyusd => pyusd::pyusd_on(chain),
+            Self::WrappedNative => wrapped_native::wrapped_native_on(chain),
+            Self::Wbtc => btc::wbtc_on(chain),
+            Self::Tbtc => btc::tbtc_on(chain),
+            Self::Stablecoin(coin) => coin.address_on(chain),
+        }
+    }
+}
+
+impl From<Stablecoin> for TokenKind {
+    fn from(coin: Stablecoin) -> Self {
+        Self::Stablecoin(coin)
+    }
+}
+
+/// A trait for chain identifiers that can look up any [`TokenKind`].
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "ethereum", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "ethereum", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::{Stablecoin, TokenKind, Tokens, UsdcError, BASE_USDC, BASE_WETH};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(NamedChain::Base.token_address(TokenKind::Usdc).unwrap(), BASE_USDC);
+/// assert_eq!(8453u64.token_address(TokenKind::WrappedNative).unwrap(), BASE_WETH);
+/// assert!(NamedChain::Base.token_address(Stablecoin::Gho.into()).is_ok());
+///
+/// // Every token known on a chain, e.g. to seed a price feed.
+/// let known = TokenKind::ALL
+///     .iter()
+///     .filter(|kind| NamedChain::Mainnet.token_address(**kind).is_ok())
+///     .count();
+/// assert!(known > 10);
+///
+/// assert!(matches!(
+///     NamedChain::Base.token_address(TokenKind::Wbtc),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Base))
+/// ));
+/// ```
+pub trait Tokens {
+    /// Returns the address of `kind` for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but `kind` has no
+    ///   known address on it.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn token_address(&self, kind: TokenKind) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical `kind` contract for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_token(&self, kind: TokenKind, address: Address) -> bool {
+        self.token_address(kind).is_ok_and(|known| known == address)
+    }
+}
+
+impl Tokens for NamedChain {
+    fn token_address(&self, kind: TokenKind) -> Result<Address, UsdcError> {
+        kind.address_on(*self)
+            .ok_or(UsdcError::UnsupportedChain(*self))
+    }
+}
+
+impl Tokens for Chain {
+    fn token_address(&self, kind: TokenKind) -> Result<Address, UsdcError> {
+        match self.named() {
+            Some(named) => named.token_address(kind),
+            None => Err(UsdcError::unsupported(*self)),
+        }
+    }
+}
+
+impl Tokens for u64 {
+    fn token_address(&self, kind: TokenKind) -> Result<Address, UsdcError> {
+        Chain::from_id(*self).token_address(kind)
+    }
+}
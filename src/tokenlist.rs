@@ -0,0 +1,338 @@
+//! Export of the built-in registry as a [Uniswap Token List](https://tokenlists.org),
+//! and import of token lists into a [`UsdcRegistry`].
+
+use crate::{
+    json::{self, Value},
+    parse_address, JsonError, SupportedUsdcChain, UsdcError, UsdcRegistry,
+};
+use alloy_chains::Chain;
+use alloy_primitives::Address;
+use std::{
+    io::{self, Read},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+
+/// The logo used for every USDC entry in the exported list.
+pub const USDC_LOGO_URI: &str = "https://raw.githubusercontent.com/trustwallet/assets/master/blockchains/ethereum/assets/0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48/logo.png";
+
+/// A token list following the [Token List schema](https://github.com/Uniswap/token-lists).
+///
+/// With the `serde` feature, the type (de)serializes using the schema's field names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenList {
+    /// The name of the list.
+    pub name: String,
+    /// When the list was produced, as an ISO 8601 UTC timestamp.
+    pub timestamp: String,
+    /// The list version.
+    pub version: TokenListVersion,
+    /// Keywords describing the list.
+    pub keywords: Vec<String>,
+    /// The tokens in the list.
+    pub tokens: Vec<TokenListEntry>,
+}
+
+/// The semantic version of a [`TokenList`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenListVersion {
+    /// Incremented when tokens are removed or their addresses change.
+    pub major: u32,
+    /// Incremented when tokens are added.
+    pub minor: u32,
+    /// Incremented for changes to existing tokens other than their addresses.
+    pub patch: u32,
+}
+
+/// A single token in a [`TokenList`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TokenListEntry {
+    /// The EIP-155 chain ID of the token.
+    pub chain_id: u64,
+    /// The token contract address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serde_helpers::checksummed")
+    )]
+    pub address: Address,
+    /// The number of decimals the token uses.
+    pub decimals: u8,
+    /// The token symbol.
+    pub symbol: String,
+    /// The token name.
+    pub name: String,
+    /// A URI for the token logo.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "logoURI", default, skip_serializing_if = "Option::is_none")
+    )]
+    pub logo_uri: Option<String>,
+}
+
+impl TokenList {
+    /// Renders the list as pretty-printed JSON.
+    ///
+    /// Addresses are written with their EIP-55 checksum, as the schema requires.
+    pub fn to_json(&self) -> String {
+        let version = Value::object([
+            ("major", Value::from(u64::from(self.version.major))),
+            ("minor", Value::from(u64::from(self.version.minor))),
+            ("patch", Value::from(u64::from(self.version.patch))),
+        ]);
+        let keywords = self.keywords.iter().map(|k| Value::from(k.as_str()));
+        let tokens = self.tokens.iter().map(TokenListEntry::to_value);
+
+        Value::object([
+            ("name", Value::from(self.name.as_str())),
+            ("timestamp", Value::from(self.timestamp.as_str())),
+            ("version", version),
+            ("keywords", Value::Array(keywords.collect())),
+            ("tokens", Value::Array(tokens.collect())),
+        ])
+        .to_pretty_string()
+    }
+}
+
+impl TokenListEntry {
+    fn to_value(&self) -> Value {
+        let mut entries = vec![
+            ("chainId", Value::from(self.chain_id)),
+            ("address", Value::from(self.address.to_checksum(None))),
+            ("decimals", Value::from(self.decimals)),
+            ("symbol", Value::from(self.symbol.as_str())),
+            ("name", Value::from(self.name.as_str())),
+        ];
+        if let Some(logo_uri) = &self.logo_uri {
+            entries.push(("logoURI", Value::from(logo_uri.as_str())));
+        }
+        Value::object(entries)
+    }
+}
+
+/// Builds a token list containing USDC on every supported chain.
+///
+/// The list version follows the crate version, and the timestamp is the current
+/// system time.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "ethereum", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "ethereum", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::usdc_token_list;
+///
+/// let list = usdc_token_list();
+/// assert!(list.tokens.iter().any(|token| token.chain_id == 1));
+///
+/// let json = list.to_json();
+/// assert!(json.contains("\"chainId\": 8453"));
+/// assert!(json.contains("\"address\": \"0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913\""));
+/// ```
+pub fn usdc_token_list() -> TokenList {
+    let tokens = SupportedUsdcChain::ALL
+        .iter()
+        .map(|chain| {
+            let info = chain.usdc_token_info();
+            TokenListEntry {
+                chain_id: chain.chain_id(),
+                address: info.address,
+                decimals: info.decimals,
+                symbol: info.symbol,
+                name: info.name,
+                logo_uri: Some(USDC_LOGO_URI.to_string()),
+            }
+        })
+        .collect();
+
+    TokenList {
+        name: "usdshe USDC".to_string(),
+        timestamp: iso8601_now(),
+        version: TokenListVersion {
+            major: parse_version(env!("CARGO_PKG_VERSION_MAJOR")),
+            minor: parse_version(env!("CARGO_PKG_VERSION_MINOR")),
+            patch: parse_version(env!("CARGO_PKG_VERSION_PATCH")),
+        },
+        keywords: vec!["usdc".to_string(), "stablecoin".to_string()],
+        tokens,
+    }
+}
+
+/// Represents errors that can occur when importing a token list.
+#[derive(Error, Debug)]
+pub enum TokenListError {
+    /// The list could not be read.
+    #[error("Failed to read token list: {0}")]
+    Io(#[from] io::Error),
+
+    /// The list is not valid JSON.
+    #[error(transparent)]
+    Json(#[from] JsonError),
+
+    /// A field is missing or has the wrong type.
+    #[error("Invalid token list field '{field}': expected {expected}")]
+    InvalidField {
+        /// The offending field, e.g. `tokens[3].chainId`.
+        field: String,
+        /// A description of the expected value.
+        expected: &'static str,
+    },
+
+    /// A mixed-case address does not match its EIP-55 checksum, which usually
+    /// means it was mistyped.
+    #[error("Address '{0}' has an invalid EIP-55 checksum")]
+    InvalidChecksum(String),
+
+    /// An address is not valid hex.
+    #[error(transparent)]
+    Usdc(#[from] UsdcError),
+}
+
+impl UsdcRegistry {
+    /// Creates a registry from the built-in data with the `USDC` entries of a
+    /// token list merged on top.
+    ///
+    /// # Errors
+    ///
+    /// See [`UsdcRegistry::merge_tokenlist`].
+    pub fn from_tokenlist(reader: impl Read) -> Result<Self, TokenListError> {
+        let mut registry = Self::new();
+        registry.merge_tokenlist(reader, "USDC")?;
+        Ok(registry)
+    }
+
+    /// Merges the entries of a token list whose symbol matches `symbol`
+    /// (ignoring ASCII case) into this registry, returning how many were merged.
+    ///
+    /// Every matching address is validated, including its EIP-55 checksum when
+    /// written in mixed case. Nothing is merged unless the whole list is valid.
+    /// Later entries for the same chain replace earlier ones.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use usdshe::UsdcRegistry;
+    /// use alloy_chains::{Chain, NamedChain};
+    /// use alloy_primitives::address;
+    ///
+    /// let list = r#"{
+    ///     "name": "Curated",
+    ///     "tokens": [
+    ///         {"chainId": 12345, "address": "0x1111111111111111111111111111111111111111", "symbol": "USDC", "decimals": 6},
+    ///         {"chainId": 100, "address": "0x2a22f9c3b484c3629090FeED35F17Ff8F88f76F0", "symbol": "USDC.e", "decimals": 6},
+    ///         {"chainId": 1, "address": "0xdAC17F958D2ee523a2206206994597C13D831ec7", "symbol": "USDT", "decimals": 6}
+    ///     ]
+    /// }"#;
+    ///
+    /// let registry = UsdcRegistry::from_tokenlist(list.as_bytes()).unwrap();
+    /// assert_eq!(
+    ///     registry.get(Chain::from_id(12_345)),
+    ///     Some(address!("0x1111111111111111111111111111111111111111"))
+    /// );
+    ///
+    /// let mut registry = UsdcRegistry::empty();
+    /// assert_eq!(registry.merge_tokenlist(list.as_bytes(), "usdc.e").unwrap(), 1);
+    /// assert!(registry.get(NamedChain::Gnosis).is_some());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TokenListError`] if the list cannot be read or parsed, or if a
+    /// matching entry is malformed or has an invalid address.
+    pub fn merge_tokenlist(
+        &mut self,
+        mut reader: impl Read,
+        symbol: &str,
+    ) -> Result<usize, TokenListError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let document = json::parse(&input)?;
+        let tokens = document
+            .get("tokens")
+            .and_then(|tokens| tokens.as_array())
+            .ok_or_else(|| TokenListError::InvalidField {
+                field: "tokens".to_string(),
+                expected: "an array of tokens",
+            })?;
+
+        let mut entries = Vec::new();
+        for (index, token) in tokens.iter().enumerate() {
+            let field = |name: &str, expected| TokenListError::InvalidField {
+                field: format!("tokens[{index}].{name}"),
+                expected,
+            };
+            let token_symbol = token
+                .get("symbol")
+                .and_then(|symbol| symbol.as_str())
+                .ok_or_else(|| field("symbol", "a string"))?;
+            if !token_symbol.eq_ignore_ascii_case(symbol) {
+                continue;
+            }
+            let chain_id = token
+                .get("chainId")
+                .and_then(|id| id.as_u64())
+                .ok_or_else(|| field("chainId", "a chain ID"))?;
+            let address = token
+                .get("address")
+                .and_then(|address| address.as_str())
+                .ok_or_else(|| field("address", "an address string"))?;
+            entries.push((Chain::from_id(chain_id), parse_listed_address(address)?));
+        }
+
+        let merged = entries.len();
+        for (chain, address) in entries {
+            self.insert(chain, address);
+        }
+        Ok(merged)
+    }
+}
+
+/// Parses an address, enforcing the EIP-55 checksum if the hex digits use both
+/// cases.
+fn parse_listed_address(input: &str) -> Result<Address, TokenListError> {
+    let address = parse_address(input)?;
+    let digits = input.strip_prefix("0x").unwrap_or(input);
+    let mixed_case = digits.bytes().any(|b| b.is_ascii_uppercase())
+        && digits.bytes().any(|b| b.is_ascii_lowercase());
+    if mixed_case && address.to_checksum_buffer(None).as_str()[2..] != *digits {
+        return Err(TokenListError::InvalidChecksum(input.to_string()));
+    }
+    Ok(address)
+}
+
+fn parse_version(component: &str) -> u32 {
+    component
+        .parse()
+        .expect("Cargo version components are integers")
+}
+
+/// Formats the current system time as `YYYY-MM-DDTHH:MM:SSZ`.
+fn iso8601_now() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+
+    // Days-to-civil conversion from Howard Hinnant's `chrono`-compatible algorithms.
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
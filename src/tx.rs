@@ -0,0 +1,224 @@
+//! Unsigned transactions against a chain's USDC contract.
+//!
+//! The builders only encode calldata; gas, nonce and fees are left to the
+//! caller's wallet or provider.
+
+use crate::{abi, units::rescale_exact, usdc_decimals, Usdc, UsdcAmount, UsdcError};
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, Bytes, U256};
+
+/// The `approve` amount conventionally treated as an unlimited allowance,
+/// `type(uint256).max`. FiatToken decrements it like any other allowance, but
+/// it is large enough never to run out.
+pub const UNLIMITED_APPROVAL: U256 = U256::MAX;
+
+/// An unsigned contract call, e.g. to a token or CCTP contract.
+///
+/// The fields map directly onto alloy's `TransactionRequest`:
+///
+/// ```rust,ignore
+/// let request = TransactionRequest::default()
+///     .with_chain_id(tx.chain_id)
+///     .with_to(tx.to)
+///     .with_input(tx.data);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequest {
+    /// The chain ID the transaction must be signed for.
+    pub chain_id: u64,
+    /// The contract address.
+    pub to: Address,
+    /// The ABI-encoded call.
+    pub data: Bytes,
+}
+
+/// Builds a `transfer(to, amount)` call to the USDC contract on `chain`.
+///
+/// `amount` is rescaled to the decimals of the deployment on `chain`: most use
+/// 6, like [`UsdcAmount`], but Binance-Peg USDC on BSC uses 18. Use
+/// [`build_usdc_transfer_raw`] for an amount already in the deployment's raw
+/// units.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "alt-l1s", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "alt-l1s", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::{build_usdc_transfer, UsdcAmount, BASE_USDC};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, U256};
+///
+/// let merchant = address!("0x1111111111111111111111111111111111111111");
+/// let amount: UsdcAmount = "12.50".parse().unwrap();
+/// let tx = build_usdc_transfer(NamedChain::Base, merchant, amount).unwrap();
+///
+/// assert_eq!(tx.chain_id, 8453);
+/// assert_eq!(tx.to, BASE_USDC);
+/// assert_eq!(tx.data[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+/// assert_eq!(U256::from_be_slice(&tx.data[36..]), U256::from(12_500_000));
+///
+/// // Binance-Peg USDC has 18 decimals.
+/// let tx = build_usdc_transfer(NamedChain::BinanceSmartChain, merchant, amount).unwrap();
+/// assert_eq!(
+///     U256::from_be_slice(&tx.data[36..]),
+///     U256::from(12_500_000) * U256::from(10).pow(U256::from(12))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+/// - [`UsdcError::PrecisionLoss`]: If the deployment has fewer decimals than
+///   `amount` needs.
+/// - [`UsdcError::AmountOverflow`]: If the rescaled amount does not fit in a
+///   `uint256`.
+pub fn build_usdc_transfer(
+    chain: NamedChain,
+    to: Address,
+    amount: UsdcAmount,
+) -> Result<TransactionRequest, UsdcError> {
+    build_usdc_transfer_raw(chain, to, deployment_units(chain, amount)?)
+}
+
+/// Like [`build_usdc_transfer`], but encodes `amount` unchanged, in the raw
+/// units of the deployment on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::build_usdc_transfer_raw;
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, U256};
+///
+/// let merchant = address!("0x1111111111111111111111111111111111111111");
+/// let tx = build_usdc_transfer_raw(NamedChain::Base, merchant, U256::from(42)).unwrap();
+/// assert_eq!(U256::from_be_slice(&tx.data[36..]), U256::from(42));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn build_usdc_transfer_raw(
+    chain: NamedChain,
+    to: Address,
+    amount: U256,
+) -> Result<TransactionRequest, UsdcError> {
+    usdc_call(
+        chain,
+        abi::encode_call(
+            abi::TRANSFER,
+            &[abi::address_word(to), abi::uint_word(amount)],
+        ),
+    )
+}
+
+/// Builds an `approve(spender, amount)` call to the USDC contract on `chain`,
+/// rescaling `amount` like [`build_usdc_transfer`].
+///
+/// Use [`build_usdc_approve_raw`] with [`UNLIMITED_APPROVAL`] for an allowance
+/// that never runs out.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{build_usdc_approve, UsdcAmount};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::{address, U256};
+///
+/// let router = address!("0x2222222222222222222222222222222222222222");
+/// let amount: UsdcAmount = "100".parse().unwrap();
+///
+/// let tx = build_usdc_approve(NamedChain::Arbitrum, router, amount).unwrap();
+/// assert_eq!(tx.data[..4], [0x09, 0x5e, 0xa7, 0xb3]);
+/// assert_eq!(U256::from_be_slice(&tx.data[36..]), U256::from(100_000_000));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+/// - [`UsdcError::PrecisionLoss`]: If the deployment has fewer decimals than
+///   `amount` needs.
+/// - [`UsdcError::AmountOverflow`]: If the rescaled amount does not fit in a
+///   `uint256`.
+pub fn build_usdc_approve(
+    chain: NamedChain,
+    spender: Address,
+    amount: UsdcAmount,
+) -> Result<TransactionRequest, UsdcError> {
+    build_usdc_approve_raw(chain, spender, deployment_units(chain, amount)?)
+}
+
+/// Like [`build_usdc_approve`], but encodes `amount` unchanged, in the raw
+/// units of the deployment on `chain`. Pass [`UNLIMITED_APPROVAL`] for an
+/// allowance that never runs out.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{build_usdc_approve_raw, UNLIMITED_APPROVAL};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::address;
+///
+/// let router = address!("0x2222222222222222222222222222222222222222");
+/// let tx = build_usdc_approve_raw(NamedChain::Arbitrum, router, UNLIMITED_APPROVAL).unwrap();
+/// assert_eq!(tx.data[36..], [0xff; 32]);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn build_usdc_approve_raw(
+    chain: NamedChain,
+    spender: Address,
+    amount: U256,
+) -> Result<TransactionRequest, UsdcError> {
+    usdc_call(
+        chain,
+        abi::encode_call(
+            abi::APPROVE,
+            &[abi::address_word(spender), abi::uint_word(amount)],
+        ),
+    )
+}
+
+/// Builds an `approve(spender, 0)` call that revokes `spender`'s allowance on
+/// the USDC contract on `chain`.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::build_usdc_revoke;
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::address;
+///
+/// let router = address!("0x2222222222222222222222222222222222222222");
+/// let tx = build_usdc_revoke(NamedChain::Base, router).unwrap();
+/// assert_eq!(tx.data[36..], [0; 32]);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If the chain has no known USDC address.
+pub fn build_usdc_revoke(
+    chain: NamedChain,
+    spender: Address,
+) -> Result<TransactionRequest, UsdcError> {
+    build_usdc_approve_raw(chain, spender, U256::ZERO)
+}
+
+/// Targets `data` at the USDC contract on `chain`.
+fn usdc_call(chain: NamedChain, data: Bytes) -> Result<TransactionRequest, UsdcError> {
+    Ok(TransactionRequest {
+        chain_id: chain as u64,
+        to: chain.usdc_address()?,
+        data,
+    })
+}
+
+/// Converts `amount` into the raw units of the deployment on `chain`.
+fn deployment_units(chain: NamedChain, amount: UsdcAmount) -> Result<U256, UsdcError> {
+    rescale_exact(amount.to_raw(), UsdcAmount::DECIMALS, usdc_decimals(chain)?)
+}
@@ -0,0 +1,209 @@
+//! Converting between raw token units and decimal strings.
+
+use crate::{usdc_decimals, UsdcAmountError, UsdcError};
+use alloc::string::{String, ToString};
+use alloy_chains::NamedChain;
+use alloy_primitives::U256;
+
+/// Formats `value` raw units of a token with `decimals` decimals as a decimal
+/// string, without trailing zeros: `1_500_000` with 6 decimals is `"1.5"`.
+///
+/// Pass the decimals of the deployment the value was read from, e.g. from
+/// [`usdc_decimals`](crate::usdc_decimals): 18 for Binance-Peg USDC on BSC, 6
+/// everywhere else. For 6-decimal amounts with a fixed two-decimal display, see
+/// [`UsdcAmount`](crate::UsdcAmount).
+///
+/// ## Examples
+///
+/// ```rust
+/// use usdshe::format_usdc;
+/// use alloy_primitives::U256;
+///
+/// assert_eq!(format_usdc(U256::from(1_500_000), 6), "1.5");
+/// assert_eq!(format_usdc(U256::from(42), 6), "0.000042");
+/// assert_eq!(format_usdc(U256::from(10).pow(U256::from(18)), 18), "1");
+/// ```
+pub fn format_usdc(value: U256, decimals: u8) -> String {
+    let Some(scale) = U256::from(10).checked_pow(U256::from(decimals)) else {
+        // Every `uint256` is below one whole token.
+        let digits = value.to_string();
+        let padded = alloc::format!("{digits:0>width$}", width = usize::from(decimals));
+        return match padded.trim_end_matches('0') {
+            "" => "0".to_string(),
+            fraction => alloc::format!("0.{fraction}"),
+        };
+    };
+    let (whole, fraction) = value.div_rem(scale);
+    if fraction.is_zero() {
+        return whole.to_string();
+    }
+    let fraction = fraction.to_string();
+    let padded = alloc::format!("{fraction:0>width$}", width = usize::from(decimals));
+    alloc::format!("{whole}.{}", padded.trim_end_matches('0'))
+}
+
+/// Parses a decimal string such as `"1.5"` into raw units of a token with
+/// `decimals` decimals, e.g. from [`usdc_decimals`](crate::usdc_decimals).
+///
+/// Only ASCII digits with an optional fractional part are accepted: no sign,
+/// exponent, separators or surrounding whitespace. Inputs are never rounded.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "alt-l1s", doc = "```rust")]
+#[cfg_attr(not(feature = "alt-l1s"), doc = "```ignore")]
+/// use usdshe::{parse_usdc, usdc_decimals, UsdcAmountError};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::U256;
+///
+/// assert_eq!(parse_usdc("1.5", 6).unwrap(), U256::from(1_500_000));
+///
+/// let decimals = usdc_decimals(NamedChain::BinanceSmartChain).unwrap();
+/// assert_eq!(
+///     parse_usdc("2", decimals).unwrap(),
+///     U256::from(2) * U256::from(10).pow(U256::from(18))
+/// );
+///
+/// assert_eq!(
+///     parse_usdc("0.0000001", 6),
+///     Err(UsdcAmountError::ExcessPrecision { decimals: 6 })
+/// );
+/// assert_eq!(parse_usdc("1,000", 6), Err(UsdcAmountError::InvalidFormat));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcAmountError::InvalidFormat`]: If the input is not a plain decimal
+///   number.
+/// - [`UsdcAmountError::ExcessPrecision`]: If the input has more than
+///   `decimals` fractional digits.
+/// - [`UsdcAmountError::Overflow`]: If the amount does not fit in a `uint256`
+///   of raw units.
+pub fn parse_usdc(s: &str, decimals: u8) -> Result<U256, UsdcAmountError> {
+    let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) || s.ends_with('.') {
+        return Err(UsdcAmountError::InvalidFormat);
+    }
+    if fraction.len() > usize::from(decimals) {
+        return Err(UsdcAmountError::ExcessPrecision { decimals });
+    }
+    let overflow = || UsdcAmountError::Overflow { decimals };
+    let whole = U256::from_str_radix(whole, 10).map_err(|_| overflow())?;
+    let padded = alloc::format!("{fraction:0<width$}", width = usize::from(decimals));
+    let fraction = match padded.as_str() {
+        "" => U256::ZERO,
+        digits => U256::from_str_radix(digits, 10).map_err(|_| overflow())?,
+    };
+    if whole.is_zero() {
+        return Ok(fraction);
+    }
+    U256::from(10)
+        .checked_pow(U256::from(decimals))
+        .and_then(|scale| whole.checked_mul(scale))
+        .and_then(|scaled| scaled.checked_add(fraction))
+        .ok_or_else(overflow)
+}
+
+/// Rescales `amount` raw units of USDC on `from` into raw units of USDC on
+/// `to`, e.g. before bridging between Binance-Peg USDC on BSC (18 decimals) and
+/// a 6-decimal deployment.
+///
+/// Scaling down fails rather than dropping digits; use
+/// [`normalize_amount_truncating`] to round toward zero instead.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "alt-l1s", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "alt-l1s", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::{normalize_amount, UsdcError};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::U256;
+///
+/// let bsc = U256::from(1_500_000_000_000_000_000u128);
+/// assert_eq!(
+///     normalize_amount(NamedChain::BinanceSmartChain, NamedChain::Base, bsc).unwrap(),
+///     U256::from(1_500_000)
+/// );
+/// assert_eq!(
+///     normalize_amount(NamedChain::Base, NamedChain::BinanceSmartChain, U256::from(1_500_000))
+///         .unwrap(),
+///     bsc
+/// );
+///
+/// let dust = bsc + U256::from(1);
+/// assert!(matches!(
+///     normalize_amount(NamedChain::BinanceSmartChain, NamedChain::Base, dust),
+///     Err(UsdcError::PrecisionLoss { decimals: 6 })
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If either chain has no known USDC address.
+/// - [`UsdcError::PrecisionLoss`]: If `to` has fewer decimals and `amount` is
+///   not a whole number of its units.
+/// - [`UsdcError::AmountOverflow`]: If the rescaled amount does not fit in a
+///   `uint256`.
+pub fn normalize_amount(from: NamedChain, to: NamedChain, amount: U256) -> Result<U256, UsdcError> {
+    rescale_exact(amount, usdc_decimals(from)?, usdc_decimals(to)?)
+}
+
+/// Like [`normalize_amount`], but rounds toward zero when `to` has fewer
+/// decimals, discarding the digits it cannot represent.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "alt-l1s", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "alt-l1s", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::normalize_amount_truncating;
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::U256;
+///
+/// let dust = U256::from(1_500_000_000_000_000_001u128);
+/// assert_eq!(
+///     normalize_amount_truncating(NamedChain::BinanceSmartChain, NamedChain::Base, dust)
+///         .unwrap(),
+///     U256::from(1_500_000)
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If either chain has no known USDC address.
+/// - [`UsdcError::AmountOverflow`]: If the rescaled amount does not fit in a
+///   `uint256`.
+pub fn normalize_amount_truncating(
+    from: NamedChain,
+    to: NamedChain,
+    amount: U256,
+) -> Result<U256, UsdcError> {
+    rescale(amount, usdc_decimals(from)?, usdc_decimals(to)?).map(|(scaled, _)| scaled)
+}
+
+/// Rescales `amount` from `from` decimals to `decimals`, failing rather than
+/// dropping digits.
+pub(crate) fn rescale_exact(amount: U256, from: u8, decimals: u8) -> Result<U256, UsdcError> {
+    let (scaled, remainder) = rescale(amount, from, decimals)?;
+    if !remainder.is_zero() {
+        return Err(UsdcError::PrecisionLoss { decimals });
+    }
+    Ok(scaled)
+}
+
+/// Rescales `amount` from `from` decimals to `decimals`, returning the result
+/// rounded toward zero and the discarded remainder.
+fn rescale(amount: U256, from: u8, decimals: u8) -> Result<(U256, U256), UsdcError> {
+    let overflow = UsdcError::AmountOverflow { decimals };
+    let scale = |exponent: u8| U256::from(10).checked_pow(U256::from(exponent));
+    if decimals >= from {
+        let scaled = scale(decimals - from)
+            .and_then(|scale| amount.checked_mul(scale))
+            .ok_or(overflow)?;
+        return Ok((scaled, U256::ZERO));
+    }
+    Ok(match scale(from - decimals) {
+        Some(scale) => amount.div_rem(scale),
+        None => (U256::ZERO, amount),
+    })
+}
@@ -0,0 +1,106 @@
+//! Canonical Tether (USDT) addresses.
+
+use crate::{TokenKind, Tokens, UsdcError};
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::{address, Address};
+
+/// Tether USD on Ethereum mainnet.
+pub const ETHEREUM_USDT: Address = address!("0xdAC17F958D2ee523a2206206994597C13D831ec7");
+/// Tether USD on Arbitrum One.
+pub const ARBITRUM_USDT: Address = address!("0xFd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9");
+/// Tether USD on Avalanche C-Chain.
+pub const AVALANCHE_USDT: Address = address!("0x9702230A8Ea53601f5cD2dc00fDBc13d4dF4A8c7");
+/// Binance-Peg Tether USD on BNB Smart Chain. Uses 18 decimals.
+pub const BSC_USDT: Address = address!("0x55d398326f99059fF775485246999027B3197955");
+/// Tether USD on Linea.
+pub const LINEA_USDT: Address = address!("0xA219439258ca9da29E9Cc4cE5596924745e12B93");
+/// Tether USD on Mantle.
+pub const MANTLE_USDT: Address = address!("0x201EBa5CC46D216Ce6DC03F6a759e8E766e956aE");
+/// Tether USD on OP Mainnet.
+pub const OPTIMISM_USDT: Address = address!("0x94b008aA00579c1307B0EF2c499aD98a8ce58e58");
+/// Tether USD on Polygon PoS.
+pub const POLYGON_USDT: Address = address!("0xc2132D05D31c914a87C6611C10748AEb04B58e8F");
+/// Tether USD on Scroll.
+pub const SCROLL_USDT: Address = address!("0xf55BEC9cafDbE8730f096Aa55dad6D22d44099Df");
+/// Tether USD on zkSync Era.
+pub const ZKSYNC_USDT: Address = address!("0x493257fD37EDB34451f62EDf8D2a0C418852bA4C");
+
+/// A trait for types that can provide a Tether (USDT) contract address, the
+/// counterpart to [`Usdc`](crate::Usdc).
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{Usdc, Usdt, UsdcError, ARBITRUM_USDT};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(NamedChain::Arbitrum.usdt_address().unwrap(), ARBITRUM_USDT);
+/// assert_eq!(42161u64.usdt_address().unwrap(), ARBITRUM_USDT);
+/// assert!(NamedChain::Arbitrum.usdc_address().unwrap() != ARBITRUM_USDT);
+/// assert!(matches!(
+///     NamedChain::Base.usdt_address(),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Base))
+/// ));
+/// ```
+pub trait Usdt {
+    /// Returns the USDT contract address for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but has no known
+    ///   USDT address.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn usdt_address(&self) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the canonical USDT contract for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_usdt(&self, address: Address) -> bool {
+        self.usdt_address().is_ok_and(|usdt| usdt == address)
+    }
+}
+
+pub(crate) const fn usdt_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_USDT),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_USDT),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::BinanceSmartChain => Some(BSC_USDT),
+        #[cfg(feature = "l2s")]
+        NamedChain::Linea => Some(LINEA_USDT),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_USDT),
+        #[cfg(feature = "l2s")]
+        NamedChain::Mantle => Some(MANTLE_USDT),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_USDT),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_USDT),
+        #[cfg(feature = "l2s")]
+        NamedChain::Scroll => Some(SCROLL_USDT),
+        #[cfg(feature = "l2s")]
+        NamedChain::ZkSync => Some(ZKSYNC_USDT),
+        _ => None,
+    }
+}
+
+impl Usdt for NamedChain {
+    fn usdt_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Usdt)
+    }
+}
+
+impl Usdt for Chain {
+    fn usdt_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Usdt)
+    }
+}
+
+impl Usdt for u64 {
+    fn usdt_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::Usdt)
+    }
+}
@@ -0,0 +1,34 @@
+/// Describes who issues the token returned for a chain.
+///
+/// Only [`UsdcVariant::Native`] tokens are minted by Circle and can be burned and
+/// minted through CCTP. The other variants are bridged representations whose
+/// backing lives on another chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum UsdcVariant {
+    /// Circle-issued USDC.
+    Native,
+    /// USDC locked on Ethereum and minted by the chain's canonical bridge, commonly
+    /// listed as `USDC.e`.
+    BridgedUsdcE,
+    /// Binance-Peg USD Coin, issued by Binance on BNB Smart Chain.
+    BinancePeg,
+    /// USDC bridged through Multichain (formerly Anyswap).
+    MultichainBridged,
+    /// USDC wrapped by the Wormhole token bridge.
+    WormholeWrapped,
+    /// USDC wrapped by Axelar (`axlUSDC`).
+    AxelarWrapped,
+}
+
+impl UsdcVariant {
+    /// Returns `true` for Circle-issued USDC.
+    pub const fn is_native(&self) -> bool {
+        matches!(self, Self::Native)
+    }
+}
@@ -0,0 +1,38 @@
+use core::fmt;
+
+/// The FiatToken release a USDC deployment runs.
+///
+/// The available functions differ between releases, so integrations that rely
+/// on newer behavior should check the version first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum FiatTokenVersion {
+    /// FiatTokenV2_1, which added the lost-and-found rescue of tokens locked in
+    /// the contract itself.
+    V2_1,
+    /// FiatTokenV2_2, which accepts ERC-1271 contract signatures through the
+    /// `bytes signature` overloads of `permit` and the EIP-3009 functions.
+    V2_2,
+}
+
+impl FiatTokenVersion {
+    /// Returns `true` if smart-contract wallets can sign permits and
+    /// authorizations, i.e. for FiatTokenV2_2 and later.
+    pub const fn supports_contract_signatures(&self) -> bool {
+        matches!(self, Self::V2_2)
+    }
+}
+
+impl fmt::Display for FiatTokenVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::V2_1 => "2.1",
+            Self::V2_2 => "2.2",
+        })
+    }
+}
@@ -0,0 +1,149 @@
+//! Wrapped native gas token addresses (WETH, WPOL, WAVAX, ...).
+
+use crate::{TokenKind, Tokens, UsdcError};
+use alloy_chains::{Chain, NamedChain};
+use alloy_primitives::{address, Address};
+
+/// Wrapped Ether on Arbitrum Sepolia.
+pub const ARBITRUM_SEPOLIA_WETH: Address = address!("0x980B62Da83eFf3D4576C647993b0c1D7faf17c73");
+/// Wrapped Ether on Arbitrum One.
+pub const ARBITRUM_WETH: Address = address!("0x82aF49447D8a07e3bd95BD0d56f35241523fBab1");
+/// Wrapped AVAX on Avalanche C-Chain.
+pub const AVALANCHE_WAVAX: Address = address!("0xB31f66AA3C1e785363F0875A1B74E27b85FD66c7");
+/// Wrapped Ether on Base Sepolia (OP Stack predeploy).
+pub const BASE_SEPOLIA_WETH: Address = address!("0x4200000000000000000000000000000000000006");
+/// Wrapped Ether on Base (OP Stack predeploy).
+pub const BASE_WETH: Address = address!("0x4200000000000000000000000000000000000006");
+/// Wrapped BERA on Berachain.
+pub const BERACHAIN_WBERA: Address = address!("0x6969696969696969696969696969696969696969");
+/// Wrapped BNB on BNB Smart Chain.
+pub const BSC_WBNB: Address = address!("0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c");
+/// Wrapped Ether on Ethereum Sepolia.
+pub const ETHEREUM_SEPOLIA_WETH: Address = address!("0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14");
+/// Wrapped Ether on Ethereum mainnet.
+pub const ETHEREUM_WETH: Address = address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+/// Wrapped FTM on Fantom.
+pub const FANTOM_WFTM: Address = address!("0x21be370D5312f44cB42ce377BC9b8a0cEF1A4C83");
+/// Wrapped Frax Ether on Fraxtal, whose gas token is frxETH.
+pub const FRAXTAL_WFRXETH: Address = address!("0xFC00000000000000000000000000000000000006");
+/// Wrapped Ether on Linea.
+pub const LINEA_WETH: Address = address!("0xe5D7C2a44FfDDf6b295A15c148167daaAf5Cf34f");
+/// Wrapped MNT on Mantle, whose gas token is MNT.
+pub const MANTLE_WMNT: Address = address!("0x78c1b0C915c4FAA5FffA6CAbf0219DA63d7f4cb8");
+/// Wrapped Ether on Mode (OP Stack predeploy).
+pub const MODE_WETH: Address = address!("0x4200000000000000000000000000000000000006");
+/// Wrapped Ether on OP Mainnet (OP Stack predeploy).
+pub const OPTIMISM_WETH: Address = address!("0x4200000000000000000000000000000000000006");
+/// Wrapped POL (formerly WMATIC) on Polygon PoS.
+pub const POLYGON_WPOL: Address = address!("0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270");
+/// Wrapped Ether on Scroll (predeploy).
+pub const SCROLL_WETH: Address = address!("0x5300000000000000000000000000000000000004");
+/// Wrapped S on Sonic.
+pub const SONIC_WS: Address = address!("0x039e2fB66102314Ce7b64Ce5Ce3E5183bc94aD38");
+/// Wrapped Ether on Unichain (OP Stack predeploy).
+pub const UNICHAIN_WETH: Address = address!("0x4200000000000000000000000000000000000006");
+/// Wrapped Ether on zkSync Era.
+pub const ZKSYNC_WETH: Address = address!("0x5AEa5775959fBC2557Cc8789bC1bf90A239D9a91");
+
+/// A trait for types that can provide the address of the chain's wrapped native
+/// gas token, e.g. WETH on Ethereum and its L2s or WAVAX on Avalanche.
+///
+/// Routers and DEX integrations usually need it next to USDC. Every chain in
+/// [`SupportedUsdcChain`](crate::SupportedUsdcChain) has an entry.
+///
+/// ## Examples
+///
+#[cfg_attr(all(feature = "alt-l1s", feature = "l2s"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "alt-l1s", feature = "l2s")), doc = "```ignore")]
+/// use usdshe::{WrappedNative, UsdcError, BASE_WETH, POLYGON_WPOL};
+/// use alloy_chains::NamedChain;
+///
+/// assert_eq!(NamedChain::Base.wrapped_native_address().unwrap(), BASE_WETH);
+/// assert_eq!(137u64.wrapped_native_address().unwrap(), POLYGON_WPOL);
+/// assert!(matches!(
+///     NamedChain::Gnosis.wrapped_native_address(),
+///     Err(UsdcError::UnsupportedChain(NamedChain::Gnosis))
+/// ));
+/// ```
+pub trait WrappedNative {
+    /// Returns the wrapped native token address for the implementing context.
+    ///
+    /// # Errors
+    ///
+    /// - [`UsdcError::UnsupportedChain`]: If the chain is named but has no known
+    ///   wrapped native token.
+    /// - [`UsdcError::UnsupportedChainId`]: If the chain ID does not map to a
+    ///   [`NamedChain`].
+    fn wrapped_native_address(&self) -> Result<Address, UsdcError>;
+
+    /// Returns `true` if `address` is the wrapped native token for the
+    /// implementing context. Unsupported contexts never match.
+    fn is_wrapped_native(&self, address: Address) -> bool {
+        self.wrapped_native_address()
+            .is_ok_and(|wrapped| wrapped == address)
+    }
+}
+
+pub(crate) const fn wrapped_native_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_WETH),
+        #[cfg(feature = "testnets")]
+        NamedChain::ArbitrumSepolia => Some(ARBITRUM_SEPOLIA_WETH),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some(AVALANCHE_WAVAX),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_WETH),
+        #[cfg(feature = "testnets")]
+        NamedChain::BaseSepolia => Some(BASE_SEPOLIA_WETH),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Berachain => Some(BERACHAIN_WBERA),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::BinanceSmartChain => Some(BSC_WBNB),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Fantom => Some(FANTOM_WFTM),
+        #[cfg(feature = "l2s")]
+        NamedChain::Fraxtal => Some(FRAXTAL_WFRXETH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Linea => Some(LINEA_WETH),
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_WETH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Mantle => Some(MANTLE_WMNT),
+        #[cfg(feature = "l2s")]
+        NamedChain::Mode => Some(MODE_WETH),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_WETH),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some(POLYGON_WPOL),
+        #[cfg(feature = "l2s")]
+        NamedChain::Scroll => Some(SCROLL_WETH),
+        #[cfg(feature = "testnets")]
+        NamedChain::Sepolia => Some(ETHEREUM_SEPOLIA_WETH),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Sonic => Some(SONIC_WS),
+        #[cfg(feature = "l2s")]
+        NamedChain::Unichain => Some(UNICHAIN_WETH),
+        #[cfg(feature = "l2s")]
+        NamedChain::ZkSync => Some(ZKSYNC_WETH),
+        _ => None,
+    }
+}
+
+impl WrappedNative for NamedChain {
+    fn wrapped_native_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::WrappedNative)
+    }
+}
+
+impl WrappedNative for Chain {
+    fn wrapped_native_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::WrappedNative)
+    }
+}
+
+impl WrappedNative for u64 {
+    fn wrapped_native_address(&self) -> Result<Address, UsdcError> {
+        self.token_address(TokenKind::WrappedNative)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{Usdc, UsdcError};
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+
+/// The Aave v3 `Pool` on Ethereum mainnet.
+pub const ETHEREUM_AAVE_V3_POOL: Address = address!("0x87870Bca3F3fD6335C3F4ce8392D69350B4fA4E2");
+/// Aave v3 aUSDC on Ethereum mainnet (`aEthUSDC`).
+pub const ETHEREUM_AAVE_V3_AUSDC: Address = address!("0x98C23E9d8f34FEFb1B7BD6a91B7FF122F4e16F5c");
+/// The Aave v3 `Pool` on Arbitrum One.
+pub const ARBITRUM_AAVE_V3_POOL: Address = address!("0x794a61358D6845594F94dc1DB02A252b5b4814aD");
+/// Aave v3 aUSDC on Arbitrum One (`aArbUSDCn`).
+pub const ARBITRUM_AAVE_V3_AUSDC: Address = address!("0x724dc807b04555b71ed48a6896b6F41593b8C637");
+/// The Aave v3 `Pool` on Avalanche C-Chain.
+pub const AVALANCHE_AAVE_V3_POOL: Address = address!("0x794a61358D6845594F94dc1DB02A252b5b4814aD");
+/// Aave v3 aUSDC on Avalanche C-Chain (`aAvaUSDC`).
+pub const AVALANCHE_AAVE_V3_AUSDC: Address = address!("0x625E7708f30cA75bfd92586e17077590C60eb4cD");
+/// The Aave v3 `Pool` on Base.
+pub const BASE_AAVE_V3_POOL: Address = address!("0xA238Dd80C259a72e81d7e4664a9801593F98d1c5");
+/// Aave v3 aUSDC on Base (`aBasUSDC`).
+pub const BASE_AAVE_V3_AUSDC: Address = address!("0x4e65fE4DbA92790696d040ac24Aa414708F5c0AB");
+/// The Aave v3 `Pool` on Optimism.
+pub const OPTIMISM_AAVE_V3_POOL: Address = address!("0x794a61358D6845594F94dc1DB02A252b5b4814aD");
+/// Aave v3 aUSDC on Optimism (`aOptUSDCn`).
+pub const OPTIMISM_AAVE_V3_AUSDC: Address = address!("0x38d693cE1dF5AaDF7bC62595A37D667aD57922e5");
+/// The Aave v3 `Pool` on Polygon PoS.
+pub const POLYGON_AAVE_V3_POOL: Address = address!("0x794a61358D6845594F94dc1DB02A252b5b4814aD");
+/// Aave v3 aUSDC on Polygon PoS (`aPolUSDCn`).
+pub const POLYGON_AAVE_V3_AUSDC: Address = address!("0xA4D94019934D8333Ef880ABFFbF2FDd611C762BD");
+/// The Aave v3 `Pool` on Scroll.
+pub const SCROLL_AAVE_V3_POOL: Address = address!("0x11fCfe756c05AD438e312a7fd934381537D3cFfe");
+/// Aave v3 aUSDC on Scroll (`aScrUSDC`).
+pub const SCROLL_AAVE_V3_AUSDC: Address = address!("0x1D738a3436A8C49CefFbaB7fbF04B660fb528CbD");
+
+/// The Aave v3 USDC market on one chain, returned by [`aave_v3_usdc`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AaveMarket {
+    /// The `Pool` that USDC is supplied to and withdrawn from with
+    /// `supply(usdc, amount, onBehalfOf, 0)` and `withdraw(usdc, amount, to)`.
+    pub pool: Address,
+    /// The aUSDC receipt token. Its balance is the supplied USDC plus accrued
+    /// interest, in USDC's raw units, and grows without transfers.
+    pub a_token: Address,
+    /// The USDC contract the market accepts, the chain's native USDC.
+    pub usdc: Address,
+}
+
+/// Returns the Aave v3 market for the chain's native USDC.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{aave_v3_usdc, BASE_AAVE_V3_AUSDC, BASE_USDC};
+/// use alloy_chains::NamedChain;
+///
+/// let market = aave_v3_usdc(NamedChain::Base).unwrap();
+/// assert_eq!(market.a_token, BASE_AAVE_V3_AUSDC);
+/// assert_eq!(market.usdc, BASE_USDC);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If no Aave v3 USDC market is recorded for
+///   the chain.
+pub fn aave_v3_usdc(chain: NamedChain) -> Result<AaveMarket, UsdcError> {
+    let (pool, a_token) = aave_v3_on(chain).ok_or(UsdcError::UnsupportedChain(chain))?;
+    Ok(AaveMarket {
+        pool,
+        a_token,
+        usdc: chain.usdc_address()?,
+    })
+}
+
+const fn aave_v3_on(chain: NamedChain) -> Option<(Address, Address)> {
+    match chain {
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some((ETHEREUM_AAVE_V3_POOL, ETHEREUM_AAVE_V3_AUSDC)),
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some((ARBITRUM_AAVE_V3_POOL, ARBITRUM_AAVE_V3_AUSDC)),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Avalanche => Some((AVALANCHE_AAVE_V3_POOL, AVALANCHE_AAVE_V3_AUSDC)),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some((BASE_AAVE_V3_POOL, BASE_AAVE_V3_AUSDC)),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some((OPTIMISM_AAVE_V3_POOL, OPTIMISM_AAVE_V3_AUSDC)),
+        #[cfg(feature = "alt-l1s")]
+        NamedChain::Polygon => Some((POLYGON_AAVE_V3_POOL, POLYGON_AAVE_V3_AUSDC)),
+        #[cfg(feature = "l2s")]
+        NamedChain::Scroll => Some((SCROLL_AAVE_V3_POOL, SCROLL_AAVE_V3_AUSDC)),
+        _ => None,
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{abi, TransactionRequest, Usdc, UsdcError};
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address, U256};
+
+/// The Compound III (Comet) USDC market on Ethereum mainnet, `cUSDCv3`.
+pub const ETHEREUM_COMET_USDC: Address = address!("0xc3d688B66703497DAA19211EEdff47f25384cdc3");
+/// The Compound III (Comet) USDC market on Arbitrum One, `cUSDCv3`.
+pub const ARBITRUM_COMET_USDC: Address = address!("0x9c4ec768c28520B50860ea7a15bd7213a9fF58bf");
+/// The Compound III (Comet) USDC market on Base, `cUSDCv3`.
+pub const BASE_COMET_USDC: Address = address!("0xb125E6687d4313864e53df431d5425969c15Eb2F");
+/// The Compound III (Comet) USDC market on Optimism, `cUSDCv3`.
+pub const OPTIMISM_COMET_USDC: Address = address!("0x2e44e174f7D53F0212823acC11C01A11d58c5bCB");
+/// The Compound III (Comet) USDC market on Scroll, `cUSDCv3`.
+pub const SCROLL_COMET_USDC: Address = address!("0xB2f97c1Bd3bf02f5e74d13f02E3e26F93D77CE44");
+
+/// The Compound III USDC market on one chain, returned by [`comet_usdc`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CometMarket {
+    /// The `Comet` proxy, which is also the `cUSDCv3` token: its balance is
+    /// the supplied USDC plus accrued interest, in USDC's raw units.
+    pub comet: Address,
+    /// The base asset of the market, the chain's native USDC.
+    pub usdc: Address,
+}
+
+/// Returns the Compound III market whose base asset is the chain's native
+/// USDC.
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{comet_usdc, ARBITRUM_COMET_USDC, ARBITRUM_USDC};
+/// use alloy_chains::NamedChain;
+///
+/// let market = comet_usdc(NamedChain::Arbitrum).unwrap();
+/// assert_eq!(market.comet, ARBITRUM_COMET_USDC);
+/// assert_eq!(market.usdc, ARBITRUM_USDC);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If no Comet USDC market is recorded for
+///   the chain.
+pub fn comet_usdc(chain: NamedChain) -> Result<CometMarket, UsdcError> {
+    let comet = comet_on(chain).ok_or(UsdcError::UnsupportedChain(chain))?;
+    Ok(CometMarket {
+        comet,
+        usdc: chain.usdc_address()?,
+    })
+}
+
+/// Builds a `supply(usdc, amount)` call to the Comet USDC market on `chain`,
+/// depositing `amount` raw units of USDC from the sender.
+///
+/// The market must first be approved to spend `amount`, e.g. with
+/// [`build_usdc_approve_raw`](crate::build_usdc_approve_raw).
+///
+/// ## Examples
+///
+#[cfg_attr(feature = "l2s", doc = "```rust")]
+#[cfg_attr(not(feature = "l2s"), doc = "```ignore")]
+/// use usdshe::{build_comet_supply, build_usdc_approve_raw, BASE_COMET_USDC};
+/// use alloy_chains::NamedChain;
+/// use alloy_primitives::U256;
+///
+/// let amount = U256::from(1_000_000_000); // 1,000 USDC
+/// let approve = build_usdc_approve_raw(NamedChain::Base, BASE_COMET_USDC, amount).unwrap();
+/// let supply = build_comet_supply(NamedChain::Base, amount).unwrap();
+/// assert_eq!(supply.to, BASE_COMET_USDC);
+/// assert_eq!(supply.data[..4], [0xf2, 0xb9, 0xfd, 0xb8]);
+/// ```
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If no Comet USDC market is recorded for
+///   the chain.
+pub fn build_comet_supply(
+    chain: NamedChain,
+    amount: U256,
+) -> Result<TransactionRequest, UsdcError> {
+    comet_call(chain, abi::COMET_SUPPLY, amount)
+}
+
+/// Builds a `withdraw(usdc, amount)` call to the Comet USDC market on `chain`,
+/// returning `amount` raw units of USDC to the sender. Pass `U256::MAX` to
+/// withdraw the whole balance, including interest accrued up to the block the
+/// transaction lands in.
+///
+/// # Errors
+///
+/// - [`UsdcError::UnsupportedChain`]: If no Comet USDC market is recorded for
+///   the chain.
+pub fn build_comet_withdraw(
+    chain: NamedChain,
+    amount: U256,
+) -> Result<TransactionRequest, UsdcError> {
+    comet_call(chain, abi::COMET_WITHDRAW, amount)
+}
+
+fn comet_call(
+    chain: NamedChain,
+    selector: abi::Selector,
+    amount: U256,
+) -> Result<TransactionRequest, UsdcError> {
+    let market = comet_usdc(chain)?;
+    Ok(TransactionRequest {
+        chain_id: chain as u64,
+        to: market.comet,
+        data: abi::encode_call(
+            selector,
+            &[abi::address_word(market.usdc), abi::uint_word(amount)],
+        ),
+    })
+}
+
+const fn comet_on(chain: NamedChain) -> Option<Address> {
+    match chain {
+        #[cfg(feature = "ethereum")]
+        NamedChain::Mainnet => Some(ETHEREUM_COMET_USDC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Arbitrum => Some(ARBITRUM_COMET_USDC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Base => Some(BASE_COMET_USDC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Optimism => Some(OPTIMISM_COMET_USDC),
+        #[cfg(feature = "l2s")]
+        NamedChain::Scroll => Some(SCROLL_COMET_USDC),
+        _ => None,
+    }
+}
@@ -0,0 +1,11 @@
+//! Contracts that USDC is commonly deposited into for yield.
+//!
+//! Treasury tooling that tracks idle USDC needs the receipt tokens it holds as
+//! well as the token itself: a deposit into a lending market moves the USDC
+//! out of the wallet and mints an interest-bearing position in its place.
+
+mod aave;
+mod compound;
+
+pub use aave::*;
+pub use compound::*;
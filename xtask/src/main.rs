@@ -0,0 +1,184 @@
+//! Maintenance tasks for `usdshe`.
+//!
+//! ```text
+//! cargo xtask codegen          # regenerate src/address from data/usdc.tokenlist.json
+//! cargo xtask codegen --check  # fail if src/address is out of date
+//! ```
+
+// Reuse the crate's JSON parser rather than pulling in a serialization stack.
+#[path = "../../src/json.rs"]
+mod json;
+
+use json::Value;
+use std::{collections::BTreeMap, env, fs, path::Path, process::ExitCode};
+
+/// The token list the address modules are generated from.
+const TOKEN_LIST: &str = "data/usdc.tokenlist.json";
+
+/// The directory the address modules are written to.
+const ADDRESS_DIR: &str = "src/address";
+
+/// Tokens carrying this tag become `USDC_E` constants instead of `USDC`.
+const USDC_E_TAG: &str = "usdc-e";
+
+const HEADER: &str =
+    "// @generated by `cargo xtask codegen` from data/usdc.tokenlist.json. Do not edit by hand.\n";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["codegen"] => codegen(false),
+        ["codegen", "--check"] => codegen(true),
+        _ => Err("usage: cargo xtask codegen [--check]".to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// A constant to emit into a chain module.
+struct Constant {
+    name: &'static str,
+    address: String,
+    source: Option<String>,
+}
+
+fn codegen(check: bool) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the workspace root")
+        .to_path_buf();
+    let input = fs::read_to_string(root.join(TOKEN_LIST))
+        .map_err(|err| format!("failed to read {TOKEN_LIST}: {err}"))?;
+    let document = json::parse(&input).map_err(|err| format!("{TOKEN_LIST}: {err}"))?;
+    let files = render(&document)?;
+
+    let dir = root.join(ADDRESS_DIR);
+    let mut stale = Vec::new();
+    for (name, contents) in &files {
+        let path = dir.join(name);
+        if fs::read_to_string(&path).ok().as_deref() == Some(contents.as_str()) {
+            continue;
+        }
+        if check {
+            stale.push(path);
+        } else {
+            fs::write(&path, contents)
+                .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+        }
+    }
+    // Modules for chains dropped from the list.
+    let entries = fs::read_dir(&dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    for entry in entries {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if name.ends_with(".rs") && !files.contains_key(name) {
+            if check {
+                stale.push(path);
+            } else {
+                fs::remove_file(&path)
+                    .map_err(|err| format!("failed to remove {}: {err}", path.display()))?;
+            }
+        }
+    }
+
+    if !stale.is_empty() {
+        let stale: Vec<String> = stale
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        return Err(format!(
+            "{ADDRESS_DIR} is out of date; run `cargo xtask codegen`:\n  {}",
+            stale.join("\n  ")
+        ));
+    }
+    Ok(())
+}
+
+/// Renders every file in the address directory, keyed by file name.
+fn render(document: &Value) -> Result<BTreeMap<String, String>, String> {
+    let tokens = document
+        .get("tokens")
+        .and_then(Value::as_array)
+        .ok_or("expected a `tokens` array")?;
+
+    let mut modules: BTreeMap<String, Vec<Constant>> = BTreeMap::new();
+    for (index, token) in tokens.iter().enumerate() {
+        let field = |name: &str| format!("tokens[{index}]: expected a string `{name}`");
+        let extensions = token.get("extensions");
+        let module = extensions
+            .and_then(|extensions| extensions.get("module"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| field("extensions.module"))?;
+        if !module
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+        {
+            return Err(format!("tokens[{index}]: invalid module name `{module}`"));
+        }
+        let address = token
+            .get("address")
+            .and_then(Value::as_str)
+            .ok_or_else(|| field("address"))?;
+        let usdc_e = token
+            .get("tags")
+            .and_then(Value::as_array)
+            .is_some_and(|tags| tags.iter().any(|tag| tag.as_str() == Some(USDC_E_TAG)));
+        let source = extensions
+            .and_then(|extensions| extensions.get("source"))
+            .and_then(Value::as_str);
+
+        let constants = modules.entry(module.to_string()).or_default();
+        let name = if usdc_e { "USDC_E" } else { "USDC" };
+        if constants.iter().any(|constant| constant.name == name) {
+            return Err(format!(
+                "tokens[{index}]: duplicate {name} for module `{module}`"
+            ));
+        }
+        constants.push(Constant {
+            name,
+            address: address.to_string(),
+            source: source.map(str::to_string),
+        });
+    }
+
+    let mut files = BTreeMap::new();
+    let mut mod_rs = format!("{HEADER}\n");
+    for module in modules.keys() {
+        mod_rs.push_str(&format!("mod {module};\n"));
+    }
+    mod_rs.push('\n');
+    for (module, constants) in &mut modules {
+        constants.sort_by_key(|constant| constant.name == "USDC_E");
+        let prefix = module.to_uppercase();
+        let mut file = format!("{HEADER}\nuse alloy_primitives::{{address, Address}};\n");
+        for constant in constants.iter() {
+            mod_rs.push_str(&format!(
+                "pub use {module}::{name} as {prefix}_{name};\n",
+                name = constant.name
+            ));
+            file.push('\n');
+            match (&constant.source, constant.name) {
+                (Some(source), "USDC_E") => {
+                    file.push_str(&format!("/// Bridged USDC.e: <{source}>\n"))
+                }
+                (Some(source), _) => file.push_str(&format!("/// <{source}>\n")),
+                (None, _) => {}
+            }
+            file.push_str(&format!(
+                "pub const {}: Address = address!(\"{}\");\n",
+                constant.name, constant.address
+            ));
+        }
+        files.insert(format!("{module}.rs"), file);
+    }
+    files.insert("mod.rs".to_string(), mod_rs);
+    Ok(files)
+}